@@ -0,0 +1,43 @@
+//! Decodes arbitrary bytes as points and checks that encoding a decoded point is stable.
+
+#![no_main]
+
+use ceno_crypto_primitives::{
+    ecdsa::{CenoAffinePoint, CenoProjectivePoint, ECDSACurve},
+    secp256k1::Secp256k1,
+    secp256r1::NistP256,
+};
+use elliptic_curve::{
+    group::GroupEncoding,
+    sec1::{CompressedPoint, ToEncodedPoint},
+};
+use libfuzzer_sys::fuzz_target;
+
+fn check<C: ECDSACurve>(data: &[u8]) {
+    // The fixed width encoding must round trip exactly, including the identity.
+    let mut bytes = CompressedPoint::<C>::default();
+    if data.len() == bytes.len() {
+        bytes.copy_from_slice(data);
+        if let Some(point) = Option::<CenoAffinePoint<C>>::from(CenoAffinePoint::from_bytes(&bytes))
+        {
+            assert_eq!(point.to_bytes(), bytes);
+            let projective = CenoProjectivePoint::<C>::from(point);
+            assert_eq!(projective.to_bytes(), bytes);
+        }
+    }
+
+    // Any SEC1 form decodes to a point whose own encodings decode back to it.
+    if let Ok(point) = CenoAffinePoint::<C>::try_from(data) {
+        for compress in [true, false] {
+            let encoded = point.to_encoded_point(compress);
+            let decoded = CenoAffinePoint::<C>::try_from(encoded.as_bytes()).unwrap();
+            assert_eq!(decoded, point);
+            assert_eq!(decoded.to_encoded_point(compress), encoded);
+        }
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    check::<Secp256k1>(data);
+    check::<NistP256>(data);
+});
@@ -0,0 +1,10 @@
+//! Arithmetic over the base field of the BLS12-381 curve and its quadratic extension, accelerated
+//! with the BLS12-381 field precompiles.
+
+/// The base field of the BLS12-381 curve.
+pub mod fp;
+pub use fp::Bls12381Fp;
+
+/// The quadratic extension of the BLS12-381 base field.
+pub mod fp2;
+pub use fp2::Bls12381Fp2;
@@ -0,0 +1,187 @@
+//! Arithmetic in the base field of the BLS12-381 curve, accelerated with the Fp precompiles.
+//!
+//! An element takes 48 bytes, stored as 12 little endian words with the least significant word
+//! first, which is the layout read and written by the precompiles.
+
+use ceno_syscall::{syscall_bls12381_fp_addmod, syscall_bls12381_fp_mulmod};
+use elliptic_curve::subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+/// The number of limbs in [Bls12381Fp].
+pub const N: usize = 12;
+
+/// The modulus of the BLS12-381 base field, as little endian words.
+pub const MODULUS: [u32; N] = [
+    0xffffaaab, 0xb9feffff, 0xb153ffff, 0x1eabfffe, 0xf6b0f624, 0x6730d2a0, 0xf38512bf, 0x64774b84,
+    0x434bacd7, 0x4b1ba7b6, 0x397fe69a, 0x1a0111ea,
+];
+
+/// The exponent `p - 2` used to invert via Fermat's little theorem, as little endian words.
+const MODULUS_MINUS_TWO: [u32; N] = [
+    0xffffaaa9, 0xb9feffff, 0xb153ffff, 0x1eabfffe, 0xf6b0f624, 0x6730d2a0, 0xf38512bf, 0x64774b84,
+    0x434bacd7, 0x4b1ba7b6, 0x397fe69a, 0x1a0111ea,
+];
+
+/// An element of the BLS12-381 base field.
+///
+/// The element is stored as little endian words and is always reduced modulo [MODULUS], which is
+/// the representation expected by the precompiles.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(align(4))]
+pub struct Bls12381Fp(pub [u32; N]);
+
+impl Bls12381Fp {
+    /// The additive identity.
+    pub const ZERO: Self = Self([0; N]);
+
+    /// The multiplicative identity.
+    pub const ONE: Self = Self([1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+    /// Creates a new [`Bls12381Fp`] from the given limbs, which must already be reduced.
+    pub fn new(limbs: [u32; N]) -> Self {
+        Self(limbs)
+    }
+
+    /// Returns true if `self` is zero.
+    pub fn is_zero(&self) -> Choice {
+        self.0.ct_eq(&[0; N])
+    }
+
+    /// Returns true if the limbs of `self` are smaller than [MODULUS], which holds for every
+    /// element built by the arithmetic here but not necessarily for untrusted limbs.
+    pub fn is_reduced(&self) -> Choice {
+        let mut borrow = false;
+        for (limb, modulus) in self.0.iter().zip(MODULUS.iter()) {
+            let (diff, b1) = limb.overflowing_sub(*modulus);
+            let (_, b2) = diff.overflowing_sub(borrow as u32);
+            borrow = b1 | b2;
+        }
+        Choice::from(borrow as u8)
+    }
+
+    /// Computes `self + other`.
+    pub fn add(&self, other: &Bls12381Fp) -> Bls12381Fp {
+        let mut res = *self;
+        syscall_bls12381_fp_addmod(&mut res.0, &other.0);
+        res
+    }
+
+    /// Computes `self * other`.
+    pub fn mul(&self, other: &Bls12381Fp) -> Bls12381Fp {
+        let mut res = *self;
+        syscall_bls12381_fp_mulmod(&mut res.0, &other.0);
+        res
+    }
+
+    /// Computes `-self` in constant time.
+    ///
+    /// Negating zero yields zero rather than the modulus, so the result is always reduced.
+    pub fn neg(&self) -> Bls12381Fp {
+        let is_zero = self.is_zero();
+        let mut res = [0u32; N];
+        let mut borrow = false;
+        for (i, limb) in res.iter_mut().enumerate() {
+            let (diff, b1) = MODULUS[i].overflowing_sub(self.0[i]);
+            let (diff, b2) = diff.overflowing_sub(borrow as u32);
+            *limb = u32::conditional_select(&diff, &0, is_zero);
+            borrow = b1 | b2;
+        }
+
+        Self(res)
+    }
+
+    /// Computes `self - other` as `self + (-other)` with the add precompile.
+    pub fn sub(&self, other: &Bls12381Fp) -> Bls12381Fp {
+        self.add(&other.neg())
+    }
+
+    /// Computes `self^exp`, where `exp` is given as little endian words.
+    ///
+    /// This is a square-and-multiply over the mulmod precompile and is variable time in `exp`.
+    pub fn pow(&self, exp: &[u32]) -> Bls12381Fp {
+        let mut res = Self::ONE;
+        for word in exp.iter().rev() {
+            for i in (0..32).rev() {
+                res = res.mul(&res);
+                if (word >> i) & 1 == 1 {
+                    res = res.mul(self);
+                }
+            }
+        }
+        res
+    }
+
+    /// Computes `self^-1` as `self^(p - 2)`, returning none if `self` is zero.
+    pub fn invert(&self) -> CtOption<Bls12381Fp> {
+        CtOption::new(self.pow(&MODULUS_MINUS_TWO), !self.is_zero())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use elliptic_curve::bigint::{Encoding, U384};
+    use proptest::prelude::*;
+
+    /// Returns the reduced element with the given limbs, clearing the top bits so that it is
+    /// below the modulus.
+    fn element(mut limbs: [u32; N]) -> Bls12381Fp {
+        limbs[N - 1] &= 0x0fff_ffff;
+        Bls12381Fp::new(limbs)
+    }
+
+    /// Returns `element` as the reference integer of `crypto-bigint`.
+    fn big(element: &Bls12381Fp) -> U384 {
+        let mut bytes = [0u8; 48];
+        for (chunk, limb) in bytes.chunks_exact_mut(4).zip(element.0.iter()) {
+            chunk.copy_from_slice(&limb.to_le_bytes());
+        }
+        U384::from_le_bytes(bytes)
+    }
+
+    fn minus(k: u32) -> Bls12381Fp {
+        let mut limbs = MODULUS;
+        limbs[0] -= k;
+        Bls12381Fp::new(limbs)
+    }
+
+    #[test]
+    fn arithmetic_at_the_modulus() {
+        assert_eq!(minus(1).add(&Bls12381Fp::ONE), Bls12381Fp::ZERO);
+        assert_eq!(minus(1).add(&minus(1)), minus(2));
+        assert_eq!(minus(1).mul(&minus(1)), Bls12381Fp::ONE);
+        assert_eq!(Bls12381Fp::ZERO.sub(&Bls12381Fp::ONE), minus(1));
+        assert_eq!(Bls12381Fp::ZERO.neg(), Bls12381Fp::ZERO);
+
+        assert!(bool::from(minus(1).is_reduced()));
+        assert!(!bool::from(Bls12381Fp::new(MODULUS).is_reduced()));
+    }
+
+    #[test]
+    fn inverting_zero_yields_none() {
+        assert!(bool::from(Bls12381Fp::ZERO.invert().is_none()));
+        assert_eq!(Bls12381Fp::ONE.invert().unwrap(), Bls12381Fp::ONE);
+        assert_eq!(minus(1).invert().unwrap(), minus(1));
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn arithmetic_matches_crypto_bigint(a in any::<[u32; N]>(), b in any::<[u32; N]>()) {
+            let (a, b) = (element(a), element(b));
+            let (x, y, p) = (big(&a), big(&b), big(&Bls12381Fp::new(MODULUS)));
+
+            prop_assert_eq!(big(&a.add(&b)), x.add_mod(&y, &p));
+            prop_assert_eq!(big(&a.sub(&b)), x.sub_mod(&y, &p));
+            prop_assert_eq!(big(&a.neg()), x.neg_mod(&p));
+            prop_assert_eq!(big(&a.mul(&b)), U384::const_rem_wide(x.mul_wide(&y), &p).0);
+        }
+
+        #[test]
+        fn invert_is_the_multiplicative_inverse(a in any::<[u32; N]>()) {
+            let a = element(a);
+            prop_assume!(!bool::from(a.is_zero()));
+            prop_assert_eq!(a.mul(&a.invert().unwrap()), Bls12381Fp::ONE);
+        }
+    }
+}
@@ -0,0 +1,172 @@
+//! Arithmetic in the quadratic extension `Fp[u] / (u^2 + 1)` of the BLS12-381 base field,
+//! accelerated with the Fp2 precompiles.
+//!
+//! An element takes 96 bytes, stored as the 12 little endian words of `c0` followed by those of
+//! `c1`.
+
+use super::fp::{self, Bls12381Fp};
+use ceno_syscall::{syscall_bls12381_fp2_addmod, syscall_bls12381_fp2_mulmod};
+use elliptic_curve::subtle::{Choice, CtOption};
+
+/// The number of limbs in [Bls12381Fp2].
+pub const N: usize = 2 * fp::N;
+
+/// The non-residue `1 + u` used to build the sextic extension of [Bls12381Fp2].
+const NONRESIDUE: Bls12381Fp2 = Bls12381Fp2([
+    1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+]);
+
+/// An element `c0 + c1 · u` of the BLS12-381 quadratic extension field, where `u^2 = -1`.
+///
+/// The element is stored as the little endian words of `c0` followed by those of `c1`, which is
+/// the representation expected by the precompiles.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(align(4))]
+pub struct Bls12381Fp2(pub [u32; N]);
+
+impl Bls12381Fp2 {
+    /// The additive identity.
+    pub const ZERO: Self = Self([0; N]);
+
+    /// The multiplicative identity.
+    pub const ONE: Self = Self([
+        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ]);
+
+    /// Creates the element `c0 + c1 · u`.
+    pub fn new(c0: Bls12381Fp, c1: Bls12381Fp) -> Self {
+        let mut limbs = [0u32; N];
+        limbs[..fp::N].copy_from_slice(&c0.0);
+        limbs[fp::N..].copy_from_slice(&c1.0);
+        Self(limbs)
+    }
+
+    /// Returns the real part `c0`.
+    pub fn c0(&self) -> Bls12381Fp {
+        Bls12381Fp::new(self.0[..fp::N].try_into().unwrap())
+    }
+
+    /// Returns the imaginary part `c1`.
+    pub fn c1(&self) -> Bls12381Fp {
+        Bls12381Fp::new(self.0[fp::N..].try_into().unwrap())
+    }
+
+    /// Returns true if `self` is zero.
+    pub fn is_zero(&self) -> Choice {
+        self.c0().is_zero() & self.c1().is_zero()
+    }
+
+    /// Returns true if both parts of `self` are reduced, see [`Bls12381Fp::is_reduced`].
+    pub fn is_reduced(&self) -> Choice {
+        self.c0().is_reduced() & self.c1().is_reduced()
+    }
+
+    /// Computes `self + other`.
+    pub fn add(&self, other: &Bls12381Fp2) -> Bls12381Fp2 {
+        let mut res = *self;
+        syscall_bls12381_fp2_addmod(&mut res.0, &other.0);
+        res
+    }
+
+    /// Computes `self * other`.
+    pub fn mul(&self, other: &Bls12381Fp2) -> Bls12381Fp2 {
+        let mut res = *self;
+        syscall_bls12381_fp2_mulmod(&mut res.0, &other.0);
+        res
+    }
+
+    /// Computes `self^2`.
+    pub fn square(&self) -> Bls12381Fp2 {
+        self.mul(self)
+    }
+
+    /// Computes `-self` in constant time.
+    pub fn neg(&self) -> Bls12381Fp2 {
+        Self::new(self.c0().neg(), self.c1().neg())
+    }
+
+    /// Computes `self - other` as `self + (-other)` with the add precompile.
+    pub fn sub(&self, other: &Bls12381Fp2) -> Bls12381Fp2 {
+        self.add(&other.neg())
+    }
+
+    /// Computes the conjugate `c0 - c1 · u`, which is also the Frobenius map of `self`.
+    pub fn conjugate(&self) -> Bls12381Fp2 {
+        Self::new(self.c0(), self.c1().neg())
+    }
+
+    /// Computes `self * (1 + u)`.
+    pub fn mul_by_nonresidue(&self) -> Bls12381Fp2 {
+        self.mul(&NONRESIDUE)
+    }
+
+    /// Computes `self * scalar` for an element of the base field.
+    pub fn mul_by_fp(&self, scalar: &Bls12381Fp) -> Bls12381Fp2 {
+        Self::new(self.c0().mul(scalar), self.c1().mul(scalar))
+    }
+
+    /// Computes `self^-1` as `conjugate(self) / (c0^2 + c1^2)`, returning none if `self` is zero.
+    ///
+    /// The norm is inverted in the base field, so this costs a single base field inversion.
+    pub fn invert(&self) -> CtOption<Bls12381Fp2> {
+        let (c0, c1) = (self.c0(), self.c1());
+        let norm = c0.mul(&c0).add(&c1.mul(&c1));
+        // As -1 is not a square modulo p, the norm is zero only if `self` is zero.
+        let norm_inv = Option::<Bls12381Fp>::from(norm.invert()).unwrap_or(Bls12381Fp::ZERO);
+        CtOption::new(self.conjugate().mul_by_fp(&norm_inv), !self.is_zero())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Returns the reduced element with the given limbs, clearing the top bits of both parts so
+    /// that they are below the modulus.
+    fn element(mut limbs: [u32; N]) -> Bls12381Fp2 {
+        limbs[fp::N - 1] &= 0x0fff_ffff;
+        limbs[N - 1] &= 0x0fff_ffff;
+        Bls12381Fp2(limbs)
+    }
+
+    #[test]
+    fn inverting_zero_yields_none() {
+        assert!(bool::from(Bls12381Fp2::ZERO.invert().is_none()));
+        assert_eq!(Bls12381Fp2::ONE.invert().unwrap(), Bls12381Fp2::ONE);
+    }
+
+    #[test]
+    fn u_squared_is_minus_one() {
+        let u = Bls12381Fp2::new(Bls12381Fp::ZERO, Bls12381Fp::ONE);
+        assert_eq!(u.square(), Bls12381Fp2::ONE.neg());
+        assert_eq!(u.conjugate(), u.neg());
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        #[test]
+        fn mul_matches_the_schoolbook_product(a in any::<[u32; N]>(), b in any::<[u32; N]>()) {
+            let (a, b) = (element(a), element(b));
+            let (a0, a1, b0, b1) = (a.c0(), a.c1(), b.c0(), b.c1());
+            let expected = Bls12381Fp2::new(
+                a0.mul(&b0).sub(&a1.mul(&b1)),
+                a0.mul(&b1).add(&a1.mul(&b0)),
+            );
+            prop_assert_eq!(a.mul(&b), expected);
+            prop_assert_eq!(a.add(&b), Bls12381Fp2::new(a0.add(&b0), a1.add(&b1)));
+            prop_assert_eq!(a.sub(&b).add(&b), a);
+            prop_assert_eq!(a.mul_by_nonresidue(), a.mul(&NONRESIDUE));
+            prop_assert_eq!(a.mul_by_fp(&b0), a.mul(&Bls12381Fp2::new(b0, Bls12381Fp::ZERO)));
+        }
+
+        #[test]
+        fn invert_is_the_multiplicative_inverse(a in any::<[u32; N]>()) {
+            let a = element(a);
+            prop_assume!(!bool::from(a.is_zero()));
+            prop_assert_eq!(a.mul(&a.invert().unwrap()), Bls12381Fp2::ONE);
+            prop_assert_eq!(a.mul(&a.conjugate()).c1(), Bls12381Fp::ZERO);
+        }
+    }
+}
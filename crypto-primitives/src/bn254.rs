@@ -1,15 +1,89 @@
-use crate::utils::{AffinePoint, WeierstrassAffinePoint, WeierstrassPoint};
+use crate::{
+    uint256::U256,
+    utils::{AffinePoint, WeierstrassAffinePoint, WeierstrassPoint},
+};
 use ceno_syscall::{syscall_bn254_add, syscall_bn254_double};
+use std::fmt;
+
+/// The base field of the Bn254 curve.
+pub mod fp;
+pub use fp::Bn254Fp;
+
+/// The quadratic extension of the Bn254 base field.
+pub mod fp2;
+pub use fp2::Bn254Fp2;
+
+/// The sextic extension of the Bn254 base field.
+pub mod fp6;
+pub use fp6::Bn254Fp6;
+
+/// The degree 12 extension of the Bn254 base field.
+pub mod fp12;
+pub use fp12::Bn254Fp12;
+
+/// The G2 group of the Bn254 pairing, on the twist over [Bn254Fp2].
+pub mod g2;
+pub use g2::Bn254G2Point;
+
+/// The optimal ate pairing.
+pub mod pairing;
+pub use pairing::{Gt, multi_pairing, pairing};
 
 /// The number of limbs in [Bn254AffinePoint].
 pub const N: usize = 16;
 
+/// The order of the Bn254 G1 group, as little endian words.
+///
+/// The curve has cofactor 1, so this is also the number of points on the curve.
+pub const ORDER: [u32; fp::N] = [
+    0xf0000001, 0x43e1f593, 0x79b97091, 0x2833e848, 0x8181585d, 0xb85045b6, 0xe131a029, 0x30644e72,
+];
+
+/// The `b` coefficient of the curve equation `y^2 = x^3 + 3`.
+const EQUATION_B: Bn254Fp = Bn254Fp([3, 0, 0, 0, 0, 0, 0, 0]);
+
 /// A point on the Bn254 curve.
 #[derive(Copy, Clone)]
 #[repr(align(4))]
 pub struct Bn254Point(pub WeierstrassPoint<N>);
 
+impl Bn254Point {
+    /// Returns true if the point is the identity, or has reduced coordinates satisfying
+    /// `y^2 = x^3 + 3`.
+    ///
+    /// Points from untrusted input should be checked before they are used, as the precompiles
+    /// assume their inputs are on the curve.
+    pub fn is_on_curve(&self) -> bool {
+        let WeierstrassPoint::Affine(limbs) = &self.0 else {
+            return true;
+        };
+
+        let x = Bn254Fp::new(limbs[..N / 2].try_into().unwrap());
+        let y = Bn254Fp::new(limbs[N / 2..].try_into().unwrap());
+        if !bool::from(x.is_reduced() & y.is_reduced()) {
+            return false;
+        }
+
+        y.mul(&y) == x.mul(&x).mul(&x).add(&EQUATION_B)
+    }
+
+    /// Returns true if the point is in the prime order subgroup of [ORDER].
+    ///
+    /// As G1 has cofactor 1, this is the same as [`Bn254Point::is_on_curve`].
+    pub fn is_in_subgroup(&self) -> bool {
+        self.is_on_curve()
+    }
+}
+
+// A swapped coordinate or limb order in `GENERATOR` fails to compile.
+const _: () = assert!(Bn254Point::GENERATOR.0.has_be_hex_coordinates(
+    "0000000000000000000000000000000000000000000000000000000000000001",
+    "0000000000000000000000000000000000000000000000000000000000000002",
+));
+
 impl WeierstrassAffinePoint<N> for Bn254Point {
+    const MODULUS: &'static [u32] = &fp::MODULUS;
+
     fn infinity() -> Self {
         Self(WeierstrassPoint::Infinity)
     }
@@ -19,6 +93,35 @@ impl WeierstrassAffinePoint<N> for Bn254Point {
     }
 }
 
+impl fmt::Debug for Bn254Point {
+    /// Formats the coordinates as big endian hex, or `Infinity` for the identity.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(limbs) = self.0.limbs() else {
+            return f.write_str("Bn254Point(Infinity)");
+        };
+
+        // Each coordinate is stored as the little endian words of a `U256`.
+        let x = U256::from_words(limbs[..N / 2].try_into().unwrap());
+        let y = U256::from_words(limbs[N / 2..].try_into().unwrap());
+        f.debug_struct("Bn254Point")
+            .field("x", &format_args!("{x:#x}"))
+            .field("y", &format_args!("{y:#x}"))
+            .finish()
+    }
+}
+
+impl AsRef<WeierstrassPoint<N>> for Bn254Point {
+    fn as_ref(&self) -> &WeierstrassPoint<N> {
+        &self.0
+    }
+}
+
+impl AsMut<WeierstrassPoint<N>> for Bn254Point {
+    fn as_mut(&mut self) -> &mut WeierstrassPoint<N> {
+        &mut self.0
+    }
+}
+
 impl AffinePoint<N> for Bn254Point {
     const GENERATOR: Self = Self(WeierstrassPoint::Affine([
         1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
@@ -59,3 +162,77 @@ impl AffinePoint<N> for Bn254Point {
         syscall_bn254_double(a);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generator_matches_canonical_coordinates() {
+        let mut bytes = [0u8; 64];
+        bytes[31] = 1;
+        bytes[63] = 2;
+        let generator = Bn254Point::from_be_bytes(&bytes);
+        assert_eq!(generator.limbs(), Bn254Point::GENERATOR.limbs());
+    }
+
+    #[test]
+    fn generator_has_the_group_order() {
+        let mut point = Bn254Point::GENERATOR;
+        point.mul_assign(&ORDER);
+        assert!(point.is_identity());
+
+        // One less than the order gives the negation instead.
+        let mut order_minus_one = ORDER;
+        order_minus_one[0] -= 1;
+        let mut point = Bn254Point::GENERATOR;
+        point.mul_assign(&order_minus_one);
+        let mut negated = Bn254Point::GENERATOR;
+        negated.negate_y();
+        assert_eq!(point.limbs(), negated.limbs());
+    }
+
+    #[test]
+    fn generator_is_on_curve() {
+        assert!(Bn254Point::GENERATOR.is_on_curve());
+        assert!(Bn254Point::GENERATOR.is_in_subgroup());
+        assert!(Bn254Point::infinity().is_on_curve());
+    }
+
+    #[test]
+    fn tampered_points_are_not_on_curve() {
+        let mut point = Bn254Point::GENERATOR;
+        point.limbs_mut()[N / 2] ^= 1;
+        assert!(!point.is_on_curve());
+        assert!(!point.is_in_subgroup());
+
+        // An unreduced coordinate is rejected even if it is congruent to a valid one.
+        let mut unreduced = Bn254Point::GENERATOR;
+        let mut carry = 0u64;
+        for (limb, &modulus) in unreduced.limbs_mut()[..N / 2]
+            .iter_mut()
+            .zip(fp::MODULUS.iter())
+        {
+            let sum = *limb as u64 + modulus as u64 + carry;
+            *limb = sum as u32;
+            carry = sum >> 32;
+        }
+        assert!(!unreduced.is_on_curve());
+    }
+
+    #[test]
+    fn debug_prints_big_endian_coordinates() {
+        // The generator is `(1, 2)`.
+        let one = format!("{:#x}", U256::ONE);
+        let two = format!("{:#x}", U256::from_u64(2));
+        assert_eq!(
+            format!("{:?}", Bn254Point::GENERATOR),
+            format!("Bn254Point {{ x: {one}, y: {two} }}")
+        );
+        assert!(format!("{:#?}", Bn254Point::GENERATOR).contains(&format!("y: {two}")));
+        assert_eq!(
+            format!("{:?}", Bn254Point::infinity()),
+            "Bn254Point(Infinity)"
+        );
+    }
+}
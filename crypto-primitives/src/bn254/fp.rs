@@ -0,0 +1,162 @@
+//! Arithmetic in the base field of the Bn254 curve, accelerated with the Fp precompiles.
+
+use ceno_syscall::{syscall_bn254_fp_addmod, syscall_bn254_fp_mulmod};
+use elliptic_curve::subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+/// The number of limbs in [Bn254Fp].
+pub const N: usize = 8;
+
+/// The modulus of the Bn254 base field, as little endian words.
+pub const MODULUS: [u32; N] = [
+    0xd87cfd47, 0x3c208c16, 0x6871ca8d, 0x97816a91, 0x8181585d, 0xb85045b6, 0xe131a029, 0x30644e72,
+];
+
+/// The exponent `p - 2` used to invert via Fermat's little theorem, as little endian words.
+const MODULUS_MINUS_TWO: [u32; N] = [
+    0xd87cfd45, 0x3c208c16, 0x6871ca8d, 0x97816a91, 0x8181585d, 0xb85045b6, 0xe131a029, 0x30644e72,
+];
+
+/// An element of the Bn254 base field.
+///
+/// The element is stored as little endian words and is always reduced modulo [MODULUS], which is
+/// the representation expected by the precompiles.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(align(4))]
+pub struct Bn254Fp(pub [u32; N]);
+
+impl Bn254Fp {
+    /// The additive identity.
+    pub const ZERO: Self = Self([0; N]);
+
+    /// The multiplicative identity.
+    pub const ONE: Self = Self([1, 0, 0, 0, 0, 0, 0, 0]);
+
+    /// Creates a new [`Bn254Fp`] from the given limbs, which must already be reduced.
+    pub fn new(limbs: [u32; N]) -> Self {
+        Self(limbs)
+    }
+
+    /// Returns true if `self` is zero.
+    pub fn is_zero(&self) -> Choice {
+        self.0.ct_eq(&[0; N])
+    }
+
+    /// Returns true if the limbs of `self` are smaller than [MODULUS], which holds for every
+    /// element built by the arithmetic here but not necessarily for untrusted limbs.
+    pub fn is_reduced(&self) -> Choice {
+        let mut borrow = false;
+        for (limb, modulus) in self.0.iter().zip(MODULUS.iter()) {
+            let (diff, b1) = limb.overflowing_sub(*modulus);
+            let (_, b2) = diff.overflowing_sub(borrow as u32);
+            borrow = b1 | b2;
+        }
+        Choice::from(borrow as u8)
+    }
+
+    /// Computes `self + other`.
+    pub fn add(&self, other: &Bn254Fp) -> Bn254Fp {
+        let mut res = *self;
+        syscall_bn254_fp_addmod(&mut res.0, &other.0);
+        res
+    }
+
+    /// Computes `self * other`.
+    pub fn mul(&self, other: &Bn254Fp) -> Bn254Fp {
+        let mut res = *self;
+        syscall_bn254_fp_mulmod(&mut res.0, &other.0);
+        res
+    }
+
+    /// Computes `-self` in constant time.
+    ///
+    /// Negating zero yields zero rather than the modulus, so the result is always reduced.
+    pub fn neg(&self) -> Bn254Fp {
+        let is_zero = self.is_zero();
+        let mut res = [0u32; N];
+        let mut borrow = false;
+        for (i, limb) in res.iter_mut().enumerate() {
+            let (diff, b1) = MODULUS[i].overflowing_sub(self.0[i]);
+            let (diff, b2) = diff.overflowing_sub(borrow as u32);
+            *limb = u32::conditional_select(&diff, &0, is_zero);
+            borrow = b1 | b2;
+        }
+
+        Self(res)
+    }
+
+    /// Computes `self - other` as `self + (-other)` with the add precompile.
+    pub fn sub(&self, other: &Bn254Fp) -> Bn254Fp {
+        self.add(&other.neg())
+    }
+
+    /// Computes `self^exp`, where `exp` is given as little endian words.
+    ///
+    /// This is a square-and-multiply over the mulmod precompile and is variable time in `exp`.
+    pub fn pow(&self, exp: &[u32]) -> Bn254Fp {
+        let mut res = Self::ONE;
+        for word in exp.iter().rev() {
+            for i in (0..32).rev() {
+                res = res.mul(&res);
+                if (word >> i) & 1 == 1 {
+                    res = res.mul(self);
+                }
+            }
+        }
+        res
+    }
+
+    /// Computes `self^-1` as `self^(p - 2)`, returning none if `self` is zero.
+    pub fn invert(&self) -> CtOption<Bn254Fp> {
+        CtOption::new(self.pow(&MODULUS_MINUS_TWO), !self.is_zero())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Returns the reduced element with the given limbs, clearing the top bits so that it is
+    /// below the modulus.
+    fn element(mut limbs: [u32; N]) -> Bn254Fp {
+        limbs[N - 1] &= 0x0fff_ffff;
+        Bn254Fp::new(limbs)
+    }
+
+    #[test]
+    fn negating_zero_yields_zero() {
+        assert_eq!(Bn254Fp::ZERO.neg(), Bn254Fp::ZERO);
+        assert_eq!(Bn254Fp::ONE.sub(&Bn254Fp::ZERO), Bn254Fp::ONE);
+        assert_eq!(Bn254Fp::ONE.sub(&Bn254Fp::ONE), Bn254Fp::ZERO);
+
+        let mut minus_one = MODULUS;
+        minus_one[0] -= 1;
+        assert_eq!(Bn254Fp::ZERO.sub(&Bn254Fp::ONE), Bn254Fp::new(minus_one));
+    }
+
+    #[test]
+    fn inverting_zero_yields_none() {
+        assert!(bool::from(Bn254Fp::ZERO.invert().is_none()));
+        assert_eq!(Bn254Fp::ONE.invert().unwrap(), Bn254Fp::ONE);
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn sub_undoes_add(a in any::<[u32; N]>(), b in any::<[u32; N]>()) {
+            let (a, b) = (element(a), element(b));
+            let diff = a.sub(&b);
+            prop_assert!(bool::from(diff.is_reduced()));
+            prop_assert_eq!(diff.add(&b), a);
+            prop_assert_eq!(a.add(&b).sub(&b), a);
+        }
+
+        #[test]
+        fn invert_is_the_multiplicative_inverse(a in any::<[u32; N]>()) {
+            let a = element(a);
+            prop_assume!(!bool::from(a.is_zero()));
+            prop_assert_eq!(a.mul(&a.invert().unwrap()), Bn254Fp::ONE);
+        }
+    }
+}
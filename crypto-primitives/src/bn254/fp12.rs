@@ -0,0 +1,174 @@
+//! Arithmetic in the degree 12 extension `Fp6[w] / (w^2 - v)` of the Bn254 base field, the
+//! target field of the pairing.
+
+use super::{Bn254Fp2, Bn254Fp6};
+use elliptic_curve::subtle::CtOption;
+
+/// The constants `(9 + u)^(i · (p - 1) / 6)`, so that the Frobenius map sends `w^i` to
+/// `FROBENIUS_COEFFS[i] · w^i`.
+pub(crate) const FROBENIUS_COEFFS: [Bn254Fp2; 6] = [
+    Bn254Fp2::ONE,
+    Bn254Fp2([
+        0xdcc9e470, 0xd60b35da, 0x292f2176, 0x5c521e08, 0x76e68b60, 0xe8b99fdd, 0x2865a7df,
+        0x1284b71c, 0x80f362ac, 0xca5cf05f, 0x8eeec7e5, 0x74799277, 0x12150b8e, 0xa6327cfe,
+        0xb4fae7e6, 0x246996f3,
+    ]),
+    Bn254Fp2([
+        0x176f553d, 0x99e39557, 0xc2c3330c, 0xb78cc310, 0xf559b143, 0x4c0bec3c, 0x4f7911f7,
+        0x2fb34798, 0x640fcba2, 0x1665d51c, 0x0b7c9dce, 0x32ae2a1d, 0xd75a0794, 0x4ba4cc8b,
+        0x61ebae20, 0x16c9e550,
+    ]),
+    Bn254Fp2([
+        0x71a0135a, 0xdc540146, 0xa9c95998, 0xdbaae0ed, 0xb6e2f9b9, 0xdc5ec698, 0x489af5dc,
+        0x063cf305, 0x2623b0e3, 0x82d37f63, 0x8fa25bd2, 0x21807dc9, 0xec796f2b, 0x0704b5a7,
+        0xac41049a, 0x07c03cbc,
+    ]),
+    Bn254Fp2([
+        0x921ea762, 0x848a1f55, 0xbe94ec72, 0xd33365f7, 0x5a181e84, 0x80f3c0b7, 0x64eea801,
+        0x05b54f5e, 0xcd2b8126, 0xc13b4711, 0x1bdec763, 0x3685d2ea, 0x3b0b1c92, 0x9f3a80b0,
+        0xe7fd8aee, 0x2c145edb,
+    ]),
+    Bn254Fp2([
+        0xeab7692f, 0x2ea2c810, 0x55aa1bd3, 0x425c459b, 0xa4353ff4, 0xe93a3661, 0x4f798649,
+        0x0183c1e7, 0x6e0c2c4b, 0x24c6b8ee, 0x678e2ac0, 0xb080cb99, 0xc7729f7d, 0xa27fb246,
+        0x76fd0675, 0x12acf2ca,
+    ]),
+];
+
+/// An element `c0 + c1 · w` of the Bn254 degree 12 extension field, where `w^2 = v`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Bn254Fp12 {
+    pub c0: Bn254Fp6,
+    pub c1: Bn254Fp6,
+}
+
+impl Bn254Fp12 {
+    /// The additive identity.
+    pub const ZERO: Self = Self::new(Bn254Fp6::ZERO, Bn254Fp6::ZERO);
+
+    /// The multiplicative identity.
+    pub const ONE: Self = Self::new(Bn254Fp6::ONE, Bn254Fp6::ZERO);
+
+    /// Creates the element `c0 + c1 · w`.
+    pub const fn new(c0: Bn254Fp6, c1: Bn254Fp6) -> Self {
+        Self { c0, c1 }
+    }
+
+    /// Computes `self + other`.
+    pub fn add(&self, other: &Bn254Fp12) -> Bn254Fp12 {
+        Self::new(self.c0.add(&other.c0), self.c1.add(&other.c1))
+    }
+
+    /// Computes `self - other`.
+    pub fn sub(&self, other: &Bn254Fp12) -> Bn254Fp12 {
+        Self::new(self.c0.sub(&other.c0), self.c1.sub(&other.c1))
+    }
+
+    /// Computes `self * other`.
+    pub fn mul(&self, other: &Bn254Fp12) -> Bn254Fp12 {
+        let c0 = self
+            .c0
+            .mul(&other.c0)
+            .add(&self.c1.mul(&other.c1).mul_by_nonresidue());
+        let c1 = self.c0.mul(&other.c1).add(&self.c1.mul(&other.c0));
+        Self::new(c0, c1)
+    }
+
+    /// Computes `self^2`.
+    pub fn square(&self) -> Bn254Fp12 {
+        self.mul(self)
+    }
+
+    /// Computes the conjugate `c0 - c1 · w`, which is `self^(p^6)`.
+    pub fn conjugate(&self) -> Bn254Fp12 {
+        Self::new(self.c0, self.c1.neg())
+    }
+
+    /// Computes the Frobenius map `self^p`.
+    pub fn frobenius_map(&self) -> Bn254Fp12 {
+        let c0 = Bn254Fp6::new(
+            self.c0.c0.conjugate(),
+            self.c0.c1.conjugate().mul(&FROBENIUS_COEFFS[2]),
+            self.c0.c2.conjugate().mul(&FROBENIUS_COEFFS[4]),
+        );
+        let c1 = Bn254Fp6::new(
+            self.c1.c0.conjugate().mul(&FROBENIUS_COEFFS[1]),
+            self.c1.c1.conjugate().mul(&FROBENIUS_COEFFS[3]),
+            self.c1.c2.conjugate().mul(&FROBENIUS_COEFFS[5]),
+        );
+        Self::new(c0, c1)
+    }
+
+    /// Computes `self^-1` as `conjugate(self) / (c0^2 - c1^2 · v)`, returning none if `self` is
+    /// zero.
+    pub fn invert(&self) -> CtOption<Bn254Fp12> {
+        let norm = self.c0.square().sub(&self.c1.square().mul_by_nonresidue());
+
+        // The norm is zero only if `self` is zero.
+        let norm_inv = Option::<Bn254Fp6>::from(norm.invert()).unwrap_or(Bn254Fp6::ZERO);
+        CtOption::new(
+            Self::new(self.c0.mul(&norm_inv), self.c1.neg().mul(&norm_inv)),
+            !norm.is_zero(),
+        )
+    }
+
+    /// Computes `self^exp`, where `exp` is given as little endian words.
+    ///
+    /// This is a square-and-multiply and is variable time in `exp`.
+    pub fn pow(&self, exp: &[u32]) -> Bn254Fp12 {
+        let mut res = Self::ONE;
+        for word in exp.iter().rev() {
+            for i in (0..32).rev() {
+                res = res.square();
+                if (word >> i) & 1 == 1 {
+                    res = res.mul(self);
+                }
+            }
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bn254::{Bn254Fp, fp::MODULUS};
+
+    /// Returns an element with small, distinct coefficients.
+    fn sample() -> Bn254Fp12 {
+        let fp2 = |a: u32| {
+            Bn254Fp2::new(
+                Bn254Fp::new([a, 0, 0, 0, 0, 0, 0, 0]),
+                Bn254Fp::new([a + 1, 0, 0, 0, 0, 0, 0, 0]),
+            )
+        };
+        Bn254Fp12::new(
+            Bn254Fp6::new(fp2(1), fp2(3), fp2(5)),
+            Bn254Fp6::new(fp2(7), fp2(11), fp2(13)),
+        )
+    }
+
+    #[test]
+    fn w_squared_is_v() {
+        let w = Bn254Fp12::new(Bn254Fp6::ZERO, Bn254Fp6::ONE);
+        let v = Bn254Fp6::new(Bn254Fp2::ZERO, Bn254Fp2::ONE, Bn254Fp2::ZERO);
+        assert_eq!(w.square(), Bn254Fp12::new(v, Bn254Fp6::ZERO));
+    }
+
+    #[test]
+    fn invert_is_the_multiplicative_inverse() {
+        let a = sample();
+        assert_eq!(a.mul(&a.invert().unwrap()), Bn254Fp12::ONE);
+        assert!(bool::from(Bn254Fp12::ZERO.invert().is_none()));
+    }
+
+    #[test]
+    fn frobenius_map_is_the_p_th_power() {
+        let a = sample();
+        assert_eq!(a.frobenius_map(), a.pow(&MODULUS));
+
+        // The sixth power of the Frobenius map is the conjugation.
+        let sixth = (0..6).fold(a, |x, _| x.frobenius_map());
+        assert_eq!(sixth, a.conjugate());
+    }
+}
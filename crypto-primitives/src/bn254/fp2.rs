@@ -0,0 +1,164 @@
+//! Arithmetic in the quadratic extension `Fp[u] / (u^2 + 1)` of the Bn254 base field,
+//! accelerated with the Fp2 precompiles.
+
+use super::fp::{self, Bn254Fp};
+use ceno_syscall::{syscall_bn254_fp2_addmod, syscall_bn254_fp2_mulmod};
+use elliptic_curve::subtle::{Choice, CtOption};
+
+/// The number of limbs in [Bn254Fp2].
+pub const N: usize = 2 * fp::N;
+
+/// The non-residue `9 + u` used to build the sextic extension of [Bn254Fp2].
+const NONRESIDUE: Bn254Fp2 = Bn254Fp2([9, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0]);
+
+/// An element `c0 + c1 · u` of the Bn254 quadratic extension field, where `u^2 = -1`.
+///
+/// The element is stored as the little endian words of `c0` followed by those of `c1`, which is
+/// the representation expected by the precompiles.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(align(4))]
+pub struct Bn254Fp2(pub [u32; N]);
+
+impl Bn254Fp2 {
+    /// The additive identity.
+    pub const ZERO: Self = Self([0; N]);
+
+    /// The multiplicative identity.
+    pub const ONE: Self = Self([1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+    /// Creates the element `c0 + c1 · u`.
+    pub fn new(c0: Bn254Fp, c1: Bn254Fp) -> Self {
+        let mut limbs = [0u32; N];
+        limbs[..fp::N].copy_from_slice(&c0.0);
+        limbs[fp::N..].copy_from_slice(&c1.0);
+        Self(limbs)
+    }
+
+    /// Returns the real part `c0`.
+    pub fn c0(&self) -> Bn254Fp {
+        Bn254Fp::new(self.0[..fp::N].try_into().unwrap())
+    }
+
+    /// Returns the imaginary part `c1`.
+    pub fn c1(&self) -> Bn254Fp {
+        Bn254Fp::new(self.0[fp::N..].try_into().unwrap())
+    }
+
+    /// Returns true if `self` is zero.
+    pub fn is_zero(&self) -> Choice {
+        self.c0().is_zero() & self.c1().is_zero()
+    }
+
+    /// Returns true if both parts of `self` are reduced, see [`Bn254Fp::is_reduced`].
+    pub fn is_reduced(&self) -> Choice {
+        self.c0().is_reduced() & self.c1().is_reduced()
+    }
+
+    /// Computes `self + other`.
+    pub fn add(&self, other: &Bn254Fp2) -> Bn254Fp2 {
+        let mut res = *self;
+        syscall_bn254_fp2_addmod(&mut res.0, &other.0);
+        res
+    }
+
+    /// Computes `self * other`.
+    pub fn mul(&self, other: &Bn254Fp2) -> Bn254Fp2 {
+        let mut res = *self;
+        syscall_bn254_fp2_mulmod(&mut res.0, &other.0);
+        res
+    }
+
+    /// Computes `self^2`.
+    pub fn square(&self) -> Bn254Fp2 {
+        self.mul(self)
+    }
+
+    /// Computes `-self` in constant time.
+    pub fn neg(&self) -> Bn254Fp2 {
+        Self::new(self.c0().neg(), self.c1().neg())
+    }
+
+    /// Computes `self - other` as `self + (-other)` with the add precompile.
+    pub fn sub(&self, other: &Bn254Fp2) -> Bn254Fp2 {
+        self.add(&other.neg())
+    }
+
+    /// Computes the conjugate `c0 - c1 · u`, which is also the Frobenius map of `self`.
+    pub fn conjugate(&self) -> Bn254Fp2 {
+        Self::new(self.c0(), self.c1().neg())
+    }
+
+    /// Computes `self * (9 + u)`.
+    pub fn mul_by_nonresidue(&self) -> Bn254Fp2 {
+        self.mul(&NONRESIDUE)
+    }
+
+    /// Computes `self * scalar` for an element of the base field.
+    pub fn mul_by_fp(&self, scalar: &Bn254Fp) -> Bn254Fp2 {
+        Self::new(self.c0().mul(scalar), self.c1().mul(scalar))
+    }
+
+    /// Computes `self^-1` as `conjugate(self) / (c0^2 + c1^2)`, returning none if `self` is zero.
+    ///
+    /// The norm is inverted in the base field, so this costs a single base field inversion.
+    pub fn invert(&self) -> CtOption<Bn254Fp2> {
+        let (c0, c1) = (self.c0(), self.c1());
+        let norm = c0.mul(&c0).add(&c1.mul(&c1));
+        // As -1 is not a square modulo p, the norm is zero only if `self` is zero.
+        let norm_inv = Option::<Bn254Fp>::from(norm.invert()).unwrap_or(Bn254Fp::ZERO);
+        CtOption::new(self.conjugate().mul_by_fp(&norm_inv), !self.is_zero())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Returns the reduced element with the given limbs, clearing the top bits of both parts so
+    /// that they are below the modulus.
+    fn element(mut limbs: [u32; N]) -> Bn254Fp2 {
+        limbs[fp::N - 1] &= 0x0fff_ffff;
+        limbs[N - 1] &= 0x0fff_ffff;
+        Bn254Fp2(limbs)
+    }
+
+    #[test]
+    fn inverting_zero_yields_none() {
+        assert!(bool::from(Bn254Fp2::ZERO.invert().is_none()));
+        assert_eq!(Bn254Fp2::ONE.invert().unwrap(), Bn254Fp2::ONE);
+    }
+
+    #[test]
+    fn u_squared_is_minus_one() {
+        let u = Bn254Fp2::new(Bn254Fp::ZERO, Bn254Fp::ONE);
+        assert_eq!(u.square(), Bn254Fp2::ONE.neg());
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        #[test]
+        fn mul_matches_the_schoolbook_product(a in any::<[u32; N]>(), b in any::<[u32; N]>()) {
+            let (a, b) = (element(a), element(b));
+            let (a0, a1, b0, b1) = (a.c0(), a.c1(), b.c0(), b.c1());
+            let expected = Bn254Fp2::new(
+                a0.mul(&b0).sub(&a1.mul(&b1)),
+                a0.mul(&b1).add(&a1.mul(&b0)),
+            );
+            prop_assert_eq!(a.mul(&b), expected);
+            prop_assert_eq!(a.sub(&b).add(&b), a);
+            prop_assert_eq!(a.mul_by_nonresidue(), a.mul(&NONRESIDUE));
+            prop_assert_eq!(a.mul_by_fp(&b0), a.mul(&Bn254Fp2::new(b0, Bn254Fp::ZERO)));
+        }
+
+        #[test]
+        fn invert_is_the_multiplicative_inverse(a in any::<[u32; N]>()) {
+            let a = element(a);
+            prop_assume!(!bool::from(a.is_zero()));
+            prop_assert_eq!(a.mul(&a.invert().unwrap()), Bn254Fp2::ONE);
+            // The norm `a · conj(a)` lies in the base field.
+            prop_assert_eq!(a.mul(&a.conjugate()).c1(), Bn254Fp::ZERO);
+        }
+    }
+}
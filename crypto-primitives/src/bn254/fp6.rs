@@ -0,0 +1,145 @@
+//! Arithmetic in the sextic extension `Fp2[v] / (v^3 - (9 + u))` of the Bn254 base field.
+
+use super::Bn254Fp2;
+use elliptic_curve::subtle::{Choice, CtOption};
+
+/// An element `c0 + c1 · v + c2 · v^2` of the Bn254 sextic extension field, where
+/// `v^3 = 9 + u`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Bn254Fp6 {
+    pub c0: Bn254Fp2,
+    pub c1: Bn254Fp2,
+    pub c2: Bn254Fp2,
+}
+
+impl Bn254Fp6 {
+    /// The additive identity.
+    pub const ZERO: Self = Self::new(Bn254Fp2::ZERO, Bn254Fp2::ZERO, Bn254Fp2::ZERO);
+
+    /// The multiplicative identity.
+    pub const ONE: Self = Self::new(Bn254Fp2::ONE, Bn254Fp2::ZERO, Bn254Fp2::ZERO);
+
+    /// Creates the element `c0 + c1 · v + c2 · v^2`.
+    pub const fn new(c0: Bn254Fp2, c1: Bn254Fp2, c2: Bn254Fp2) -> Self {
+        Self { c0, c1, c2 }
+    }
+
+    /// Returns true if `self` is zero.
+    pub fn is_zero(&self) -> Choice {
+        self.c0.is_zero() & self.c1.is_zero() & self.c2.is_zero()
+    }
+
+    /// Computes `self + other`.
+    pub fn add(&self, other: &Bn254Fp6) -> Bn254Fp6 {
+        Self::new(
+            self.c0.add(&other.c0),
+            self.c1.add(&other.c1),
+            self.c2.add(&other.c2),
+        )
+    }
+
+    /// Computes `self - other`.
+    pub fn sub(&self, other: &Bn254Fp6) -> Bn254Fp6 {
+        Self::new(
+            self.c0.sub(&other.c0),
+            self.c1.sub(&other.c1),
+            self.c2.sub(&other.c2),
+        )
+    }
+
+    /// Computes `-self`.
+    pub fn neg(&self) -> Bn254Fp6 {
+        Self::new(self.c0.neg(), self.c1.neg(), self.c2.neg())
+    }
+
+    /// Computes `self * other`.
+    pub fn mul(&self, other: &Bn254Fp6) -> Bn254Fp6 {
+        let (a0, a1, a2) = (&self.c0, &self.c1, &self.c2);
+        let (b0, b1, b2) = (&other.c0, &other.c1, &other.c2);
+
+        let c0 = a0
+            .mul(b0)
+            .add(&a1.mul(b2).add(&a2.mul(b1)).mul_by_nonresidue());
+        let c1 = a0
+            .mul(b1)
+            .add(&a1.mul(b0))
+            .add(&a2.mul(b2).mul_by_nonresidue());
+        let c2 = a0.mul(b2).add(&a1.mul(b1)).add(&a2.mul(b0));
+        Self::new(c0, c1, c2)
+    }
+
+    /// Computes `self^2`.
+    pub fn square(&self) -> Bn254Fp6 {
+        self.mul(self)
+    }
+
+    /// Computes `self * v`.
+    pub fn mul_by_nonresidue(&self) -> Bn254Fp6 {
+        Self::new(self.c2.mul_by_nonresidue(), self.c0, self.c1)
+    }
+
+    /// Computes `self * scalar` for an element of [Bn254Fp2].
+    pub fn mul_by_fp2(&self, scalar: &Bn254Fp2) -> Bn254Fp6 {
+        Self::new(
+            self.c0.mul(scalar),
+            self.c1.mul(scalar),
+            self.c2.mul(scalar),
+        )
+    }
+
+    /// Computes `self^-1`, returning none if `self` is zero.
+    ///
+    /// The inverse is the adjugate of `self` divided by its norm, which costs a single
+    /// [Bn254Fp2] inversion.
+    pub fn invert(&self) -> CtOption<Bn254Fp6> {
+        let (c0, c1, c2) = (&self.c0, &self.c1, &self.c2);
+
+        let a = c0.square().sub(&c1.mul(c2).mul_by_nonresidue());
+        let b = c2.square().mul_by_nonresidue().sub(&c0.mul(c1));
+        let c = c1.square().sub(&c0.mul(c2));
+        let norm = c0
+            .mul(&a)
+            .add(&c2.mul(&b).add(&c1.mul(&c)).mul_by_nonresidue());
+
+        // The norm is zero only if `self` is zero.
+        let norm_inv = Option::<Bn254Fp2>::from(norm.invert()).unwrap_or(Bn254Fp2::ZERO);
+        CtOption::new(Self::new(a, b, c).mul_by_fp2(&norm_inv), !norm.is_zero())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bn254::Bn254Fp;
+
+    /// Returns the element `a + b · u` for small `a` and `b`.
+    fn fp2(a: u32, b: u32) -> Bn254Fp2 {
+        Bn254Fp2::new(
+            Bn254Fp::new([a, 0, 0, 0, 0, 0, 0, 0]),
+            Bn254Fp::new([b, 0, 0, 0, 0, 0, 0, 0]),
+        )
+    }
+
+    #[test]
+    fn v_cubed_is_the_nonresidue() {
+        let v = Bn254Fp6::new(Bn254Fp2::ZERO, Bn254Fp2::ONE, Bn254Fp2::ZERO);
+        let nonresidue = Bn254Fp6::new(fp2(9, 1), Bn254Fp2::ZERO, Bn254Fp2::ZERO);
+        assert_eq!(v.square().mul(&v), nonresidue);
+
+        let a = Bn254Fp6::new(fp2(1, 2), fp2(3, 4), fp2(5, 6));
+        assert_eq!(a.mul_by_nonresidue(), a.mul(&v));
+    }
+
+    #[test]
+    fn invert_is_the_multiplicative_inverse() {
+        let a = Bn254Fp6::new(fp2(1, 2), fp2(3, 4), fp2(5, 6));
+        assert_eq!(a.mul(&a.invert().unwrap()), Bn254Fp6::ONE);
+        assert_eq!(a.square(), a.mul(&a));
+        assert_eq!(a.sub(&a), Bn254Fp6::ZERO);
+        assert_eq!(
+            a.mul_by_fp2(&fp2(7, 0)),
+            a.mul(&Bn254Fp6::new(fp2(7, 0), Bn254Fp2::ZERO, Bn254Fp2::ZERO))
+        );
+        assert!(bool::from(Bn254Fp6::ZERO.invert().is_none()));
+    }
+}
@@ -0,0 +1,219 @@
+//! Affine arithmetic on the Bn254 G2 twist `y^2 = x^3 + 3 / (9 + u)` over [Bn254Fp2].
+//!
+//! There are no precompiles for G2 points, so the group law is written out over the accelerated
+//! Fp2 arithmetic, with one field inversion per addition or doubling.
+
+use super::Bn254Fp2;
+
+/// The `b'` coefficient `3 / (9 + u)` of the twist equation.
+pub const TWIST_B: Bn254Fp2 = Bn254Fp2([
+    0x24a138e5, 0x3267e6dc, 0x59dbefa3, 0xb5b4c5e5, 0x1be06ac3, 0x81be1899, 0xceb8aaae, 0x2b149d40,
+    0x85c315d2, 0xe4a2bd06, 0xe52d1852, 0xa74fa084, 0xeed8fdf4, 0xcd2cafad, 0x3af0fed4, 0x009713b0,
+]);
+
+/// A point on the Bn254 G2 twist, in affine coordinates.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Bn254G2Point {
+    /// The point at infinity.
+    Infinity,
+    /// The point `(x, y)`.
+    Affine(Bn254Fp2, Bn254Fp2),
+}
+
+impl Bn254G2Point {
+    /// The standard generator of G2, as used by the Ethereum pairing precompile.
+    pub const GENERATOR: Self = Self::Affine(
+        Bn254Fp2([
+            0xd992f6ed, 0x46debd5c, 0xf75edadd, 0x674322d4, 0x5e5c4479, 0x426a0066, 0x121f1e76,
+            0x1800deef, 0xaef312c2, 0x97e485b7, 0x35a9e712, 0xf1aa4933, 0x31fb5d25, 0x7260bfb7,
+            0x920d483a, 0x198e9393,
+        ]),
+        Bn254Fp2([
+            0x66fa7daa, 0x4ce6cc01, 0x0c43d37b, 0xe3d1e769, 0x8dcb408f, 0x4aab7180, 0xdb8c6deb,
+            0x12c85ea5, 0xd122975b, 0x55acdadc, 0x70b38ef3, 0xbc4b3133, 0x690c3395, 0xec9e99ad,
+            0x585ff075, 0x090689d0,
+        ]),
+    );
+
+    /// Creates the point `(x, y)` without checking that it is on the twist.
+    pub fn new(x: Bn254Fp2, y: Bn254Fp2) -> Self {
+        Self::Affine(x, y)
+    }
+
+    /// Returns true if the point is the point at infinity.
+    pub fn is_infinity(&self) -> bool {
+        matches!(self, Self::Infinity)
+    }
+
+    /// Returns true if the point is the point at infinity, or has reduced coordinates satisfying
+    /// `y^2 = x^3 + b'`.
+    ///
+    /// This does not check membership of the prime order subgroup, as the twist has a large
+    /// cofactor.
+    pub fn is_on_curve(&self) -> bool {
+        let Self::Affine(x, y) = self else {
+            return true;
+        };
+        if !bool::from(x.is_reduced() & y.is_reduced()) {
+            return false;
+        }
+
+        y.square() == x.square().mul(x).add(&TWIST_B)
+    }
+
+    /// Computes `-self`.
+    pub fn neg(&self) -> Self {
+        match self {
+            Self::Infinity => Self::Infinity,
+            Self::Affine(x, y) => Self::Affine(*x, y.neg()),
+        }
+    }
+
+    /// Sets `self` to `self + other`, handling the point at infinity, doubling and the sum of a
+    /// point and its negation.
+    pub fn add_assign(&mut self, other: &Self) {
+        let (Self::Affine(x1, y1), Self::Affine(x2, y2)) = (*self, *other) else {
+            if self.is_infinity() {
+                *self = *other;
+            }
+            return;
+        };
+
+        if x1 == x2 {
+            match y1 == y2 {
+                true => self.double(),
+                false => *self = Self::Infinity,
+            }
+            return;
+        }
+
+        // The x coordinates differ, so the denominator is invertible.
+        let lambda = y2.sub(&y1).mul(&x2.sub(&x1).invert().unwrap());
+        *self = Self::chord(&lambda, &x1, &y1, &x2);
+    }
+
+    /// Sets `self` to `2 · self`, mapping points with `y = 0` and the point at infinity to the
+    /// point at infinity.
+    pub fn double(&mut self) {
+        let Self::Affine(x, y) = *self else {
+            return;
+        };
+
+        let Some(denominator) = Option::<Bn254Fp2>::from(y.add(&y).invert()) else {
+            *self = Self::Infinity;
+            return;
+        };
+
+        let x_squared = x.square();
+        let lambda = x_squared.add(&x_squared).add(&x_squared).mul(&denominator);
+        *self = Self::chord(&lambda, &x, &y, &x);
+    }
+
+    /// Returns the third intersection of the line of slope `lambda` through `(x1, y1)` and a
+    /// point with X coordinate `x2`, reflected over the X axis.
+    pub(super) fn chord(lambda: &Bn254Fp2, x1: &Bn254Fp2, y1: &Bn254Fp2, x2: &Bn254Fp2) -> Self {
+        let x3 = lambda.square().sub(x1).sub(x2);
+        let y3 = lambda.mul(&x1.sub(&x3)).sub(y1);
+        Self::Affine(x3, y3)
+    }
+
+    /// Multiplies `self` by the scalar given as little endian bits, with a variable time
+    /// double-and-add.
+    pub fn scalar_mul(&self, scalar_bits_le: &[bool]) -> Self {
+        let mut res = Self::Infinity;
+        for &bit in scalar_bits_le.iter().rev() {
+            res.double();
+            if bit {
+                res.add_assign(self);
+            }
+        }
+        res
+    }
+
+    /// Returns the coordinates of the point, or none for the point at infinity.
+    pub fn coordinates(&self) -> Option<(Bn254Fp2, Bn254Fp2)> {
+        match self {
+            Self::Infinity => None,
+            Self::Affine(x, y) => Some((*x, *y)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bn254::{Bn254Fp, ORDER};
+
+    /// Returns the little endian bits of the little endian `words`.
+    fn bits_le(words: &[u32]) -> Vec<bool> {
+        words
+            .iter()
+            .flat_map(|word| (0..32).map(move |i| (word >> i) & 1 == 1))
+            .collect()
+    }
+
+    #[test]
+    fn twist_b_is_three_over_the_nonresidue() {
+        let nonresidue = Bn254Fp2::new(Bn254Fp::new([9, 0, 0, 0, 0, 0, 0, 0]), Bn254Fp::ONE);
+        let three = Bn254Fp2::new(Bn254Fp::new([3, 0, 0, 0, 0, 0, 0, 0]), Bn254Fp::ZERO);
+        assert_eq!(TWIST_B.mul(&nonresidue), three);
+    }
+
+    #[test]
+    fn group_law_on_the_generator() {
+        let g = Bn254G2Point::GENERATOR;
+        assert!(g.is_on_curve());
+
+        let mut doubled = g;
+        doubled.double();
+        let mut added = g;
+        added.add_assign(&g);
+        assert_eq!(doubled, added);
+        assert!(doubled.is_on_curve());
+
+        // 2G + G == G + 2G == 3G
+        let mut tripled = doubled;
+        tripled.add_assign(&g);
+        let mut other = g;
+        other.add_assign(&doubled);
+        assert_eq!(tripled, other);
+        assert_eq!(tripled, g.scalar_mul(&bits_le(&[3])));
+        assert!(tripled.is_on_curve());
+
+        let mut zero = g;
+        zero.add_assign(&g.neg());
+        assert!(zero.is_infinity());
+    }
+
+    #[test]
+    fn point_at_infinity() {
+        let g = Bn254G2Point::GENERATOR;
+        let mut sum = Bn254G2Point::Infinity;
+        sum.add_assign(&g);
+        assert_eq!(sum, g);
+        sum.add_assign(&Bn254G2Point::Infinity);
+        assert_eq!(sum, g);
+
+        let mut doubled = Bn254G2Point::Infinity;
+        doubled.double();
+        assert!(doubled.is_infinity());
+        assert!(Bn254G2Point::Infinity.is_on_curve());
+        assert!(g.scalar_mul(&[]).is_infinity());
+    }
+
+    #[test]
+    fn generator_has_the_group_order() {
+        assert!(
+            Bn254G2Point::GENERATOR
+                .scalar_mul(&bits_le(&ORDER))
+                .is_infinity()
+        );
+    }
+
+    #[test]
+    fn tampered_points_are_off_the_curve() {
+        let (x, y) = Bn254G2Point::GENERATOR.coordinates().unwrap();
+        assert!(!Bn254G2Point::new(x, y.add(&Bn254Fp2::ONE)).is_on_curve());
+        assert!(!Bn254G2Point::new(x, Bn254Fp2([u32::MAX; 16])).is_on_curve());
+    }
+}
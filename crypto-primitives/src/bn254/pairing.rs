@@ -0,0 +1,236 @@
+//! The optimal ate pairing `e: G1 × G2 → Gt` of the Bn254 curve.
+//!
+//! The Miller loop runs over the affine G2 arithmetic of [Bn254G2Point], evaluating each line at
+//! the G1 point, and the final exponentiation raises the product to `(p^12 - 1) / r`. Every
+//! multiplication in the tower goes through the accelerated [Bn254Fp2] arithmetic.
+
+use super::{
+    Bn254Fp, Bn254Fp2, Bn254Fp6, Bn254Fp12, Bn254G2Point, Bn254Point, fp12::FROBENIUS_COEFFS,
+};
+use crate::utils::WeierstrassPoint;
+
+/// The Miller loop parameter `6x + 2`, where `x = 4965661367192848881` is the curve seed.
+const ATE_LOOP_COUNT: u128 = 29793968203157093288;
+
+/// The hard part `(p^4 - p^2 + 1) / r` of the final exponent, as little endian words.
+const FINAL_EXP_HARD: [u32; 24] = [
+    0xccdf42b1, 0xe81bb482, 0xf49c36d4, 0x5abf5cc4, 0x1da014fd, 0xf1154e7e, 0x87cdbacf, 0xdcc7b44c,
+    0x954bcf8a, 0xaaa441e3, 0xd5095f23, 0x6b887d56, 0xf3fd90c6, 0x79581e16, 0xd189227d, 0x3b1b1355,
+    0x61876f6b, 0x4e529a58, 0xd5b12278, 0x6c0eb522, 0x83177faf, 0x331ec151, 0x0b0759ad, 0x01baaa71,
+];
+
+/// An element of the target group of the pairing, the order `r` subgroup of [Bn254Fp12].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Gt(pub Bn254Fp12);
+
+impl Gt {
+    /// Returns the identity of the group.
+    pub fn identity() -> Self {
+        Self(Bn254Fp12::ONE)
+    }
+
+    /// Returns true if `self` is the identity.
+    pub fn is_identity(&self) -> bool {
+        self.0 == Bn254Fp12::ONE
+    }
+
+    /// Computes the group operation `self · other`.
+    pub fn mul(&self, other: &Gt) -> Gt {
+        Self(self.0.mul(&other.0))
+    }
+
+    /// Computes `self^exp`, where `exp` is given as little endian words, see
+    /// [`Bn254Fp12::pow`].
+    pub fn pow(&self, exp: &[u32]) -> Gt {
+        Self(self.0.pow(exp))
+    }
+}
+
+/// Creates the line `y_p - λ · x_p · w + (λ · x_t - y_t) · w^3` through `(x_t, y_t)` with slope
+/// `λ`, evaluated at the untwisted image of `(x_p, y_p)`.
+fn line(lambda: &Bn254Fp2, x_t: &Bn254Fp2, y_t: &Bn254Fp2, p: &(Bn254Fp, Bn254Fp)) -> Bn254Fp12 {
+    let (x_p, y_p) = p;
+    Bn254Fp12::new(
+        Bn254Fp6::new(
+            Bn254Fp2::new(*y_p, Bn254Fp::ZERO),
+            Bn254Fp2::ZERO,
+            Bn254Fp2::ZERO,
+        ),
+        Bn254Fp6::new(
+            lambda.mul_by_fp(x_p).neg(),
+            lambda.mul(x_t).sub(y_t),
+            Bn254Fp2::ZERO,
+        ),
+    )
+}
+
+/// Doubles `t`, returning the tangent line at `t` evaluated at `p`.
+///
+/// Vertical lines lie in a subfield and are erased by the final exponentiation, so they are
+/// returned as one.
+fn double_step(t: &mut Bn254G2Point, p: &(Bn254Fp, Bn254Fp)) -> Bn254Fp12 {
+    let Bn254G2Point::Affine(x, y) = *t else {
+        return Bn254Fp12::ONE;
+    };
+    let Some(denominator) = Option::<Bn254Fp2>::from(y.add(&y).invert()) else {
+        t.double();
+        return Bn254Fp12::ONE;
+    };
+
+    let x_squared = x.square();
+    let lambda = x_squared.add(&x_squared).add(&x_squared).mul(&denominator);
+    *t = Bn254G2Point::chord(&lambda, &x, &y, &x);
+    line(&lambda, &x, &y, p)
+}
+
+/// Sets `t` to `t + q`, returning the line through `t` and `q` evaluated at `p`.
+fn add_step(t: &mut Bn254G2Point, q: &Bn254G2Point, p: &(Bn254Fp, Bn254Fp)) -> Bn254Fp12 {
+    let (Bn254G2Point::Affine(x1, y1), Bn254G2Point::Affine(x2, y2)) = (*t, *q) else {
+        t.add_assign(q);
+        return Bn254Fp12::ONE;
+    };
+    if x1 == x2 {
+        return match y1 == y2 {
+            true => double_step(t, p),
+            false => {
+                *t = Bn254G2Point::Infinity;
+                Bn254Fp12::ONE
+            }
+        };
+    }
+
+    let lambda = y2.sub(&y1).mul(&x2.sub(&x1).invert().unwrap());
+    *t = Bn254G2Point::chord(&lambda, &x1, &y1, &x2);
+    line(&lambda, &x1, &y1, p)
+}
+
+/// Applies the Frobenius endomorphism of the twist to `q`.
+fn frobenius(q: &Bn254G2Point) -> Bn254G2Point {
+    match q {
+        Bn254G2Point::Infinity => Bn254G2Point::Infinity,
+        Bn254G2Point::Affine(x, y) => Bn254G2Point::Affine(
+            x.conjugate().mul(&FROBENIUS_COEFFS[2]),
+            y.conjugate().mul(&FROBENIUS_COEFFS[3]),
+        ),
+    }
+}
+
+/// Computes the Miller loop of the optimal ate pairing for `(p, q)`, without the final
+/// exponentiation.
+fn miller_loop(p: &Bn254Point, q: &Bn254G2Point) -> Bn254Fp12 {
+    let WeierstrassPoint::Affine(limbs) = &p.0 else {
+        return Bn254Fp12::ONE;
+    };
+    if q.is_infinity() {
+        return Bn254Fp12::ONE;
+    }
+    let p = (
+        Bn254Fp::new(limbs[..8].try_into().unwrap()),
+        Bn254Fp::new(limbs[8..].try_into().unwrap()),
+    );
+
+    let mut f = Bn254Fp12::ONE;
+    let mut t = *q;
+    for i in (0..ATE_LOOP_COUNT.ilog2()).rev() {
+        f = f.square().mul(&double_step(&mut t, &p));
+        if (ATE_LOOP_COUNT >> i) & 1 == 1 {
+            f = f.mul(&add_step(&mut t, q, &p));
+        }
+    }
+
+    // The two final lines through π(q) and -π^2(q).
+    let q1 = frobenius(q);
+    let q2 = frobenius(&q1).neg();
+    f = f.mul(&add_step(&mut t, &q1, &p));
+    f.mul(&add_step(&mut t, &q2, &p))
+}
+
+/// Raises the output of the Miller loop to `(p^12 - 1) / r`.
+///
+/// The easy part `(p^6 - 1) · (p^2 + 1)` uses the conjugate and the Frobenius map, and the hard
+/// part is a plain exponentiation.
+fn final_exponentiation(f: &Bn254Fp12) -> Gt {
+    // A zero Miller loop output cannot arise from curve points, and maps to the identity.
+    let Some(f_inv) = Option::<Bn254Fp12>::from(f.invert()) else {
+        return Gt::identity();
+    };
+
+    let f = f.conjugate().mul(&f_inv);
+    let f = f.frobenius_map().frobenius_map().mul(&f);
+    Gt(f.pow(&FINAL_EXP_HARD))
+}
+
+/// Computes the optimal ate pairing `e(p, q)`.
+///
+/// `q` must be in the prime order subgroup of the twist for the result to be bilinear. If either
+/// point is the identity, the result is the identity of [Gt].
+pub fn pairing(p: &Bn254Point, q: &Bn254G2Point) -> Gt {
+    multi_pairing(&[(*p, *q)])
+}
+
+/// Computes the product of the pairings `e(p_i, q_i)`.
+///
+/// The Miller loops are multiplied together before a single final exponentiation, which makes
+/// checking a pairing product much cheaper than computing each pairing, see [pairing].
+pub fn multi_pairing(pairs: &[(Bn254Point, Bn254G2Point)]) -> Gt {
+    let f = pairs
+        .iter()
+        .fold(Bn254Fp12::ONE, |f, (p, q)| f.mul(&miller_loop(p, q)));
+    final_exponentiation(&f)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bn254::ORDER,
+        utils::{AffinePoint, WeierstrassAffinePoint},
+    };
+
+    /// Returns the little endian bits of `k`.
+    fn bits_le(k: u32) -> Vec<bool> {
+        (0..32).map(|i| (k >> i) & 1 == 1).collect()
+    }
+
+    fn g1(k: u32) -> Bn254Point {
+        Bn254Point::GENERATOR.scalar_mul(&bits_le(k))
+    }
+
+    fn g2(k: u32) -> Bn254G2Point {
+        Bn254G2Point::GENERATOR.scalar_mul(&bits_le(k))
+    }
+
+    #[test]
+    fn pairing_is_bilinear() {
+        let base = pairing(&g1(1), &g2(1));
+        assert!(!base.is_identity());
+        assert!(base.pow(&ORDER).is_identity());
+
+        let (a, b) = (3, 5);
+        let expected = base.pow(&[a * b]);
+        assert_eq!(pairing(&g1(a), &g2(b)), expected);
+        assert_eq!(pairing(&g1(a * b), &g2(1)), expected);
+        assert_eq!(pairing(&g1(1), &g2(a * b)), expected);
+    }
+
+    #[test]
+    fn pairing_with_the_identity() {
+        assert!(pairing(&Bn254Point::infinity(), &g2(1)).is_identity());
+        assert!(pairing(&g1(1), &Bn254G2Point::Infinity).is_identity());
+        assert!(multi_pairing(&[]).is_identity());
+    }
+
+    #[test]
+    fn multi_pairing_is_the_product_of_pairings() {
+        let pairs = [(g1(2), g2(3)), (g1(1), g2(7))];
+        assert_eq!(
+            multi_pairing(&pairs),
+            pairing(&pairs[0].0, &pairs[0].1).mul(&pairing(&pairs[1].0, &pairs[1].1))
+        );
+
+        // e(P, Q) · e(-P, Q) is the identity, the shape of a pairing check.
+        let mut minus_p = g1(4);
+        minus_p.negate_y();
+        assert!(multi_pairing(&[(g1(4), g2(5)), (minus_p, g2(5))]).is_identity());
+    }
+}
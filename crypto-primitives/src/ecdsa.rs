@@ -14,6 +14,7 @@
 //! to be more efficient in the VM, such as `sqrt` or `inverse`.
 
 use super::utils::AffinePoint as AffinePointTrait;
+use crate::uint256::U256;
 
 use elliptic_curve::{
     CurveArithmetic, FieldBytes, ff, generic_array::typenum::consts::U32, subtle::CtOption,
@@ -26,7 +27,30 @@ pub use affine::CenoAffinePoint;
 
 /// The projective point type for SP1.
 pub mod projective;
-pub use projective::CenoProjectivePoint;
+pub use projective::{CenoProjectivePoint, scalar_to_bits_le};
+
+/// The precomputed generator table used by [`elliptic_curve::ops::MulByGenerator`].
+mod generator;
+pub use generator::GeneratorTable;
+
+/// The DER encoding of ECDSA signatures.
+mod der;
+
+/// Deterministic nonce generation for ECDSA signing.
+mod rfc6979;
+
+/// ECDSA signatures, signing keys and verifying keys.
+pub mod signature;
+#[cfg(all(feature = "rayon", not(target_os = "zkvm")))]
+pub use signature::verify_batch_parallel;
+pub use signature::{
+    ComponentOrder, PreparedVerifyingKey, RecoveryId, Signature, SigningKey, VerifyingKey,
+    keypair_from_seed, normalize_s, public_key_compressed, verify_batch,
+};
+
+/// Serde support for ECDSA signatures.
+#[cfg(feature = "serde")]
+mod signature_serde;
 
 /// NOTE: The only supported ECDSA curves are secp256k1 and secp256r1, which both
 /// have 8 limbs in their field elements.
@@ -63,13 +87,65 @@ where
 
     /// The `b` coefficient in the curve equation.
     const EQUATION_B: Self::FieldElement;
+
+    /// The order `n` of the group of points, which is the modulus of the scalar field.
+    const ORDER: U256;
+
+    /// The prime `p` of the base field.
+    const MODULUS: U256;
+
+    /// Decompresses a point with the precompile of the curve.
+    ///
+    /// The first half of `point` holds the big endian X coordinate, and the second half is
+    /// overwritten with the big endian Y coordinate of parity `is_odd`.
+    fn decompress(point: &mut [u8; 64], is_odd: bool);
+
+    /// Returns the precomputed multiples of the generator, computing them on first use.
+    ///
+    /// Statics cannot be generic, so each curve keeps the table in a `static OnceLock` of its own,
+    /// initialized with [`GeneratorTable::generator`].
+    fn generator_table() -> &'static GeneratorTable<Self>;
 }
 
+/// Marker for an [`ECDSACurve`] whose group of points has prime order, that is a cofactor of 1.
+///
+/// Only these curves get the trivial [`group::cofactor::CofactorGroup`] and
+/// [`group::prime::PrimeGroup`] implementations, which accept every point on the curve. A curve
+/// with a nontrivial cofactor must instead implement them with a real multiplication by the
+/// cofactor and a subgroup check.
+///
+/// Generic code only gets these implementations with this bound:
+///
+/// ```
+/// use ceno_crypto_primitives::ecdsa::{CenoProjectivePoint, PrimeOrderCurve};
+/// use elliptic_curve::group::cofactor::CofactorGroup;
+///
+/// fn is_torsion_free<C: PrimeOrderCurve>(point: &CenoProjectivePoint<C>) -> bool {
+///     point.is_torsion_free().into()
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use ceno_crypto_primitives::ecdsa::{CenoProjectivePoint, ECDSACurve};
+/// use elliptic_curve::group::cofactor::CofactorGroup;
+///
+/// fn is_torsion_free<C: ECDSACurve>(point: &CenoProjectivePoint<C>) -> bool {
+///     point.is_torsion_free().into()
+/// }
+/// ```
+///
+/// [`group::cofactor::CofactorGroup`]: elliptic_curve::group::cofactor::CofactorGroup
+/// [`group::prime::PrimeGroup`]: elliptic_curve::group::prime::PrimeGroup
+pub trait PrimeOrderCurve: ECDSACurve {}
+
 /// Alias trait for the [`ff::PrimeField`] with 32 byte field elements.
 ///
 /// Note: All bytes should be considered to be in big-endian format.
 pub trait Field<C: ECDSACurve>: ff::PrimeField {
     /// Create an instance of self from a FieldBytes.
+    ///
+    /// Implementers must return none for any big endian value that is not smaller than the field
+    /// modulus, so that every element has a single encoding.
     fn from_bytes(bytes: &FieldBytes<C>) -> CtOption<Self>;
 
     /// Convert self to a FieldBytes.
@@ -96,7 +172,31 @@ impl<P> ECDSAPoint for P where P: AffinePointTrait<POINT_LIMBS> + Clone + Copy +
 pub mod ecdh {
     pub use elliptic_curve::ecdh::{EphemeralSecret, SharedSecret, diffie_hellman};
 
-    use super::{CenoAffinePoint, ECDSACurve, Field};
+    use super::{CenoAffinePoint, CenoProjectivePoint, ECDSACurve, Field};
+    use elliptic_curve::{Error, NonZeroScalar};
+
+    /// Computes the shared secret `secret · public` using the accelerated scalar multiplication of
+    /// [`CenoProjectivePoint`], and returns its X coordinate.
+    ///
+    /// Returns an error if `public` (and therefore the shared point) is the identity, instead of
+    /// deriving an all-zero shared secret.
+    pub fn ceno_diffie_hellman<C: ECDSACurve>(
+        secret: &NonZeroScalar<C>,
+        public: &CenoAffinePoint<C>,
+    ) -> Result<SharedSecret<C>, Error> {
+        if public.is_identity().into() {
+            return Err(Error);
+        }
+
+        let scalar: &C::Scalar = secret.as_ref();
+        let shared = (CenoProjectivePoint::<C>::from(public) * scalar).to_affine();
+
+        if shared.is_identity().into() {
+            return Err(Error);
+        }
+
+        Ok(SharedSecret::from(&shared))
+    }
 
     impl<C: ECDSACurve> From<&CenoAffinePoint<C>> for SharedSecret<C> {
         fn from(affine: &CenoAffinePoint<C>) -> SharedSecret<C> {
@@ -105,4 +205,72 @@ pub mod ecdh {
             x.to_bytes().into()
         }
     }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::secp256k1::{Scalar, Secp256k1};
+        use elliptic_curve::{group::Group, sec1::ToEncodedPoint};
+
+        fn key_pair(seed: u64) -> (NonZeroScalar<Secp256k1>, CenoAffinePoint<Secp256k1>) {
+            let secret = NonZeroScalar::new(Scalar::from_u64(seed)).unwrap();
+            let public = (CenoProjectivePoint::<Secp256k1>::generator() * *secret).to_affine();
+            (secret, public)
+        }
+
+        #[test]
+        fn both_parties_derive_the_same_secret() {
+            let (alice_secret, alice_public) = key_pair(0x1234_5678);
+            let (bob_secret, bob_public) = key_pair(0x9abc_def0);
+
+            let alice_shared = ceno_diffie_hellman(&alice_secret, &bob_public).unwrap();
+            let bob_shared = ceno_diffie_hellman(&bob_secret, &alice_public).unwrap();
+            assert_eq!(
+                alice_shared.raw_secret_bytes(),
+                bob_shared.raw_secret_bytes()
+            );
+
+            let expected = k256::ProjectivePoint::GENERATOR
+                * k256::Scalar::from(0x1234_5678u64 * 0x9abc_def0u64);
+            let expected = expected.to_affine().to_encoded_point(false);
+            assert_eq!(
+                alice_shared.raw_secret_bytes()[..],
+                expected.x().unwrap()[..]
+            );
+        }
+
+        #[test]
+        fn identity_public_key_is_rejected() {
+            let (secret, _) = key_pair(7);
+            assert!(ceno_diffie_hellman(&secret, &CenoAffinePoint::identity()).is_err());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{secp256k1::Secp256k1, secp256r1::NistP256};
+    use elliptic_curve::{Curve, PrimeField, bigint::Encoding, group::Group};
+
+    fn check_constants<C: ECDSACurve + Curve<Uint = elliptic_curve::bigint::U256>>() {
+        let order = <C as ECDSACurve>::ORDER;
+        assert_eq!(order.to_be_bytes(), <C as Curve>::ORDER.to_be_bytes());
+
+        let modulus = format!("0x{:x}", C::MODULUS);
+        assert_eq!(modulus, <FieldElement<C> as PrimeField>::MODULUS);
+
+        // `(n - 1) · G = -G`, so `n · G` is the identity.
+        let n_minus_one = order.overflowing_sub(&U256::ONE).0;
+        let scalar = C::Scalar::from_repr(n_minus_one.to_be_bytes().into()).unwrap();
+        let g = CenoProjectivePoint::<C>::generator();
+        assert_eq!(g * scalar, -g);
+        assert!(bool::from((g * scalar + g).is_identity()));
+    }
+
+    #[test]
+    fn order_and_modulus_constants() {
+        check_constants::<Secp256k1>();
+        check_constants::<NistP256>();
+    }
 }
@@ -11,74 +11,149 @@
 use super::{
     AffinePointTrait, ECDSACurve, ECDSAPoint, FIELD_BYTES_SIZE_USIZE, Field, FieldElement,
 };
+use crate::{
+    CryptoError,
+    utils::{WeierstrassPoint, words_to_bytes_le},
+};
+use ceno_syscall::Aligned4;
 #[cfg(feature = "profiling")]
 use ceno_syscall::syscall_phantom_log_pc_cycle;
-use ceno_syscall::syscall_secp256k1_decompress;
 
 use elliptic_curve::{
     FieldBytes, PrimeField,
     ff::Field as _,
-    generic_array::GenericArray,
     group::GroupEncoding,
     point::{AffineCoordinates, DecompactPoint, DecompressPoint},
+    rand_core::RngCore,
     sec1::{self, CompressedPoint, EncodedPoint, FromEncodedPoint, ToEncodedPoint},
     subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption},
     zeroize::DefaultIsZeroes,
 };
-use std::ops::Neg;
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+};
 
+/// The SP1 accelerated affine point.
+///
+/// The point is plain data, and it is [`Send`] and [`Sync`] because [`ECDSAPoint`] requires both
+/// of the inner point.
 #[derive(Clone, Copy, Debug)]
 pub struct CenoAffinePoint<C: ECDSACurve> {
     pub inner: C::SP1AffinePoint,
 }
 
 impl<C: ECDSACurve> CenoAffinePoint<C> {
+    /// Creates an affine point from the given field elements, returning none if they do not satisfy
+    /// the curve equation.
+    pub fn from_field_elements(x: FieldElement<C>, y: FieldElement<C>) -> CtOption<Self> {
+        let lhs = (y * y).normalize();
+        let rhs = (x * x * x) + (C::EQUATION_A * x) + C::EQUATION_B;
+
+        CtOption::new(
+            Self::from_field_elements_unchecked(x, y),
+            lhs.ct_eq(&rhs.normalize()),
+        )
+    }
+
     /// Create an affine point from the given field elements, without checking if the point is on
     /// the curve.
+    ///
+    /// This is only sound when the coordinates are known to be on the curve, such as the result of
+    /// a curve operation or the negation of a valid point. Coordinates from any other source must
+    /// go through [`CenoAffinePoint::from_field_elements`], since the precompiles assume their
+    /// inputs are on the curve.
     pub fn from_field_elements_unchecked(x: FieldElement<C>, y: FieldElement<C>) -> Self {
         let mut x_slice = x.to_bytes();
-        let x_slice = x_slice.as_mut_slice();
         x_slice.reverse();
 
         let mut y_slice = y.to_bytes();
-        let y_slice = y_slice.as_mut_slice();
         y_slice.reverse();
 
         CenoAffinePoint {
-            inner: <C::SP1AffinePoint as ECDSAPoint>::from(x_slice, y_slice),
+            inner: <C::SP1AffinePoint as ECDSAPoint>::from(&x_slice, &y_slice),
         }
     }
 
     /// Get the x and y field elements of the point.
     ///
-    /// The returned elements are always normalized.
+    /// The returned elements are always normalized, and the identity gives zeros.
+    ///
+    /// Panics if the limbs are not reduced field elements, which no point built through the
+    /// checked constructors has.
     pub fn field_elements(&self) -> (FieldElement<C>, FieldElement<C>) {
-        if self.is_identity().into() {
-            return (FieldElement::<C>::ZERO, FieldElement::<C>::ZERO);
-        }
+        let (is_affine, x, y) = self.ct_normalized_coordinates();
+        assert!(
+            bool::from(is_affine | self.is_identity()),
+            "the point limbs are not reduced field elements"
+        );
+        (x, y)
+    }
 
-        let bytes = self.inner.to_le_bytes();
+    /// Decodes the limbs as normalized coordinates without branching on the identity.
+    ///
+    /// The returned flag is set if the point is not the identity and both coordinates are reduced,
+    /// and the coordinates are zero otherwise.
+    fn ct_normalized_coordinates(&self) -> (Choice, FieldElement<C>, FieldElement<C>) {
+        let (is_infinity, limbs) = self.inner.inner().ct_components();
+        let bytes = words_to_bytes_le(&limbs);
 
         let mut x_bytes: [u8; FIELD_BYTES_SIZE_USIZE] =
             bytes[..FIELD_BYTES_SIZE_USIZE].try_into().unwrap();
-
         x_bytes.reverse();
-
         let mut y_bytes: [u8; FIELD_BYTES_SIZE_USIZE] =
             bytes[FIELD_BYTES_SIZE_USIZE..].try_into().unwrap();
-
         y_bytes.reverse();
 
-        let x = FieldElement::<C>::from_bytes(&x_bytes.into()).unwrap();
-        let y = FieldElement::<C>::from_bytes(&y_bytes.into()).unwrap();
-        (x, y)
+        let x = FieldElement::<C>::from_bytes(&x_bytes.into());
+        let y = FieldElement::<C>::from_bytes(&y_bytes.into());
+        (
+            !is_infinity & x.is_some() & y.is_some(),
+            x.unwrap_or(FieldElement::<C>::ZERO).normalize(),
+            y.unwrap_or(FieldElement::<C>::ZERO).normalize(),
+        )
+    }
+
+    /// Returns the X and Y coordinates of the point, or none for the identity.
+    ///
+    /// Unlike [`CenoAffinePoint::field_elements`], this does not map the identity to zeros.
+    pub fn coordinates(&self) -> Option<(FieldElement<C>, FieldElement<C>)> {
+        match bool::from(self.is_identity()) {
+            true => None,
+            false => Some(self.field_elements()),
+        }
+    }
+
+    /// Returns the X coordinate of the point, or none for the identity.
+    ///
+    /// This is named apart from [`AffineCoordinates::x`], which returns the encoded coordinate.
+    pub fn x_coordinate(&self) -> Option<FieldElement<C>> {
+        self.coordinates().map(|(x, _)| x)
+    }
+
+    /// Returns the Y coordinate of the point, or none for the identity.
+    pub fn y_coordinate(&self) -> Option<FieldElement<C>> {
+        self.coordinates().map(|(_, y)| y)
     }
 
     /// Get the generator point.
     pub fn generator() -> Self {
-        CenoAffinePoint {
+        let generator = CenoAffinePoint {
             inner: C::SP1AffinePoint::GENERATOR,
+        };
+
+        #[cfg(debug_assertions)]
+        {
+            let (x, y): (FieldElement<C>, FieldElement<C>) = generator.field_elements();
+            let lhs = (y * y).normalize();
+            let rhs = (x * x * x) + (C::EQUATION_A * x) + C::EQUATION_B;
+            debug_assert!(
+                bool::from(lhs.ct_eq(&rhs.normalize())),
+                "the generator is not on the curve"
+            );
         }
+
+        generator
     }
 
     /// Get the identity point.
@@ -92,6 +167,46 @@ impl<C: ECDSACurve> CenoAffinePoint<C> {
     pub fn is_identity(&self) -> Choice {
         Choice::from(self.inner.is_identity() as u8)
     }
+
+    /// Samples a random point by drawing random X coordinates and parities until one
+    /// decompresses to a point on the curve.
+    ///
+    /// Every X coordinate of a curve point is drawn with the same probability and each of its two
+    /// points is then equally likely, so the result is uniform over the non-identity points. About
+    /// two candidates are needed on average.
+    pub fn random(mut rng: impl RngCore) -> Self {
+        loop {
+            let x = FieldElement::<C>::random(&mut rng);
+            let y_is_odd = Choice::from((rng.next_u32() & 1) as u8);
+            if let Some(point) = Self::decompress(&x.to_bytes(), y_is_odd).into_option() {
+                return point;
+            }
+        }
+    }
+
+    /// Decodes a 33 byte compressed or 65 byte uncompressed SEC1 encoding, validating that the
+    /// point is on the curve.
+    ///
+    /// Unlike [`GroupEncoding::from_bytes`], which only takes the compressed form, this accepts
+    /// both. The uncompressed path skips the decompress syscall, since both coordinates are given
+    /// directly, and only checks the curve equation.
+    pub fn from_sec1_bytes(bytes: &[u8]) -> CtOption<Self> {
+        match Self::try_from(bytes) {
+            Ok(point) => CtOption::new(point, Choice::from(1)),
+            Err(_) => CtOption::new(Self::identity(), Choice::from(0)),
+        }
+    }
+
+    /// Returns `-self` if `choice` is set, and `self` otherwise.
+    ///
+    /// The identity is returned unchanged.
+    pub fn conditional_negate(&self, choice: Choice) -> Self {
+        let (x, y) = self.field_elements();
+        let negated = Self::from_field_elements_unchecked(x, -y);
+        let negated = Self::conditional_select(&negated, self, self.is_identity());
+
+        Self::conditional_select(self, &negated, choice)
+    }
 }
 
 impl<C: ECDSACurve> FromEncodedPoint<C> for CenoAffinePoint<C> {
@@ -106,19 +221,30 @@ impl<C: ECDSACurve> FromEncodedPoint<C> for CenoAffinePoint<C> {
                 let x = FieldElement::<C>::from_bytes(x);
                 let y = FieldElement::<C>::from_bytes(y);
 
-                x.and_then(|x| {
-                    y.and_then(|y| {
-                        // Ensure the point is on the curve.
-                        let lhs = (y * y).normalize();
-                        let rhs = (x * x * x) + (C::EQUATION_A * x) + C::EQUATION_B;
+                x.and_then(|x| y.and_then(|y| Self::from_field_elements(x, y)))
+            }
+        }
+    }
+}
 
-                        let point = Self::from_field_elements_unchecked(x, y);
+impl<C: ECDSACurve> TryFrom<&[u8]> for CenoAffinePoint<C> {
+    type Error = CryptoError;
 
-                        CtOption::new(point, lhs.ct_eq(&rhs.normalize()))
-                    })
-                })
-            }
+    /// Decodes a compressed or uncompressed SEC1 encoding, validating that the point is on the
+    /// curve.
+    ///
+    /// Inputs of neither 33 nor 65 bytes fail with [`CryptoError::InvalidLength`].
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        if bytes.len() != 1 + FIELD_BYTES_SIZE_USIZE
+            && bytes.len() != 1 + 2 * FIELD_BYTES_SIZE_USIZE
+        {
+            return Err(CryptoError::InvalidLength(bytes.len()));
         }
+
+        let point =
+            EncodedPoint::<C>::from_bytes(bytes).map_err(|_| CryptoError::InvalidEncoding)?;
+
+        Option::from(Self::from_encoded_point(&point)).ok_or(CryptoError::NotOnCurve)
     }
 }
 
@@ -129,10 +255,13 @@ impl<C: ECDSACurve> ToEncodedPoint<C> for CenoAffinePoint<C> {
             return EncodedPoint::<C>::identity();
         }
 
+        // The coordinates are normalized explicitly, so that equal points always share an encoding.
         let (x, y) = self.field_elements();
-
-        // The field elements are already normalized by virtue of being created via `FromBytes`.
-        EncodedPoint::<C>::from_affine_coordinates(&x.to_bytes(), &y.to_bytes(), compress)
+        EncodedPoint::<C>::from_affine_coordinates(
+            &x.normalize().to_bytes(),
+            &y.normalize().to_bytes(),
+            compress,
+        )
     }
 }
 
@@ -140,20 +269,31 @@ impl<C: ECDSACurve> DecompressPoint<C> for CenoAffinePoint<C> {
     fn decompress(x_bytes: &FieldBytes<C>, y_is_odd: Choice) -> CtOption<Self> {
         #[cfg(feature = "profiling")]
         syscall_phantom_log_pc_cycle("decompress start");
-        let mut data = [0u8; 64];
+        let mut data = Aligned4::<16>::zeroed();
         let is_odd = match y_is_odd.unwrap_u8() {
             0 => false,
             1 => true,
             _ => panic!("illegal y_is_odd"),
         };
         // copy x to input data
-        data[0..32].copy_from_slice(&x_bytes[0..32]);
-        syscall_secp256k1_decompress(&mut data, is_odd);
-        let x = FieldElement::<C>::from_bytes(GenericArray::from_slice(&data[0..32]));
-        let y = FieldElement::<C>::from_bytes(GenericArray::from_slice(&data[32..64]));
+        data.as_bytes_mut()[0..32].copy_from_slice(&x_bytes[0..32]);
+        C::decompress(data.as_byte_array_mut(), is_odd);
+        let mut x_bytes = FieldBytes::<C>::default();
+        x_bytes.copy_from_slice(&data.as_bytes()[0..32]);
+        let mut y_bytes = FieldBytes::<C>::default();
+        y_bytes.copy_from_slice(&data.as_bytes()[32..64]);
+        let x = FieldElement::<C>::from_bytes(&x_bytes);
+        let y = FieldElement::<C>::from_bytes(&y_bytes);
+        // The precompile writes an unspecified Y when X has no point on the curve, so the result
+        // is checked before it is used.
         if let Some(p) = x
             .into_option()
             .zip(y.into_option())
+            .filter(|(x, y)| {
+                let lhs = (*y * *y).normalize();
+                let rhs = (*x * *x * *x) + (C::EQUATION_A * *x) + C::EQUATION_B;
+                bool::from(lhs.ct_eq(&rhs.normalize())) && bool::from(y.is_odd()) == is_odd
+            })
             .map(|(x, y)| CenoAffinePoint::from_field_elements_unchecked(x, y.normalize()))
         {
             #[cfg(feature = "profiling")]
@@ -179,7 +319,7 @@ impl<C: ECDSACurve> AffineCoordinates for CenoAffinePoint<C> {
     fn x(&self) -> FieldBytes<C> {
         let (x, _) = self.field_elements();
 
-        x.to_bytes()
+        x.normalize().to_bytes()
     }
 
     fn y_is_odd(&self) -> Choice {
@@ -192,23 +332,22 @@ impl<C: ECDSACurve> AffineCoordinates for CenoAffinePoint<C> {
 
 impl<C: ECDSACurve> ConditionallySelectable for CenoAffinePoint<C> {
     fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
-        // Conditional select is a constant time if-else operation.
-        //
-        // In the SP1 vm, there are no attempts made to prevent side channel attacks.
-        if choice.into() { *b } else { *a }
+        // The limbs are selected one by one, so `choice` is never branched on.
+        let mut res = *a;
+        *res.inner.inner_mut() =
+            WeierstrassPoint::conditional_select(a.inner.inner(), b.inner.inner(), choice);
+        res
     }
 }
 
 impl<C: ECDSACurve> ConstantTimeEq for CenoAffinePoint<C> {
     fn ct_eq(&self, other: &Self) -> Choice {
-        let (x1, y1) = self.field_elements();
-        let (x1, y1) = (x1, y1);
-
-        let (x2, y2) = other.field_elements();
-        let (x2, y2) = (x2, y2);
-
-        // These are already normalized by virtue of being created via `FromBytes`.
-        x1.ct_eq(&x2) & y1.ct_eq(&y2)
+        // Identical representations are equal, and otherwise the normalized coordinates of two
+        // non-identity points are compared, so neither check branches on the identity.
+        let (self_is_affine, self_x, self_y) = self.ct_normalized_coordinates();
+        let (other_is_affine, other_x, other_y) = other.ct_normalized_coordinates();
+        self.inner.inner().ct_eq(other.inner.inner())
+            | (self_is_affine & other_is_affine & self_x.ct_eq(&other_x) & self_y.ct_eq(&other_y))
     }
 }
 
@@ -220,6 +359,35 @@ impl<C: ECDSACurve> PartialEq for CenoAffinePoint<C> {
 
 impl<C: ECDSACurve> Eq for CenoAffinePoint<C> {}
 
+impl<C: ECDSACurve> Hash for CenoAffinePoint<C> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // The compressed encoding is canonical, so it agrees with `PartialEq`, and maps the
+        // identity to all zero bytes.
+        self.to_bytes().hash(state);
+    }
+}
+
+impl<C: ECDSACurve> fmt::LowerHex for CenoAffinePoint<C> {
+    /// Formats the compressed SEC1 encoding, see [`GroupEncoding::to_bytes`], so the identity is
+    /// rendered as all zeros.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        for byte in self.to_bytes().iter() {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<C: ECDSACurve> fmt::Display for CenoAffinePoint<C> {
+    /// Formats the compressed SEC1 encoding as hex with a `0x` prefix.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:#x}")
+    }
+}
+
 impl<C: ECDSACurve> Default for CenoAffinePoint<C> {
     fn default() -> Self {
         CenoAffinePoint::identity()
@@ -228,15 +396,24 @@ impl<C: ECDSACurve> Default for CenoAffinePoint<C> {
 
 impl<C: ECDSACurve> DefaultIsZeroes for CenoAffinePoint<C> {}
 
+/// Points are encoded in the compressed SEC1 form.
+///
+/// The identity is encoded as all zeros. SEC1 encodes it as the single byte `0x00`, which is
+/// padded with zeros to the fixed width of [`CompressedPoint`]. No other point has a zero tag, so
+/// this encoding is unambiguous and round trips through [`GroupEncoding::from_bytes`].
 impl<C: ECDSACurve> GroupEncoding for CenoAffinePoint<C> {
     type Repr = CompressedPoint<C>;
 
     fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
         EncodedPoint::<C>::from_bytes(bytes)
-            .map(|point| CtOption::new(point, Choice::from(1)))
+            .map(|point| {
+                // The compact form has the same length, but would not round trip through
+                // `to_bytes`, which always writes the compressed form.
+                let is_compressed = Choice::from(point.is_compressed() as u8);
+                CtOption::new(point, is_compressed)
+            })
             .unwrap_or_else(|_| {
-                // SEC1 identity encoding is technically 1-byte 0x00, but the
-                // `GroupEncoding` API requires a fixed-width `Repr`.
+                // The padded identity is rejected by SEC1, which expects a single byte for it.
                 let is_identity = bytes.ct_eq(&Self::Repr::default());
                 CtOption::new(EncodedPoint::<C>::identity(), is_identity)
             })
@@ -249,9 +426,413 @@ impl<C: ECDSACurve> GroupEncoding for CenoAffinePoint<C> {
     }
 
     fn to_bytes(&self) -> Self::Repr {
-        let encoded = self.to_encoded_point(true);
         let mut result = CompressedPoint::<C>::default();
-        result[..encoded.len()].copy_from_slice(encoded.as_bytes());
+        if self.is_identity().into() {
+            return result;
+        }
+
+        let encoded = self.to_encoded_point(true);
+        result.copy_from_slice(encoded.as_bytes());
+
+        #[cfg(debug_assertions)]
+        if let Some(decoded) = Option::<Self>::from(Self::from_bytes(&result)) {
+            debug_assert_eq!(
+                decoded.to_encoded_point(true),
+                encoded,
+                "re-encoding a decoded point must be idempotent"
+            );
+        }
+
         result
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ecdsa::CenoProjectivePoint,
+        secp256k1::{CenoSecp256k1Point, Scalar, Secp256k1},
+        secp256r1::NistP256,
+    };
+    use ceno_syscall::{DefaultHandler, SyscallHandler, set_syscall_handler, take_syscall_handler};
+    use elliptic_curve::group::Group;
+    use std::rc::Rc;
+
+    type Point = CenoAffinePoint<Secp256k1>;
+
+    #[test]
+    fn equal_points_encode_identically() {
+        let g = CenoProjectivePoint::<Secp256k1>::generator();
+        let doubled = (g.double() + g).to_affine();
+        let added = (g + g + g).to_affine();
+        assert_eq!(doubled.to_bytes(), added.to_bytes());
+        assert_eq!(doubled, added);
+    }
+
+    #[test]
+    fn encoding_round_trips() {
+        let g = CenoProjectivePoint::<Secp256k1>::generator();
+        for point in [
+            Point::identity(),
+            Point::generator(),
+            (g * Scalar::from_u64(7)).to_affine(),
+        ] {
+            let bytes = point.to_bytes();
+            let decoded = Point::from_bytes(&bytes).unwrap();
+            assert_eq!(decoded, point);
+            assert_eq!(decoded.to_bytes(), bytes);
+        }
+    }
+
+    #[test]
+    fn identity_is_encoded_as_zeros() {
+        fn check<C: ECDSACurve>() {
+            let bytes = CenoAffinePoint::<C>::identity().to_bytes();
+            assert!(bytes.iter().all(|&byte| byte == 0));
+
+            let decoded = CenoAffinePoint::<C>::from_bytes(&bytes).unwrap();
+            assert!(bool::from(decoded.is_identity()));
+            assert_eq!(decoded, CenoAffinePoint::<C>::identity());
+
+            let projective = CenoProjectivePoint::<C>::from_bytes(&bytes).unwrap();
+            assert!(bool::from(projective.is_identity()));
+            assert_eq!(projective.to_bytes(), bytes);
+
+            // Only the full zero padding is the identity.
+            let mut padded = bytes;
+            padded[32] = 1;
+            assert!(bool::from(
+                CenoAffinePoint::<C>::from_bytes(&padded).is_none()
+            ));
+        }
+        check::<Secp256k1>();
+        check::<NistP256>();
+    }
+
+    #[test]
+    #[should_panic(expected = "not reduced field elements")]
+    fn field_elements_of_unreduced_limbs() {
+        let point = Point {
+            inner: CenoSecp256k1Point::new([u32::MAX; 16]),
+        };
+        point.field_elements();
+    }
+
+    #[test]
+    fn field_elements_of_the_identity() {
+        assert_eq!(
+            Point::identity().field_elements(),
+            (
+                FieldElement::<Secp256k1>::ZERO,
+                FieldElement::<Secp256k1>::ZERO
+            )
+        );
+    }
+
+    #[test]
+    fn from_field_elements_checks_the_curve_equation() {
+        fn check<C: ECDSACurve>() {
+            let point = (CenoProjectivePoint::<C>::generator() * C::Scalar::from(5u64)).to_affine();
+            let (x, y) = point.coordinates().unwrap();
+
+            assert_eq!(
+                CenoAffinePoint::<C>::from_field_elements(x, y).unwrap(),
+                point
+            );
+            assert_eq!(
+                CenoAffinePoint::<C>::from_field_elements(x, -y).unwrap(),
+                -point
+            );
+            assert!(bool::from(
+                CenoAffinePoint::<C>::from_field_elements(x, y + FieldElement::<C>::ONE).is_none()
+            ));
+            assert!(bool::from(
+                CenoAffinePoint::<C>::from_field_elements(x + FieldElement::<C>::ONE, y).is_none()
+            ));
+        }
+        check::<Secp256k1>();
+        check::<NistP256>();
+    }
+
+    #[test]
+    fn ct_eq_of_affine_points() {
+        let g = Point::generator();
+        let identity = Point::identity();
+        let double = (CenoProjectivePoint::from(g).double()).to_affine();
+
+        assert!(bool::from(identity.ct_eq(&identity)));
+        assert!(bool::from(g.ct_eq(&g)));
+        assert!(!bool::from(g.ct_eq(&double)));
+        assert!(!bool::from(g.ct_eq(&identity)));
+        assert!(!bool::from(identity.ct_eq(&g)));
+    }
+
+    #[test]
+    fn conditional_negate_of_affine_points() {
+        let g = Point::generator();
+        assert_eq!(g.conditional_negate(Choice::from(0)), g);
+        assert_eq!(g.conditional_negate(Choice::from(1)), -g);
+        assert_eq!((-g).conditional_negate(Choice::from(1)), g);
+        for choice in [0, 1] {
+            assert_eq!(
+                Point::identity().conditional_negate(Choice::from(choice)),
+                Point::identity()
+            );
+        }
+    }
+
+    #[test]
+    fn conditional_select_of_affine_points() {
+        let g = Point::generator();
+        let identity = Point::identity();
+        let double = (CenoProjectivePoint::from(g).double()).to_affine();
+
+        assert_eq!(Point::conditional_select(&g, &double, Choice::from(0)), g);
+        assert_eq!(
+            Point::conditional_select(&g, &double, Choice::from(1)),
+            double
+        );
+        assert_eq!(Point::conditional_select(&g, &identity, Choice::from(0)), g);
+        assert_eq!(
+            Point::conditional_select(&g, &identity, Choice::from(1)),
+            identity
+        );
+        assert_eq!(Point::conditional_select(&identity, &g, Choice::from(1)), g);
+    }
+
+    #[test]
+    fn equal_points_hash_equally() {
+        let g = CenoProjectivePoint::<Secp256k1>::generator();
+        let mut set = std::collections::HashSet::new();
+        assert!(set.insert(Point::generator()));
+        assert!(set.insert(Point::identity()));
+        // The same points, computed differently.
+        assert!(!set.insert((g.double() - g).to_affine()));
+        assert!(!set.insert((g - g).to_affine()));
+
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&Point::generator()));
+        assert!(set.contains(&Point::identity()));
+        assert!(!set.contains(&g.double().to_affine()));
+    }
+
+    #[test]
+    fn decompress_recovers_the_generator() {
+        let (x, y) = Point::generator().field_elements();
+        let point = Point::decompress(&x.to_bytes(), y.is_odd()).unwrap();
+        assert_eq!(point, Point::generator());
+        let negated = Point::decompress(&x.to_bytes(), !y.is_odd()).unwrap();
+        assert_eq!(negated, -Point::generator());
+    }
+
+    #[test]
+    fn decompress_rejects_x_off_the_curve() {
+        let x = (1u8..)
+            .map(|x| {
+                let mut bytes = FieldBytes::<Secp256k1>::default();
+                bytes[31] = x;
+                bytes
+            })
+            .find(|x| bool::from(k256::AffinePoint::decompress(x, 0.into()).is_none()))
+            .unwrap();
+        for is_odd in [0, 1] {
+            assert!(bool::from(Point::decompress(&x, is_odd.into()).is_none()));
+        }
+    }
+
+    #[test]
+    fn decompress_rejects_the_wrong_parity() {
+        struct FlipParity;
+
+        impl SyscallHandler for FlipParity {
+            fn secp256k1_decompress(&self, point: &mut [u8; 64], is_odd: bool) {
+                DefaultHandler.secp256k1_decompress(point, !is_odd);
+            }
+        }
+
+        let (x, y) = Point::generator().field_elements();
+        set_syscall_handler(Rc::new(FlipParity));
+        let point = Point::decompress(&x.to_bytes(), y.is_odd());
+        take_syscall_handler();
+        assert!(bool::from(point.is_none()));
+    }
+
+    #[test]
+    fn field_elements_round_trip() {
+        let (x, y) = Point::generator().field_elements();
+        assert_eq!(
+            Point::from_field_elements_unchecked(x, y),
+            Point::generator()
+        );
+        assert_eq!(
+            Point::from_field_elements(x, y).unwrap(),
+            Point::generator()
+        );
+        assert!(bool::from(Point::from_field_elements(x, x).is_none()));
+    }
+
+    #[test]
+    fn try_from_accepts_both_encodings() {
+        let g = CenoProjectivePoint::<Secp256k1>::generator();
+        for point in [Point::generator(), (g * Scalar::from_u64(7)).to_affine()] {
+            for compress in [true, false] {
+                let encoded = point.to_encoded_point(compress);
+                assert_eq!(encoded.len(), if compress { 33 } else { 65 });
+                assert_eq!(Point::try_from(encoded.as_bytes()).unwrap(), point);
+            }
+        }
+    }
+
+    #[test]
+    fn from_sec1_bytes_accepts_both_encodings_of_the_generator() {
+        let g = Point::generator();
+        let compressed =
+            hex_literal::hex!("0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798");
+        let uncompressed = hex_literal::hex!(
+            "0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"
+            "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8"
+        );
+        for bytes in [&compressed[..], &uncompressed[..]] {
+            assert_eq!(Point::from_sec1_bytes(bytes).unwrap(), g);
+            assert_eq!(
+                CenoProjectivePoint::<Secp256k1>::from_sec1_bytes(bytes).unwrap(),
+                CenoProjectivePoint::from(g)
+            );
+        }
+
+        // The uncompressed path checks the curve equation.
+        let mut off_curve = uncompressed;
+        off_curve[64] ^= 1;
+        assert!(bool::from(Point::from_sec1_bytes(&off_curve).is_none()));
+        assert!(bool::from(
+            Point::from_sec1_bytes(&uncompressed[..64]).is_none()
+        ));
+    }
+
+    #[test]
+    fn try_from_rejects_truncated_input() {
+        for compress in [true, false] {
+            let encoded = Point::generator().to_encoded_point(compress);
+            let truncated = &encoded.as_bytes()[..encoded.len() - 1];
+            assert_eq!(
+                Point::try_from(truncated).unwrap_err(),
+                CryptoError::InvalidLength(truncated.len())
+            );
+        }
+    }
+
+    #[test]
+    fn try_from_rejects_bad_lengths() {
+        for len in [0, 1, 32, 34, 64, 66] {
+            assert_eq!(
+                Point::try_from(&vec![0x02; len][..]).unwrap_err(),
+                CryptoError::InvalidLength(len)
+            );
+        }
+    }
+
+    #[test]
+    fn try_from_rejects_bad_tags() {
+        let mut bytes = Point::generator()
+            .to_encoded_point(true)
+            .as_bytes()
+            .to_vec();
+        bytes[0] = 0x07;
+        assert_eq!(
+            Point::try_from(&bytes[..]).unwrap_err(),
+            CryptoError::InvalidEncoding
+        );
+    }
+
+    #[test]
+    fn try_from_rejects_points_off_the_curve() {
+        let mut bytes = Point::generator()
+            .to_encoded_point(false)
+            .as_bytes()
+            .to_vec();
+        bytes[64] ^= 1;
+        assert_eq!(
+            Point::try_from(&bytes[..]).unwrap_err(),
+            CryptoError::NotOnCurve
+        );
+    }
+
+    #[test]
+    fn points_format_as_compressed_hex() {
+        let g = Point::generator();
+        let hex = "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+        assert_eq!(format!("{g:x}"), hex);
+        assert_eq!(format!("{g:#x}"), format!("0x{hex}"));
+        assert_eq!(g.to_string(), format!("0x{hex}"));
+        assert_eq!(CenoProjectivePoint::from(g).to_string(), format!("0x{hex}"));
+
+        let identity = format!("0x{}", "00".repeat(33));
+        assert_eq!(Point::identity().to_string(), identity);
+        assert_eq!(
+            CenoProjectivePoint::<Secp256k1>::identity().to_string(),
+            identity
+        );
+    }
+
+    /// A deterministic SplitMix64 generator, so the sampled points are reproducible.
+    struct SplitMix(u64);
+
+    impl RngCore for SplitMix {
+        fn next_u32(&mut self) -> u32 {
+            self.next_u64() as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = self.0;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            z ^ (z >> 31)
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            for chunk in dest.chunks_mut(8) {
+                chunk.copy_from_slice(&self.next_u64().to_le_bytes()[..chunk.len()]);
+            }
+        }
+
+        fn try_fill_bytes(
+            &mut self,
+            dest: &mut [u8],
+        ) -> Result<(), elliptic_curve::rand_core::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn random_points_are_on_the_curve() {
+        fn check<C: ECDSACurve>() {
+            let mut rng = SplitMix(7);
+            let mut odd = 0;
+            let points: Vec<_> = (0..64)
+                .map(|_| CenoAffinePoint::<C>::random(&mut rng))
+                .collect();
+            for point in &points {
+                let (x, y) = point.coordinates().expect("the identity is never sampled");
+                assert!(bool::from(
+                    CenoAffinePoint::<C>::from_field_elements(x, y).is_some()
+                ));
+                odd += bool::from(point.y_is_odd()) as usize;
+            }
+            assert_eq!(
+                points
+                    .iter()
+                    .map(|point| point.to_bytes())
+                    .collect::<std::collections::HashSet<_>>()
+                    .len(),
+                points.len()
+            );
+            // Both parities are sampled.
+            assert!(0 < odd && odd < points.len());
+        }
+        check::<Secp256k1>();
+        check::<NistP256>();
+    }
+}
@@ -0,0 +1,170 @@
+//! The ASN.1 DER encoding of ECDSA signatures, `SEQUENCE { r INTEGER, s INTEGER }`.
+//!
+//! Only the strict distinguished encoding is accepted: short form lengths, minimal positive
+//! integers and no trailing bytes. Both supported curves have 32 byte scalars, so an encoded
+//! signature is at most 72 bytes long and every length fits the short form.
+
+use elliptic_curve::Error;
+
+/// The tag of an ASN.1 `SEQUENCE`.
+const SEQUENCE: u8 = 0x30;
+
+/// The tag of an ASN.1 `INTEGER`.
+const INTEGER: u8 = 0x02;
+
+/// The maximum length of an encoded signature in bytes.
+pub(super) const MAX_LEN: usize = 2 + 2 * (2 + 33);
+
+/// Encodes the big endian scalars `r` and `s` as a DER sequence.
+pub(super) fn encode(r: &[u8; 32], s: &[u8; 32]) -> Vec<u8> {
+    let mut body = Vec::with_capacity(MAX_LEN - 2);
+    encode_integer(r, &mut body);
+    encode_integer(s, &mut body);
+
+    let mut der = Vec::with_capacity(2 + body.len());
+    der.push(SEQUENCE);
+    der.push(body.len() as u8);
+    der.extend_from_slice(&body);
+    der
+}
+
+/// Appends the minimal DER integer of the big endian unsigned `value` to `out`.
+fn encode_integer(value: &[u8; 32], out: &mut Vec<u8>) {
+    // Zero is still encoded with one byte.
+    let start = value.iter().position(|&byte| byte != 0).unwrap_or(31);
+    let digits = &value[start..];
+    // A set high bit would make the integer negative, so it is preceded by a zero byte.
+    let pad = digits[0] & 0x80 != 0;
+
+    out.push(INTEGER);
+    out.push((digits.len() + pad as usize) as u8);
+    if pad {
+        out.push(0);
+    }
+    out.extend_from_slice(digits);
+}
+
+/// Decodes a DER sequence of two integers into the big endian scalars `r` and `s`.
+///
+/// Fails for any encoding that is not the distinguished one, for trailing bytes after the
+/// sequence and for integers that are negative or do not fit 32 bytes. The scalars are not
+/// checked against the curve order.
+pub(super) fn decode(der: &[u8]) -> Result<([u8; 32], [u8; 32]), Error> {
+    let body = match der {
+        [SEQUENCE, len, body @ ..] if *len < 0x80 && *len as usize == body.len() => body,
+        _ => return Err(Error),
+    };
+
+    let (r, rest) = decode_integer(body)?;
+    let (s, rest) = decode_integer(rest)?;
+    if !rest.is_empty() {
+        return Err(Error);
+    }
+    Ok((r, s))
+}
+
+/// Decodes a minimal, positive DER integer of at most 32 bytes from the front of `input`,
+/// returning it big endian together with the remaining bytes.
+fn decode_integer(input: &[u8]) -> Result<([u8; 32], &[u8]), Error> {
+    let (len, rest) = match input {
+        [INTEGER, len, rest @ ..] if *len < 0x80 && *len as usize <= rest.len() => {
+            (*len as usize, rest)
+        }
+        _ => return Err(Error),
+    };
+    let (digits, rest) = rest.split_at(len);
+
+    let digits = match digits {
+        // Empty or negative.
+        [] | [0x80..=0xff, ..] => return Err(Error),
+        // A leading zero is only allowed, and then required, before a set high bit.
+        [0, next, ..] if *next < 0x80 => return Err(Error),
+        [0, tail @ ..] if !tail.is_empty() => tail,
+        _ => digits,
+    };
+    if digits.len() > 32 {
+        return Err(Error);
+    }
+
+    let mut value = [0u8; 32];
+    value[32 - digits.len()..].copy_from_slice(digits);
+    Ok((value, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the 32 byte big endian value with `tail` in its lowest bytes.
+    fn value(tail: &[u8]) -> [u8; 32] {
+        let mut value = [0u8; 32];
+        value[32 - tail.len()..].copy_from_slice(tail);
+        value
+    }
+
+    #[test]
+    fn integers_are_minimal() {
+        for (tail, expected) in [
+            (&[][..], &[0x02, 0x01, 0x00][..]),
+            (&[0x01], &[0x02, 0x01, 0x01]),
+            (&[0x7f], &[0x02, 0x01, 0x7f]),
+            (&[0x80], &[0x02, 0x02, 0x00, 0x80]),
+            (&[0x01, 0x00], &[0x02, 0x02, 0x01, 0x00]),
+        ] {
+            let mut out = Vec::new();
+            encode_integer(&value(tail), &mut out);
+            assert_eq!(out, expected);
+            assert_eq!(decode_integer(&out).unwrap(), (value(tail), &[][..]));
+        }
+    }
+
+    #[test]
+    fn round_trips_at_the_maximum_length() {
+        let high = [0xff; 32];
+        let der = encode(&high, &high);
+        assert_eq!(der.len(), MAX_LEN);
+        assert_eq!(der[..5], [0x30, 0x46, 0x02, 0x21, 0x00]);
+        assert_eq!(decode(&der).unwrap(), (high, high));
+
+        let (r, s) = (value(&[1]), value(&[0x12, 0x34]));
+        let der = encode(&r, &s);
+        assert_eq!(der, [0x30, 0x07, 0x02, 0x01, 0x01, 0x02, 0x02, 0x12, 0x34]);
+        assert_eq!(decode(&der).unwrap(), (r, s));
+    }
+
+    #[test]
+    fn rejects_non_distinguished_encodings() {
+        let valid = [0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02];
+        assert!(decode(&valid).is_ok());
+
+        let mut too_long = [0x02, 0x21, 0x01].to_vec();
+        too_long.extend_from_slice(&[0; 32]);
+
+        for der in [
+            // Empty input, a wrong tag, and a sequence length that is off.
+            &[][..],
+            &[0x31, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02],
+            &[0x30, 0x07, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02],
+            &[0x30, 0x05, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02],
+            // Trailing bytes after the sequence, and inside it after `s`.
+            &[0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02, 0x00],
+            &[0x30, 0x07, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02, 0x00],
+            // A long form length.
+            &[0x30, 0x81, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02],
+            // A missing `s`, and an integer that runs past the end.
+            &[0x30, 0x03, 0x02, 0x01, 0x01],
+            &[0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x02, 0x02],
+            // A wrong integer tag, an empty integer and a negative one.
+            &[0x30, 0x06, 0x03, 0x01, 0x01, 0x02, 0x01, 0x02],
+            &[0x30, 0x05, 0x02, 0x00, 0x02, 0x01, 0x02],
+            &[0x30, 0x06, 0x02, 0x01, 0x81, 0x02, 0x01, 0x02],
+            // A leading zero before a clear high bit.
+            &[0x30, 0x07, 0x02, 0x02, 0x00, 0x01, 0x02, 0x01, 0x02],
+        ] {
+            assert!(decode(der).is_err(), "{der:02x?}");
+        }
+
+        // An integer of 33 significant bytes does not fit a scalar.
+        assert!(decode_integer(&too_long).is_err());
+    }
+}
@@ -0,0 +1,210 @@
+//! A precomputed fixed-base table for multiplying the generator of an [`ECDSACurve`].
+//!
+//! Because the generator is fixed, its multiples can be computed once and reused by every
+//! `k·G` in key generation and signing. With a 4-bit window this replaces the 256 doublings and
+//! ~128 additions of a double-and-add by 64 additions. The same table is built for the public key
+//! of a [`PreparedVerifyingKey`](super::signature::PreparedVerifyingKey).
+
+use super::{AffinePointTrait, CenoAffinePoint, ECDSACurve, POINT_LIMBS};
+use crate::utils::WeierstrassPoint;
+use elliptic_curve::subtle::{ConditionallySelectable, ConstantTimeEq};
+
+/// The number of scalar bits covered by each window of the table.
+const WINDOW: usize = 4;
+
+/// The number of windows needed to cover a 256 bit scalar.
+const NUM_WINDOWS: usize = 256 / WINDOW;
+
+/// The number of entries of a row, one for each digit of a window.
+const ROW_SIZE: usize = 1 << WINDOW;
+
+/// Precomputed multiples of a fixed base `B`, where `windows[i][d] = (d + 2) · 16^i · B`.
+///
+/// The digits are offset by two so that no entry is the identity, and so that the sum of the
+/// entries picked for the lower windows stays below every entry of the next one. The offset adds
+/// `2 · Σ 16^i · B` to every product, which `offset` cancels.
+///
+/// The base is the generator for the table returned by [`ECDSACurve::generator_table`].
+pub struct GeneratorTable<C: ECDSACurve> {
+    windows: Vec<[C::SP1AffinePoint; ROW_SIZE]>,
+    offset: C::SP1AffinePoint,
+}
+
+impl<C: ECDSACurve> GeneratorTable<C> {
+    /// Computes the table of `base`.
+    ///
+    /// This costs 1088 complete additions and holds 1025 points.
+    pub(crate) fn new(mut base: C::SP1AffinePoint) -> Self {
+        let mut windows = Vec::with_capacity(NUM_WINDOWS);
+        let mut offset = C::SP1AffinePoint::identity();
+
+        for _ in 0..NUM_WINDOWS {
+            let mut row = [base; ROW_SIZE];
+            row[0].complete_add_assign(&base);
+            for d in 1..ROW_SIZE {
+                row[d] = row[d - 1];
+                row[d].complete_add_assign(&base);
+            }
+            offset.complete_add_assign(&row[0]);
+
+            // The entry of the digit 14 is 16 · base.
+            base = row[ROW_SIZE - 2];
+            windows.push(row);
+        }
+
+        Self {
+            windows,
+            offset: (-CenoAffinePoint::<C> { inner: offset }).inner,
+        }
+    }
+
+    /// Computes the table of the generator of `C`.
+    ///
+    /// This is meant to initialize the static returned by [`ECDSACurve::generator_table`].
+    pub fn generator() -> Self {
+        Self::new(C::SP1AffinePoint::GENERATOR)
+    }
+
+    /// Computes `k·B`, where `k` is given as little endian bits.
+    ///
+    /// This is used for secret scalars such as the signing nonce, so the work does not depend on
+    /// `k`: every window reads its whole row with a constant time select and adds the selected
+    /// entry, for 64 complete additions including the final `offset`. The running sum starts at an
+    /// entry and stays below the next entry, so only the last two additions can reach the special
+    /// cases of the complete addition, for a negligible fraction of the scalars and for `k = 0`.
+    pub(crate) fn mul(&self, bits_le: &[bool; 256]) -> C::SP1AffinePoint {
+        let mut digits = bits_le.chunks_exact(WINDOW).map(|window| {
+            window
+                .iter()
+                .rev()
+                .fold(0u32, |acc, &bit| (acc << 1) | bit as u32)
+        });
+
+        let mut rows = self.windows.iter();
+        let mut res = select(rows.next().unwrap(), digits.next().unwrap());
+        for (row, digit) in rows.zip(digits) {
+            res.complete_add_assign(&select(row, digit));
+        }
+        res.complete_add_assign(&self.offset);
+
+        res
+    }
+}
+
+/// Returns `row[digit]`, reading every entry so that neither the memory accesses nor the branches
+/// depend on `digit`.
+fn select<P: AffinePointTrait<POINT_LIMBS>>(row: &[P; ROW_SIZE], digit: u32) -> P {
+    let mut res = row[0].clone();
+    for (i, entry) in row.iter().enumerate().skip(1) {
+        let point = WeierstrassPoint::conditional_select(
+            res.inner(),
+            entry.inner(),
+            digit.ct_eq(&(i as u32)),
+        );
+        *res.inner_mut() = point;
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ecdsa::CenoProjectivePoint,
+        secp256k1::{self, Secp256k1},
+        secp256r1::{self, NistP256},
+    };
+    use elliptic_curve::{
+        bigint::U256,
+        group::{Group, GroupEncoding},
+        ops::{MulByGenerator, Reduce},
+    };
+    use proptest::prelude::*;
+
+    fn check_curve<C: ECDSACurve>(bytes: [u8; 32])
+    where
+        C::Scalar: Reduce<U256, Bytes = elliptic_curve::FieldBytes<C>>,
+    {
+        let k = C::Scalar::reduce_bytes(&bytes.into());
+        assert_eq!(
+            CenoProjectivePoint::<C>::mul_by_generator(&k),
+            CenoProjectivePoint::<C>::generator() * k
+        );
+    }
+
+    #[test]
+    fn tables_are_shared() {
+        assert!(core::ptr::eq(
+            Secp256k1::generator_table(),
+            Secp256k1::generator_table()
+        ));
+        assert!(core::ptr::eq(
+            NistP256::generator_table(),
+            NistP256::generator_table()
+        ));
+    }
+
+    #[test]
+    fn select_reads_every_digit() {
+        let table = Secp256k1::generator_table();
+        for (row, base) in table.windows.iter().zip([1u64, 16, 256]) {
+            for digit in 0..ROW_SIZE as u32 {
+                let expected = CenoProjectivePoint::<Secp256k1>::generator()
+                    * secp256k1::Scalar::from_u64((digit as u64 + 2) * base);
+                let entry = CenoAffinePoint::<Secp256k1> {
+                    inner: select(row, digit),
+                };
+                assert_eq!(CenoProjectivePoint::from(entry), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn mul_by_generator_edge_cases() {
+        for k in [
+            secp256k1::Scalar::ZERO,
+            secp256k1::Scalar::ONE,
+            -secp256k1::Scalar::ONE,
+            secp256k1::Scalar::from_u64(15),
+            secp256k1::Scalar::from_u64(16),
+            secp256k1::Scalar::from_u64(0x1111),
+        ] {
+            assert_eq!(
+                CenoProjectivePoint::<Secp256k1>::mul_by_generator(&k),
+                CenoProjectivePoint::<Secp256k1>::generator() * k
+            );
+        }
+        for k in [
+            secp256r1::Scalar::ZERO,
+            secp256r1::Scalar::ONE,
+            -secp256r1::Scalar::ONE,
+        ] {
+            assert_eq!(
+                CenoProjectivePoint::<NistP256>::mul_by_generator(&k),
+                CenoProjectivePoint::<NistP256>::generator() * k
+            );
+        }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        #[test]
+        fn mul_by_generator_matches_mul(bytes in any::<[u8; 32]>()) {
+            check_curve::<Secp256k1>(bytes);
+            check_curve::<NistP256>(bytes);
+        }
+
+        #[test]
+        fn mul_by_generator_matches_k256(bytes in any::<[u8; 32]>()) {
+            let k = secp256k1::Scalar::reduce_bytes(&bytes.into());
+            let expected = k256::ProjectivePoint::mul_by_generator(
+                &<k256::Scalar as Reduce<U256>>::reduce_bytes(&bytes.into()),
+            );
+            prop_assert_eq!(
+                CenoProjectivePoint::<Secp256k1>::mul_by_generator(&k).to_bytes(),
+                expected.to_bytes()
+            );
+        }
+    }
+}
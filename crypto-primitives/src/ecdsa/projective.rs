@@ -8,7 +8,8 @@
 //! Note: When performing curve operations, accelerated crates for SP1 use affine arithmetic instead
 //! of projective arithmetic for performance.
 
-use super::{AffinePointTrait, CenoAffinePoint, ECDSACurve};
+use super::{AffinePointTrait, CenoAffinePoint, ECDSACurve, FieldElement, PrimeOrderCurve};
+use crate::uint256::U256;
 
 use elliptic_curve::{
     CurveArithmetic, FieldBytes,
@@ -27,13 +28,28 @@ use elliptic_curve::{
 };
 
 use std::{
+    fmt,
+    hash::{Hash, Hasher},
     iter::Sum,
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 
 use std::borrow::Borrow;
 
+/// Returns the 256 bits of `scalar`, least significant first, as consumed by the scalar
+/// multiplications.
+///
+/// The scalar is reduced modulo [`ECDSACurve::ORDER`] in constant time before it is decomposed,
+/// so every bit is meaningful even if its representation is not canonical, and this never panics.
+pub fn scalar_to_bits_le<C: ECDSACurve>(scalar: &C::Scalar) -> [bool; 256] {
+    let value = U256::from_be_bytes(&scalar.to_repr().into()).sub_if_ge(&<C as ECDSACurve>::ORDER);
+    std::array::from_fn(|i| value.bit(i))
+}
+
 /// The SP1 accelerated projective point.
+///
+/// Like [`CenoAffinePoint`], which it wraps, the point is [`Send`] and [`Sync`]. Off-target, the
+/// `rayon` feature uses this to spread `CenoProjectivePoint::par_msm` across threads.
 #[derive(Clone, Copy, Debug)]
 pub struct CenoProjectivePoint<C: ECDSACurve> {
     /// The inner affine point.
@@ -71,11 +87,171 @@ impl<C: ECDSACurve> CenoProjectivePoint<C> {
         self.inner.is_identity()
     }
 
+    /// Returns the affine X and Y coordinates of the point, or none for the identity.
+    pub fn coordinates(&self) -> Option<(FieldElement<C>, FieldElement<C>)> {
+        self.inner.coordinates()
+    }
+
+    /// Returns the affine X coordinate of the point, or none for the identity.
+    pub fn x_coordinate(&self) -> Option<FieldElement<C>> {
+        self.inner.x_coordinate()
+    }
+
+    /// Returns the affine Y coordinate of the point, or none for the identity.
+    pub fn y_coordinate(&self) -> Option<FieldElement<C>> {
+        self.inner.y_coordinate()
+    }
+
+    /// Decodes a compressed or uncompressed SEC1 encoding, see
+    /// [`CenoAffinePoint::from_sec1_bytes`].
+    pub fn from_sec1_bytes(bytes: &[u8]) -> CtOption<Self> {
+        CenoAffinePoint::<C>::from_sec1_bytes(bytes).map(Self::from)
+    }
+
+    /// Returns `-self` if `choice` is set, and `self` otherwise.
+    ///
+    /// The identity is returned unchanged.
+    pub fn conditional_negate(&self, choice: Choice) -> Self {
+        self.inner.conditional_negate(choice).into()
+    }
+
     fn from_zkvm_point(p: C::SP1AffinePoint) -> Self {
         Self {
             inner: CenoAffinePoint { inner: p },
         }
     }
+
+    /// Computes `k · p + l · q`, the shape of the ECDSA verification equation.
+    ///
+    /// This is [`LinearCombination::lincomb`] with each scalar next to its point. Identity
+    /// operands are dispatched to a single scalar multiplication, so the shared doubling chain
+    /// only ever sees two proper points.
+    pub fn double_scalar_mul(k: &C::Scalar, p: &Self, l: &C::Scalar, q: &Self) -> Self {
+        match (bool::from(p.is_identity()), bool::from(q.is_identity())) {
+            (true, true) => Self::identity(),
+            (true, false) => *q * l,
+            (false, true) => *p * k,
+            (false, false) => Self::lincomb(p, k, q, l),
+        }
+    }
+
+    /// Computes `scalar · self` with a Montgomery ladder, for secret scalars.
+    ///
+    /// The scalar is first raised by one or two times the order, which leaves the product
+    /// unchanged, so that its bit 256 is always set. The ladder then starts from `self` and
+    /// `2 · self`, and each of the 256 lower bits costs one complete addition and one doubling,
+    /// with the bit only swapping the two accumulators in constant time. The default [`Mul`]
+    /// instead adds only for set bits, which makes it cheaper, but its sequence of precompile
+    /// calls reveals the scalar. The special cases of the addition and doubling are only reached
+    /// when an accumulator is the identity or the negation of the other, that is for scalars
+    /// whose leading bits form a multiple of the order, which a random secret scalar has with
+    /// negligible probability.
+    ///
+    /// Panics if the order of the curve is below `2^255`, which is not the case for either curve
+    /// of this crate.
+    pub fn mul_ct(&self, scalar: &C::Scalar) -> Self {
+        let order = <C as ECDSACurve>::ORDER;
+        assert!(order.bit(255), "the curve order must be at least 2^255");
+        if bool::from(self.is_identity()) {
+            return *self;
+        }
+
+        // As the order is above 2^255, `k + n` carries into bit 256 for large `k`, and `k + 2n`
+        // carries for the others. The carry is the implicit top bit of the ladder.
+        let k = U256::from_be_bytes(&scalar.to_repr().into()).sub_if_ge(&order);
+        let (once, carry) = k.overflowing_add(&order);
+        let (twice, _) = once.overflowing_add(&order);
+        let k = U256::conditional_select(&twice, &once, Choice::from(carry as u8));
+
+        // `r1 = r0 + self` holds throughout, starting from the top bit.
+        let mut r0 = *self;
+        let mut r1 = self.double();
+        for i in (0..256).rev() {
+            let bit = Choice::from(k.bit(i) as u8);
+            Self::conditional_swap(&mut r0, &mut r1, bit);
+            r1 += r0;
+            // Not every curve supports doubling the identity.
+            if !bool::from(r0.is_identity()) {
+                r0 = r0.double();
+            }
+            Self::conditional_swap(&mut r0, &mut r1, bit);
+        }
+        r0
+    }
+
+    /// Computes the multi-scalar multiplication `Σ scalars[i] · points[i]`.
+    ///
+    /// This generalizes [`LinearCombination::lincomb`] to any number of terms: all products share
+    /// a single chain of doublings, with one accelerated addition per set bit.
+    ///
+    /// Panics if `points` and `scalars` have different lengths.
+    pub fn msm(points: &[Self], scalars: &[C::Scalar]) -> Self {
+        assert_eq!(
+            points.len(),
+            scalars.len(),
+            "points and scalars must have the same length"
+        );
+
+        let bits_le: Vec<[bool; 256]> = scalars.iter().map(scalar_to_bits_le::<C>).collect();
+
+        let mut res = C::SP1AffinePoint::identity();
+        for i in (0..256).rev() {
+            // Not every curve supports doubling the identity.
+            if !res.is_identity() {
+                res.double();
+            }
+            for (point, bits) in points.iter().zip(bits_le.iter()) {
+                if bits[i] {
+                    res.complete_add_assign(&point.to_zkvm_point());
+                }
+            }
+        }
+
+        Self::from_zkvm_point(res)
+    }
+
+    /// Computes the multi-scalar multiplication `Σ scalars[i] · points[i]` like
+    /// [`CenoProjectivePoint::msm`], splitting the terms across the threads of the rayon pool.
+    ///
+    /// Each thread runs [`CenoProjectivePoint::msm`] over a contiguous chunk of the terms, and the
+    /// partial sums are added up. This is only available off-target, and the `rayon` feature does
+    /// not pick a backend for the precompiles. The syscall handler is installed per thread, so the
+    /// worker threads need either the `soft` feature for [`ceno_syscall::DefaultHandler`], or a
+    /// [`ceno_syscall::SyscallHandler`] installed on each of them, for example with
+    /// `rayon::ThreadPoolBuilder::start_handler`. Otherwise they panic on the first syscall.
+    ///
+    /// Panics if `points` and `scalars` have different lengths.
+    #[cfg(all(feature = "rayon", not(target_os = "zkvm")))]
+    pub fn par_msm(points: &[Self], scalars: &[C::Scalar]) -> Self {
+        use rayon::prelude::*;
+
+        assert_eq!(
+            points.len(),
+            scalars.len(),
+            "points and scalars must have the same length"
+        );
+
+        let chunk_size = points.len().div_ceil(rayon::current_num_threads()).max(1);
+        points
+            .par_chunks(chunk_size)
+            .zip(scalars.par_chunks(chunk_size))
+            .map(|(points, scalars)| Self::msm(points, scalars))
+            .reduce(Self::identity, |a, b| a + b)
+    }
+
+    /// Computes `Σ kᵢ · Pᵢ` over the `(kᵢ, Pᵢ)` pairs, see [`CenoProjectivePoint::msm`].
+    ///
+    /// An empty input returns the identity, and a single pair is a plain scalar multiplication.
+    pub fn sum_of_products(pairs: &[(C::Scalar, Self)]) -> Self {
+        match pairs {
+            [] => Self::identity(),
+            [(scalar, point)] => *point * scalar,
+            _ => {
+                let (scalars, points): (Vec<_>, Vec<_>) = pairs.iter().copied().unzip();
+                Self::msm(&points, &scalars)
+            }
+        }
+    }
 }
 
 impl<C: ECDSACurve> From<CenoAffinePoint<C>> for CenoProjectivePoint<C> {
@@ -138,15 +314,21 @@ impl<C: ECDSACurve> Curve for CenoProjectivePoint<C> {
     }
 }
 
-impl<C: ECDSACurve> MulByGenerator for CenoProjectivePoint<C> {}
+impl<C: ECDSACurve> MulByGenerator for CenoProjectivePoint<C> {
+    fn mul_by_generator(scalar: &Self::Scalar) -> Self {
+        let bits_le = scalar_to_bits_le::<C>(scalar);
+
+        Self::from_zkvm_point(C::generator_table().mul(&bits_le))
+    }
+}
 
 impl<C: ECDSACurve> LinearCombination for CenoProjectivePoint<C> {
     fn lincomb(x: &Self, k: &Self::Scalar, y: &Self, l: &Self::Scalar) -> Self {
         let x = x.to_zkvm_point();
         let y = y.to_zkvm_point();
 
-        let a_bits_le = be_bytes_to_le_bits(k.to_repr().as_ref());
-        let b_bits_le = be_bytes_to_le_bits(l.to_repr().as_ref());
+        let a_bits_le = scalar_to_bits_le::<C>(k);
+        let b_bits_le = scalar_to_bits_le::<C>(l);
 
         let sp1_point =
             C::SP1AffinePoint::multi_scalar_multiplication(&a_bits_le, x, &b_bits_le, y);
@@ -156,26 +338,45 @@ impl<C: ECDSACurve> LinearCombination for CenoProjectivePoint<C> {
 }
 
 // Implementation of scalar multiplication for the projective point.
+//
+// A single term `msm` is the double-and-add over the bits from `scalar_to_bits_le`, which reduces
+// the scalar, so the product never fails.
 
 impl<C: ECDSACurve, T: Borrow<C::Scalar>> Mul<T> for CenoProjectivePoint<C> {
     type Output = CenoProjectivePoint<C>;
 
-    fn mul(mut self, rhs: T) -> Self::Output {
-        let sp1_point = self.as_mut_zkvm_point();
-        sp1_point.mul_assign(&be_bytes_to_le_words(rhs.borrow().to_repr()));
-
-        self
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::msm(&[self], &[*rhs.borrow()])
     }
 }
 
 impl<C: ECDSACurve, T: Borrow<C::Scalar>> MulAssign<T> for CenoProjectivePoint<C> {
     fn mul_assign(&mut self, rhs: T) {
-        self.as_mut_zkvm_point()
-            .mul_assign(&be_bytes_to_le_words(rhs.borrow().to_repr()));
+        *self = *self * rhs;
+    }
+}
+
+// Scalar multiplication of the affine point goes through the projective point, which wraps the
+// affine point, so both conversions are free.
+
+impl<C: ECDSACurve, T: Borrow<C::Scalar>> Mul<T> for CenoAffinePoint<C> {
+    type Output = CenoAffinePoint<C>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        (CenoProjectivePoint::from(self) * rhs).to_affine()
+    }
+}
+
+impl<C: ECDSACurve, T: Borrow<C::Scalar>> MulAssign<T> for CenoAffinePoint<C> {
+    fn mul_assign(&mut self, rhs: T) {
+        *self = *self * rhs;
     }
 }
 
 // Implementation of projective arithmetic.
+//
+// The operands of the group operations may be the identity, equal or opposite, which the addition
+// precompiles do not handle, so every sum goes through `complete_add_assign`.
 
 impl<C: ECDSACurve> Neg for CenoProjectivePoint<C> {
     type Output = CenoProjectivePoint<C>;
@@ -192,11 +393,36 @@ impl<C: ECDSACurve> Neg for CenoProjectivePoint<C> {
     }
 }
 
+impl<C: ECDSACurve> Neg for &CenoProjectivePoint<C> {
+    type Output = CenoProjectivePoint<C>;
+
+    fn neg(self) -> Self::Output {
+        (*self).neg()
+    }
+}
+
+impl<C: ECDSACurve> Neg for CenoAffinePoint<C> {
+    type Output = CenoAffinePoint<C>;
+
+    fn neg(self) -> Self::Output {
+        CenoProjectivePoint::from(self).neg().to_affine()
+    }
+}
+
+impl<C: ECDSACurve> Neg for &CenoAffinePoint<C> {
+    type Output = CenoAffinePoint<C>;
+
+    fn neg(self) -> Self::Output {
+        (*self).neg()
+    }
+}
+
 impl<C: ECDSACurve> Add<CenoProjectivePoint<C>> for CenoProjectivePoint<C> {
     type Output = CenoProjectivePoint<C>;
 
     fn add(mut self, rhs: CenoProjectivePoint<C>) -> Self::Output {
-        self.as_mut_zkvm_point().add_assign(rhs.as_zkvm_point());
+        self.as_mut_zkvm_point()
+            .complete_add_assign(rhs.as_zkvm_point());
 
         self
     }
@@ -206,7 +432,8 @@ impl<C: ECDSACurve> Add<&CenoProjectivePoint<C>> for CenoProjectivePoint<C> {
     type Output = CenoProjectivePoint<C>;
 
     fn add(mut self, rhs: &CenoProjectivePoint<C>) -> Self::Output {
-        self.as_mut_zkvm_point().add_assign(rhs.as_zkvm_point());
+        self.as_mut_zkvm_point()
+            .complete_add_assign(rhs.as_zkvm_point());
 
         self
     }
@@ -244,27 +471,29 @@ impl<'a, C: ECDSACurve> Sum<&'a CenoProjectivePoint<C>> for CenoProjectivePoint<
 
 impl<C: ECDSACurve> AddAssign<CenoProjectivePoint<C>> for CenoProjectivePoint<C> {
     fn add_assign(&mut self, rhs: CenoProjectivePoint<C>) {
-        self.as_mut_zkvm_point().add_assign(rhs.as_zkvm_point());
+        self.as_mut_zkvm_point()
+            .complete_add_assign(rhs.as_zkvm_point());
     }
 }
 
 impl<C: ECDSACurve> AddAssign<&CenoProjectivePoint<C>> for CenoProjectivePoint<C> {
     fn add_assign(&mut self, rhs: &CenoProjectivePoint<C>) {
-        self.as_mut_zkvm_point().add_assign(rhs.as_zkvm_point());
+        self.as_mut_zkvm_point()
+            .complete_add_assign(rhs.as_zkvm_point());
     }
 }
 
 impl<C: ECDSACurve> SubAssign<CenoProjectivePoint<C>> for CenoProjectivePoint<C> {
     fn sub_assign(&mut self, rhs: CenoProjectivePoint<C>) {
         self.as_mut_zkvm_point()
-            .add_assign(rhs.neg().as_zkvm_point());
+            .complete_add_assign(rhs.neg().as_zkvm_point());
     }
 }
 
 impl<C: ECDSACurve> SubAssign<&CenoProjectivePoint<C>> for CenoProjectivePoint<C> {
     fn sub_assign(&mut self, rhs: &CenoProjectivePoint<C>) {
         self.as_mut_zkvm_point()
-            .add_assign(rhs.neg().as_zkvm_point());
+            .complete_add_assign(rhs.neg().as_zkvm_point());
     }
 }
 
@@ -294,13 +523,13 @@ impl<C: ECDSACurve> Add<&CenoAffinePoint<C>> for CenoProjectivePoint<C> {
 
 impl<C: ECDSACurve> AddAssign<CenoAffinePoint<C>> for CenoProjectivePoint<C> {
     fn add_assign(&mut self, rhs: CenoAffinePoint<C>) {
-        self.as_mut_zkvm_point().add_assign(&rhs.inner);
+        self.as_mut_zkvm_point().complete_add_assign(&rhs.inner);
     }
 }
 
 impl<C: ECDSACurve> AddAssign<&CenoAffinePoint<C>> for CenoProjectivePoint<C> {
     fn add_assign(&mut self, rhs: &CenoAffinePoint<C>) {
-        self.as_mut_zkvm_point().add_assign(&rhs.inner);
+        self.as_mut_zkvm_point().complete_add_assign(&rhs.inner);
     }
 }
 
@@ -325,7 +554,7 @@ impl<C: ECDSACurve> SubAssign<CenoAffinePoint<C>> for CenoProjectivePoint<C> {
         let projective = CenoProjectivePoint { inner: rhs }.neg();
 
         self.as_mut_zkvm_point()
-            .add_assign(projective.as_zkvm_point());
+            .complete_add_assign(projective.as_zkvm_point());
     }
 }
 
@@ -334,7 +563,7 @@ impl<C: ECDSACurve> SubAssign<&CenoAffinePoint<C>> for CenoProjectivePoint<C> {
         let projective = CenoProjectivePoint { inner: *rhs }.neg();
 
         self.as_mut_zkvm_point()
-            .add_assign(projective.as_zkvm_point());
+            .complete_add_assign(projective.as_zkvm_point());
     }
 }
 
@@ -362,6 +591,24 @@ impl<C: ECDSACurve> PartialEq for CenoProjectivePoint<C> {
 
 impl<C: ECDSACurve> Eq for CenoProjectivePoint<C> {}
 
+impl<C: ECDSACurve> Hash for CenoProjectivePoint<C> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inner.hash(state);
+    }
+}
+
+impl<C: ECDSACurve> fmt::LowerHex for CenoProjectivePoint<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.inner, f)
+    }
+}
+
+impl<C: ECDSACurve> fmt::Display for CenoProjectivePoint<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.inner, f)
+    }
+}
+
 impl<C: ECDSACurve> GroupEncoding for CenoProjectivePoint<C>
 where
     FieldBytes<C>: Copy,
@@ -384,7 +631,7 @@ where
     }
 }
 
-impl<C: ECDSACurve> PrimeGroup for CenoProjectivePoint<C>
+impl<C: PrimeOrderCurve> PrimeGroup for CenoProjectivePoint<C>
 where
     FieldBytes<C>: Copy,
     C::FieldBytesSize: ModulusSize,
@@ -392,8 +639,8 @@ where
 {
 }
 
-/// The scalar field has prime order, so the cofactor is 1.
-impl<C: ECDSACurve> CofactorGroup for CenoProjectivePoint<C>
+/// The curve has prime order, so the cofactor is 1 and every point is torsion free.
+impl<C: PrimeOrderCurve> CofactorGroup for CenoProjectivePoint<C>
 where
     FieldBytes<C>: Copy,
     C::FieldBytesSize: ModulusSize,
@@ -414,28 +661,358 @@ where
     }
 }
 
-#[inline]
-fn be_bytes_to_le_words<T: AsMut<[u8]>>(mut bytes: T) -> [u32; 8] {
-    let bytes = bytes.as_mut();
-    bytes.reverse();
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ecdsa::Field as _,
+        secp256k1::{Scalar, Secp256k1},
+        secp256r1::NistP256,
+    };
+    use elliptic_curve::{bigint::U256 as BigUint, ops::Reduce};
+    use hex_literal::hex;
+    use proptest::prelude::*;
+
+    type Point = CenoProjectivePoint<Secp256k1>;
+
+    /// Returns `count` distinct points and scalars.
+    fn terms(count: u64) -> (Vec<Point>, Vec<Scalar>) {
+        let g = Point::generator();
+        (1..=count)
+            .map(|i| {
+                (
+                    g * Scalar::from_u64(3 * i + 1),
+                    Scalar::from_u64(i * i + 7).invert().unwrap(),
+                )
+            })
+            .unzip()
+    }
+
+    /// Returns `k · G` for the scalar reduced from `bytes`.
+    fn point<C: ECDSACurve>(bytes: [u8; 32]) -> CenoProjectivePoint<C>
+    where
+        C::Scalar: Reduce<BigUint, Bytes = FieldBytes<C>>,
+    {
+        CenoProjectivePoint::<C>::generator() * C::Scalar::reduce_bytes(&bytes.into())
+    }
+
+    /// Checks the group laws on `p`, `q` and `r`.
+    fn check_group_laws<C: ECDSACurve>(
+        p: CenoProjectivePoint<C>,
+        q: CenoProjectivePoint<C>,
+        r: CenoProjectivePoint<C>,
+    ) {
+        let identity = CenoProjectivePoint::<C>::identity();
+        assert_eq!((p + q) + r, p + (q + r), "associativity");
+        assert_eq!(p + q, q + p, "commutativity");
+        assert_eq!(p + identity, p, "right identity");
+        assert_eq!(identity + p, p, "left identity");
+        assert_eq!(p + (-p), identity, "inverse");
+        assert_eq!((p + q) - q, p, "subtraction");
+        assert_eq!(p + p, p.double(), "doubling");
+    }
+
+    #[test]
+    fn group_law_regressions() {
+        fn check<C: ECDSACurve>() {
+            let g = CenoProjectivePoint::<C>::generator();
+            let identity = CenoProjectivePoint::<C>::identity();
+
+            // P + P, P + (-P) and P + O hit the special cases of the affine addition.
+            assert_eq!(g + g, g.double());
+            assert_eq!(g + (-g), identity);
+            assert_eq!(g + identity, g);
+            assert_eq!(identity + identity, identity);
+            assert_eq!(identity.double(), identity);
+            check_group_laws(g, g, -g);
+            check_group_laws(identity, g, g.double());
+        }
+        check::<Secp256k1>();
+        check::<NistP256>();
+    }
+
+    #[test]
+    fn negation_by_reference() {
+        fn check<C: ECDSACurve>(p: CenoProjectivePoint<C>) {
+            let affine = p.to_affine();
+            assert_eq!(-&p, -p);
+            assert_eq!(-&affine, -affine);
+            assert_eq!(-affine, (-p).to_affine());
+            assert_eq!(-(-&p), p);
+            assert_eq!(p + -&p, CenoProjectivePoint::<C>::identity());
+        }
+        for bytes in [[0u8; 32], [1; 32], [0xab; 32]] {
+            check(point::<Secp256k1>(bytes));
+            check(point::<NistP256>(bytes));
+        }
+        check(Point::identity());
+        check(CenoProjectivePoint::<NistP256>::identity());
+    }
 
-    let mut iter = bytes
-        .chunks(4)
-        .map(|b| u32::from_le_bytes(b.try_into().unwrap()));
-    core::array::from_fn(|_| iter.next().unwrap())
-}
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn secp256k1_group_laws(
+            p in any::<[u8; 32]>(),
+            q in any::<[u8; 32]>(),
+            r in any::<[u8; 32]>(),
+        ) {
+            check_group_laws(point::<Secp256k1>(p), point(q), point(r));
+        }
+
+        #[test]
+        fn mul_ct_matches_mul(bytes in any::<[u8; 32]>(), leading_zeros in 0usize..32) {
+            let g = Point::generator().double();
+            let mut bytes = bytes;
+            bytes[..leading_zeros].fill(0);
+            let k = <Scalar as Reduce<BigUint>>::reduce_bytes(&bytes.into());
+            prop_assert_eq!(g.mul_ct(&k), g * k);
+        }
+
+        #[test]
+        fn scalar_on_the_left(bytes in any::<[u8; 32]>(), k in any::<[u8; 32]>()) {
+            let p = point::<Secp256k1>(bytes);
+            let k = <Scalar as Reduce<BigUint>>::reduce_bytes(&k.into());
+            let k_ref = &k;
+            prop_assert_eq!(k * p, p * k);
+            prop_assert_eq!(k_ref * p, p * k);
+            prop_assert_eq!(Scalar::ZERO * p, Point::identity());
+
+            let p = point::<NistP256>(bytes);
+            let k = <crate::secp256r1::Scalar as Reduce<BigUint>>::reduce_bytes(&k.to_be_bytes().into());
+            let k_ref = &k;
+            prop_assert_eq!(k * p, p * k);
+            prop_assert_eq!(k_ref * p, p * k);
+            prop_assert_eq!(crate::secp256r1::Scalar::ZERO * p, CenoProjectivePoint::identity());
+        }
+
+        #[test]
+        fn secp256r1_group_laws(
+            p in any::<[u8; 32]>(),
+            q in any::<[u8; 32]>(),
+            r in any::<[u8; 32]>(),
+        ) {
+            check_group_laws(point::<NistP256>(p), point(q), point(r));
+        }
+    }
+
+    #[test]
+    fn prime_order_curves_are_torsion_free() {
+        fn check<C: PrimeOrderCurve>()
+        where
+            FieldBytes<C>: Copy,
+            C::FieldBytesSize: ModulusSize,
+            CompressedPoint<C>: Copy,
+        {
+            let g = CenoProjectivePoint::<C>::generator();
+            for point in [CenoProjectivePoint::<C>::identity(), g, g.double() + g] {
+                assert!(bool::from(point.is_torsion_free()));
+                assert_eq!(point.clear_cofactor(), point);
+                assert_eq!(point.into_subgroup().unwrap(), point);
+            }
+        }
+        check::<Secp256k1>();
+        check::<NistP256>();
+    }
+
+    #[test]
+    fn conditional_negate_of_projective_points() {
+        let g = Point::generator();
+        assert_eq!(g.conditional_negate(Choice::from(0)), g);
+        assert_eq!(g.conditional_negate(Choice::from(1)), -g);
+        assert_eq!(g + g.conditional_negate(Choice::from(1)), Point::identity());
+        for choice in [0, 1] {
+            assert_eq!(
+                Point::identity().conditional_negate(Choice::from(choice)),
+                Point::identity()
+            );
+        }
+    }
+
+    #[test]
+    fn equal_points_hash_equally() {
+        let g = Point::generator();
+        let set: std::collections::HashSet<_> = [g, Point::identity(), g.double() - g, g - g]
+            .into_iter()
+            .collect();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&g) && set.contains(&Point::identity()));
+    }
+
+    #[test]
+    fn msm_matches_separate_products() {
+        for count in [0, 1, 2, 7] {
+            let (points, scalars) = terms(count);
+            let expected = points
+                .iter()
+                .zip(&scalars)
+                .map(|(p, k)| *p * k)
+                .sum::<Point>();
+            assert_eq!(Point::msm(&points, &scalars), expected);
+        }
+    }
+
+    #[test]
+    fn scalar_bits_are_reduced_modulo_the_order() {
+        let k = Scalar::from_u64(0b1011);
+        let bits = scalar_to_bits_le::<Secp256k1>(&k);
+        assert_eq!(bits[..5], [true, true, false, true, false]);
+        assert!(bits[5..].iter().all(|&bit| !bit));
+
+        // `k + n` still fits 256 bits, and only an unchecked scalar can hold it.
+        let (k_plus_n, carry) = k.to_u256().overflowing_add(&Scalar::MODULUS);
+        assert!(!carry);
+        let unreduced = Scalar::from_u256_unchecked(k_plus_n);
+        assert_eq!(scalar_to_bits_le::<Secp256k1>(&unreduced), bits);
+        assert_eq!(Point::generator() * unreduced, Point::generator() * k);
+
+        let minus_one = -Scalar::ONE;
+        let bits = scalar_to_bits_le::<Secp256k1>(&minus_one);
+        let value = minus_one.to_u256();
+        assert!((0..256).all(|i| bits[i] == value.bit(i)));
+    }
+
+    #[test]
+    fn sum_of_products_matches_the_fold() {
+        assert_eq!(Point::sum_of_products(&[]), Point::identity());
+        for count in [1, 2, 5] {
+            let (points, scalars) = terms(count);
+            let pairs: Vec<_> = scalars
+                .iter()
+                .copied()
+                .zip(points.iter().copied())
+                .collect();
+            let expected = pairs
+                .iter()
+                .fold(Point::identity(), |acc, (k, p)| acc + *p * k);
+            assert_eq!(Point::sum_of_products(&pairs), expected);
+        }
+    }
+
+    #[test]
+    fn double_scalar_mul_matches_separate_products() {
+        let g = Point::generator();
+        let q = g * Scalar::from_u64(11);
+        let identity = Point::identity();
+        let (k, l) = (Scalar::from_u64(5), -Scalar::from_u64(3));
+
+        assert_eq!(Point::double_scalar_mul(&k, &g, &l, &q), g * k + q * l);
+        assert_eq!(Point::double_scalar_mul(&k, &g, &Scalar::ZERO, &q), g * k);
+        assert_eq!(Point::double_scalar_mul(&Scalar::ZERO, &g, &l, &q), q * l);
+        assert_eq!(Point::double_scalar_mul(&k, &identity, &l, &q), q * l);
+        assert_eq!(Point::double_scalar_mul(&k, &g, &l, &identity), g * k);
+        assert_eq!(
+            Point::double_scalar_mul(&k, &identity, &l, &identity),
+            identity
+        );
+        // The sum cancels for `p = q` with opposite scalars.
+        assert_eq!(Point::double_scalar_mul(&k, &g, &-k, &g), identity);
+    }
+
+    #[test]
+    fn affine_mul_matches_projective_mul() {
+        let g = Point::generator();
+        let affine = (g * Scalar::from_u64(9)).to_affine();
+        for k in [
+            Scalar::ZERO,
+            Scalar::ONE,
+            Scalar::from_u64(12345),
+            -Scalar::ONE,
+        ] {
+            let expected = (CenoProjectivePoint::from(affine) * k).to_affine();
+            assert_eq!(affine * k, expected);
+
+            let mut assigned = affine;
+            assigned *= k;
+            assert_eq!(assigned, expected);
+        }
+        assert!(bool::from((affine * Scalar::ZERO).is_identity()));
+    }
+
+    #[test]
+    fn mul_ct_edge_scalars() {
+        let g = Point::generator();
+        for k in [
+            Scalar::ZERO,
+            Scalar::ONE,
+            Scalar::from_u64(2),
+            -Scalar::from_u64(2),
+            -Scalar::ONE,
+        ] {
+            assert_eq!(g.mul_ct(&k), g * k);
+        }
+
+        let g = CenoProjectivePoint::<NistP256>::generator();
+        for k in [0, 1, 2, 12345] {
+            let k = <NistP256 as CurveArithmetic>::Scalar::from(k);
+            assert_eq!(g.mul_ct(&k), g * k);
+            assert_eq!(g.mul_ct(&-k), g * -k);
+        }
+        assert_eq!(
+            Point::identity().mul_ct(&Scalar::from_u64(7)),
+            Point::identity()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "same length")]
+    fn msm_rejects_mismatched_lengths() {
+        let (points, scalars) = terms(3);
+        Point::msm(&points, &scalars[..2]);
+    }
+
+    #[test]
+    #[cfg(all(feature = "rayon", not(target_os = "zkvm")))]
+    fn par_msm_matches_msm() {
+        for count in [0, 1, 2, 7, 33] {
+            let (points, scalars) = terms(count);
+            assert_eq!(
+                Point::par_msm(&points, &scalars),
+                Point::msm(&points, &scalars)
+            );
+        }
+    }
 
-/// Convert big-endian bytes with the most significant bit first to little-endian bytes with the
-/// least significant bit first. Panics: If the bytes have len > 32.
-#[inline]
-fn be_bytes_to_le_bits(be_bytes: &[u8]) -> [bool; 256] {
-    let mut bits = [false; 256];
-    // Reverse the byte order to little-endian.
-    for (i, &byte) in be_bytes.iter().rev().enumerate() {
-        for j in 0..8 {
-            // Flip the bit order so the least significant bit is now the first bit of the chunk.
-            bits[i * 8 + j] = ((byte >> j) & 1) == 1;
+    #[test]
+    #[cfg(all(feature = "rayon", not(target_os = "zkvm")))]
+    #[should_panic(expected = "same length")]
+    fn par_msm_rejects_mismatched_lengths() {
+        let (points, scalars) = terms(3);
+        Point::par_msm(&points[..2], &scalars);
+    }
+
+    #[test]
+    fn points_are_send_and_sync() {
+        fn check<T: Send + Sync>() {}
+        check::<Point>();
+        check::<CenoAffinePoint<Secp256k1>>();
+        check::<CenoProjectivePoint<NistP256>>();
+    }
+
+    #[test]
+    fn generator_coordinates() {
+        fn check<C: ECDSACurve>(x: [u8; 32], y: [u8; 32]) {
+            let g = CenoProjectivePoint::<C>::generator();
+            let (gx, gy) = g.coordinates().unwrap();
+            assert_eq!(gx.to_bytes()[..], x);
+            assert_eq!(gy.to_bytes()[..], y);
+            assert_eq!(g.x_coordinate(), Some(gx));
+            assert_eq!(g.y_coordinate(), Some(gy));
+            assert_eq!(g.to_affine().coordinates(), Some((gx, gy)));
+
+            let identity = CenoProjectivePoint::<C>::identity();
+            assert!(identity.coordinates().is_none());
+            assert!(identity.x_coordinate().is_none() && identity.y_coordinate().is_none());
+            assert!(identity.to_affine().coordinates().is_none());
         }
+        check::<Secp256k1>(
+            hex!("79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"),
+            hex!("483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8"),
+        );
+        check::<NistP256>(
+            hex!("6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296"),
+            hex!("4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5"),
+        );
     }
-    bits
 }
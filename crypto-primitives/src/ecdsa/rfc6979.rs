@@ -0,0 +1,168 @@
+//! Deterministic ECDSA nonces as specified in RFC 6979, instantiated with HMAC-SHA256.
+//!
+//! Both supported curves have a 256 bit order, so `qlen` is always 256 and the bit string
+//! conversions of the RFC reduce to byte operations.
+
+use super::ECDSACurve;
+use crate::sha256::Sha256;
+use elliptic_curve::{FieldBytes, PrimeField, ff::Field as _, ops::Reduce};
+
+/// The size of a SHA-256 block in bytes.
+const BLOCK_SIZE: usize = 64;
+
+/// Computes HMAC-SHA256 with a 32 byte key over the concatenation of `parts`.
+fn hmac_sha256(key: &[u8; 32], parts: &[&[u8]]) -> [u8; 32] {
+    let mut inner_key = [0x36u8; BLOCK_SIZE];
+    let mut outer_key = [0x5cu8; BLOCK_SIZE];
+    for (i, byte) in key.iter().enumerate() {
+        inner_key[i] ^= byte;
+        outer_key[i] ^= byte;
+    }
+
+    let mut inner = Sha256::new();
+    inner.update(&inner_key);
+    for part in parts {
+        inner.update(part);
+    }
+
+    let mut outer = Sha256::new();
+    outer.update(&outer_key);
+    outer.update(&inner.finalize());
+    outer.finalize()
+}
+
+/// Converts a message digest into the field bytes of an integer, following `bits2int` of
+/// RFC 6979, section 2.3.2.
+///
+/// Longer digests are truncated to their leftmost 32 bytes and shorter ones are zero extended on
+/// the left, so the result is suitable for [`Reduce::reduce_bytes`].
+pub(crate) fn bits2field<C: ECDSACurve>(prehash: &[u8]) -> FieldBytes<C> {
+    let mut bytes = FieldBytes::<C>::default();
+    let len = prehash.len().min(bytes.len());
+    let offset = bytes.len() - len;
+    bytes[offset..].copy_from_slice(&prehash[..len]);
+    bytes
+}
+
+/// Derives the nonce for signing `prehash` with the secret scalar `priv_scalar`, following the
+/// HMAC-DRBG loop of RFC 6979, section 3.2.
+///
+/// Candidates that are zero or not smaller than the curve order are rejected.
+pub(crate) fn rfc6979_nonce<C: ECDSACurve>(priv_scalar: &C::Scalar, prehash: &[u8]) -> C::Scalar {
+    let x = priv_scalar.to_repr();
+    let h1 = <C::Scalar as Reduce<C::Uint>>::reduce_bytes(&bits2field::<C>(prehash)).to_repr();
+
+    let mut k = [0u8; 32];
+    let mut v = [1u8; 32];
+
+    k = hmac_sha256(&k, &[&v, &[0x00], &x, &h1]);
+    v = hmac_sha256(&k, &[&v]);
+    k = hmac_sha256(&k, &[&v, &[0x01], &x, &h1]);
+    v = hmac_sha256(&k, &[&v]);
+
+    loop {
+        v = hmac_sha256(&k, &[&v]);
+
+        let candidate = C::Scalar::from_repr(v.into())
+            .into_option()
+            .filter(|candidate| !bool::from(candidate.is_zero()));
+        if let Some(nonce) = candidate {
+            return nonce;
+        }
+
+        k = hmac_sha256(&k, &[&v, &[0x00]]);
+        v = hmac_sha256(&k, &[&v]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ecdsa::{SigningKey, normalize_s},
+        secp256k1::{self, Secp256k1},
+        secp256r1::{self, NistP256},
+    };
+    use hex_literal::hex;
+    use k256::ecdsa::signature::hazmat::PrehashSigner;
+    use proptest::prelude::*;
+    use sha2::Digest;
+
+    /// The secret key of the P-256 vectors of RFC 6979, appendix A.2.5.
+    const P256_KEY: [u8; 32] =
+        hex!("C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721");
+
+    #[test]
+    fn p256_sha256_vectors() {
+        let vectors = [
+            (
+                &b"sample"[..],
+                hex!("A6E3C57DD01ABE90086538398355DD4C3B17AA873382B0F24D6129493D8AAD60"),
+                hex!(
+                    "EFD48B2AACB6A8FD1140DD9CD45E81D69D2C877B56AAF991C34D0EA84EAF3716"
+                    "F7CB1C942D657C41D436C7A1B6E29F65F3E900DBB9AFF4064DC4AB2F843ACDA8"
+                ),
+            ),
+            (
+                &b"test"[..],
+                hex!("D16B6AE827F17175E040871A1C7EC3500192C4C92677336EC2537ACAEE0008E0"),
+                hex!(
+                    "F1ABB023518351CD71D881567B1EA663ED3EFCF6C5132B354F28D3B0B7D38367"
+                    "019F4113742A2B14BD25926B49C649155F267E60D3814B4C0CC84250E46F0083"
+                ),
+            ),
+        ];
+
+        let x = secp256r1::Scalar::from_be_bytes(&P256_KEY).unwrap();
+        let signing_key = SigningKey::<NistP256>::from_scalar(x).unwrap();
+        for (msg, k, sig) in vectors {
+            let prehash = sha2::Sha256::digest(msg);
+            assert_eq!(rfc6979_nonce::<NistP256>(&x, &prehash).to_be_bytes(), k);
+            assert_eq!(signing_key.sign(msg).unwrap().to_bytes(), sig);
+        }
+    }
+
+    #[test]
+    fn secp256k1_vector() {
+        let x = secp256k1::Scalar::ONE;
+        let prehash = sha2::Sha256::digest(b"Satoshi Nakamoto");
+        assert_eq!(
+            rfc6979_nonce::<Secp256k1>(&x, &prehash).to_be_bytes(),
+            hex!("8F8A276C19F4149656B280621E358CCE24F5F52542772691EE69063B74F15D15")
+        );
+    }
+
+    #[test]
+    fn bits2field_truncates_and_extends() {
+        let long: Vec<u8> = (0..48).collect();
+        assert_eq!(bits2field::<Secp256k1>(&long)[..], long[..32]);
+
+        let mut expected = [0u8; 32];
+        expected[30..].copy_from_slice(&[7, 8]);
+        assert_eq!(bits2field::<Secp256k1>(&[7, 8])[..], expected[..]);
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn signatures_match_k256(seed in any::<[u8; 32]>(), prehash in any::<[u8; 32]>()) {
+            let Some(x) = Option::<secp256k1::Scalar>::from(secp256k1::Scalar::from_be_bytes(&seed))
+                .filter(|x| !bool::from(x.is_zero()))
+            else {
+                return Ok(());
+            };
+            let mut sig = SigningKey::<Secp256k1>::from_scalar(x)
+                .unwrap()
+                .sign_prehash(&prehash)
+                .unwrap();
+            normalize_s(&mut sig);
+
+            // k256 always returns the low `s` form.
+            let signing_key = k256::ecdsa::SigningKey::from_bytes(&seed.into()).unwrap();
+            let expected: k256::ecdsa::Signature =
+                PrehashSigner::sign_prehash(&signing_key, &prehash).unwrap();
+            prop_assert_eq!(&sig.to_bytes()[..], &expected.to_bytes()[..]);
+        }
+    }
+}
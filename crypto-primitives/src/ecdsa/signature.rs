@@ -0,0 +1,1177 @@
+//! ECDSA signatures and their verification, using the accelerated curve arithmetic.
+
+use super::{
+    CenoAffinePoint, CenoProjectivePoint, ECDSACurve, der,
+    generator::GeneratorTable,
+    projective::scalar_to_bits_le,
+    rfc6979::{bits2field, rfc6979_nonce},
+};
+use crate::{
+    CryptoError,
+    sha256::{Sha256, sha256},
+    uint256::U256,
+};
+use elliptic_curve::{
+    Error, FieldBytes, PrimeField,
+    ff::Field,
+    group::{Group, GroupEncoding},
+    ops::{LinearCombination, MulByGenerator, Reduce},
+    point::{AffineCoordinates, DecompressPoint},
+    scalar::IsHigh,
+    subtle::{Choice, ConditionallySelectable, ConstantTimeEq},
+    zeroize::{Zeroize, ZeroizeOnDrop},
+};
+use std::fmt;
+
+/// An ECDSA signature, given by its scalars `r` and `s`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Signature<C: ECDSACurve> {
+    r: C::Scalar,
+    s: C::Scalar,
+}
+
+impl<C: ECDSACurve> Signature<C> {
+    /// Creates a signature from its scalars, returning an error if either is zero.
+    pub fn from_scalars(r: C::Scalar, s: C::Scalar) -> Result<Self, Error> {
+        if bool::from(r.is_zero() | s.is_zero()) {
+            return Err(Error);
+        }
+
+        Ok(Self { r, s })
+    }
+
+    /// Returns the `r` scalar of the signature.
+    pub fn r(&self) -> C::Scalar {
+        self.r
+    }
+
+    /// Returns the `s` scalar of the signature.
+    pub fn s(&self) -> C::Scalar {
+        self.s
+    }
+
+    /// Returns the fixed size encoding `r || s`, with each scalar big endian.
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&self.r.to_repr());
+        bytes[32..].copy_from_slice(&self.s.to_repr());
+        bytes
+    }
+
+    /// Decodes the fixed size encoding `r || s`, returning an error if either scalar is not
+    /// reduced or is zero.
+    pub fn from_bytes(bytes: &[u8; 64]) -> Result<Self, Error> {
+        let mut r = FieldBytes::<C>::default();
+        r.copy_from_slice(&bytes[..32]);
+        let mut s = FieldBytes::<C>::default();
+        s.copy_from_slice(&bytes[32..]);
+
+        let r = Option::<C::Scalar>::from(C::Scalar::from_repr(r)).ok_or(Error)?;
+        let s = Option::<C::Scalar>::from(C::Scalar::from_repr(s)).ok_or(Error)?;
+        Self::from_scalars(r, s)
+    }
+
+    /// Returns the fixed size encoding of the two scalars in the given order, each big endian.
+    pub fn to_bytes_ordered(&self, order: ComponentOrder) -> [u8; 64] {
+        let bytes = self.to_bytes();
+        match order {
+            ComponentOrder::RFirst => bytes,
+            ComponentOrder::SFirst => swap_halves(&bytes),
+        }
+    }
+
+    /// Decodes the fixed size encoding of the two scalars in the given order, like
+    /// [`Signature::from_bytes`].
+    pub fn from_bytes_ordered(bytes: &[u8; 64], order: ComponentOrder) -> Result<Self, Error> {
+        match order {
+            ComponentOrder::RFirst => Self::from_bytes(bytes),
+            ComponentOrder::SFirst => Self::from_bytes(&swap_halves(bytes)),
+        }
+    }
+
+    /// Returns the ASN.1 DER encoding `SEQUENCE { r INTEGER, s INTEGER }`, at most 72 bytes.
+    pub fn to_der(&self) -> Vec<u8> {
+        der::encode(&self.r.to_repr().into(), &self.s.to_repr().into())
+    }
+
+    /// Decodes the ASN.1 DER encoding written by [`Signature::to_der`].
+    ///
+    /// Returns an error for any encoding that is not strictly DER, such as non-minimal integers or
+    /// trailing bytes, and if either scalar is not in `[1, n)`.
+    pub fn from_der(bytes: &[u8]) -> Result<Self, Error> {
+        let (r, s) = der::decode(bytes)?;
+        let r = Option::<C::Scalar>::from(C::Scalar::from_repr(r.into())).ok_or(Error)?;
+        let s = Option::<C::Scalar>::from(C::Scalar::from_repr(s.into())).ok_or(Error)?;
+        Self::from_scalars(r, s)
+    }
+}
+
+/// The order of the scalars in the fixed size encoding of a [`Signature`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ComponentOrder {
+    /// `r || s`, the order of [`Signature::to_bytes`] and of most libraries.
+    #[default]
+    RFirst,
+    /// `s || r`.
+    SFirst,
+}
+
+/// Swaps the two 32 byte halves of `bytes`.
+fn swap_halves(bytes: &[u8; 64]) -> [u8; 64] {
+    let mut swapped = [0u8; 64];
+    swapped[..32].copy_from_slice(&bytes[32..]);
+    swapped[32..].copy_from_slice(&bytes[..32]);
+    swapped
+}
+
+/// The recovery id of an ECDSA signature, which selects the nonce point `R` among the points whose
+/// X coordinate reduces to `r`.
+///
+/// Bit `0` is the parity of the Y coordinate of `R`, and bit `1` is set if its X coordinate was
+/// not smaller than the curve order `n` and was reduced to obtain `r`. The latter only happens
+/// with a probability of about `2^-128` on the supported curves.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RecoveryId(u8);
+
+impl RecoveryId {
+    /// The largest valid recovery id byte.
+    pub const MAX: u8 = 3;
+
+    /// Creates a recovery id from the parity of `R.y` and whether `R.x` was reduced.
+    pub const fn new(is_y_odd: bool, is_x_reduced: bool) -> Self {
+        Self(((is_x_reduced as u8) << 1) | is_y_odd as u8)
+    }
+
+    /// Decodes a recovery id byte, returning none if it is above [`RecoveryId::MAX`].
+    pub const fn from_byte(byte: u8) -> Option<Self> {
+        if byte <= Self::MAX {
+            Some(Self(byte))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the recovery id as a byte in `0..=3`.
+    pub const fn to_byte(self) -> u8 {
+        self.0
+    }
+
+    /// Returns true if the Y coordinate of `R` is odd.
+    pub const fn is_y_odd(self) -> bool {
+        self.0 & 1 != 0
+    }
+
+    /// Returns true if the X coordinate of `R` was reduced modulo the curve order.
+    pub const fn is_x_reduced(self) -> bool {
+        self.0 & 2 != 0
+    }
+}
+
+impl TryFrom<u8> for RecoveryId {
+    type Error = Error;
+
+    fn try_from(byte: u8) -> Result<Self, Error> {
+        Self::from_byte(byte).ok_or(Error)
+    }
+}
+
+impl From<RecoveryId> for u8 {
+    fn from(id: RecoveryId) -> u8 {
+        id.to_byte()
+    }
+}
+
+/// Replaces `s` with `n - s` if it is greater than `n / 2`, returning whether it did.
+///
+/// The comparison against `n / 2` is constant time.
+pub fn normalize_s<C: ECDSACurve>(sig: &mut Signature<C>) -> bool {
+    let is_high = sig.s.is_high();
+    sig.s = C::Scalar::conditional_select(&sig.s, &-sig.s, is_high);
+    is_high.into()
+}
+
+/// Derives a key pair deterministically from `seed`.
+///
+/// The secret scalar is the big endian `seed` reduced modulo the curve order `n`, so a seed that
+/// is already a valid secret key yields that key. Only the seeds `0` and `n` reduce to zero, and
+/// they are replaced by `SHA-256(seed || i)` for the first counter byte `i` that reduces to a
+/// non-zero scalar. The public key is computed with the accelerated [`MulByGenerator`].
+pub fn keypair_from_seed<C: ECDSACurve>(seed: &[u8; 32]) -> (SigningKey<C>, VerifyingKey<C>) {
+    let mut scalar = <C::Scalar as Reduce<C::Uint>>::reduce_bytes(&(*seed).into());
+    let mut counter = 0u8;
+    while bool::from(scalar.is_zero()) {
+        let mut hasher = Sha256::new();
+        hasher.update(seed);
+        hasher.update(&[counter]);
+        scalar = <C::Scalar as Reduce<C::Uint>>::reduce_bytes(&hasher.finalize().into());
+        counter += 1;
+    }
+
+    let signing_key = SigningKey { scalar };
+    let verifying_key = signing_key.verifying_key();
+    (signing_key, verifying_key)
+}
+
+/// Returns the 33 byte compressed SEC1 encoding of the public key `d · G` of the big endian secret
+/// `d`, computed with the accelerated [`MulByGenerator`].
+///
+/// Returns an error if `secret` is zero or not smaller than the curve order.
+pub fn public_key_compressed<C: ECDSACurve>(secret: &[u8; 32]) -> Result<[u8; 33], Error> {
+    let verifying_key = SigningKey::<C>::from_bytes(&(*secret).into())?.verifying_key();
+
+    let mut bytes = [0u8; 33];
+    bytes.copy_from_slice(&verifying_key.point.to_bytes());
+    Ok(bytes)
+}
+
+/// An ECDSA secret key.
+///
+/// Its [`Debug`](fmt::Debug) output does not show the secret, and keys are compared in constant
+/// time. The key is not [`Copy`], so that the secret is only ever in the places it was explicitly
+/// cloned to, and each of them is zeroized when dropped.
+#[derive(Clone)]
+pub struct SigningKey<C: ECDSACurve> {
+    scalar: C::Scalar,
+}
+
+impl<C: ECDSACurve> SigningKey<C> {
+    /// Creates a signing key from a secret scalar, returning an error if it is zero.
+    pub fn from_scalar(scalar: C::Scalar) -> Result<Self, Error> {
+        if scalar.is_zero().into() {
+            return Err(Error);
+        }
+
+        Ok(Self { scalar })
+    }
+
+    /// Creates a signing key from big endian bytes, returning an error if they do not encode a
+    /// non-zero scalar.
+    pub fn from_bytes(bytes: &FieldBytes<C>) -> Result<Self, Error> {
+        let scalar = Option::<C::Scalar>::from(C::Scalar::from_repr(*bytes)).ok_or(Error)?;
+        Self::from_scalar(scalar)
+    }
+
+    /// Returns the secret scalar of the key.
+    pub fn as_scalar(&self) -> &C::Scalar {
+        &self.scalar
+    }
+
+    /// Returns the public key `d · G` matching this key.
+    pub fn verifying_key(&self) -> VerifyingKey<C> {
+        VerifyingKey {
+            point: CenoProjectivePoint::<C>::mul_by_generator(&self.scalar).to_affine(),
+        }
+    }
+
+    /// Signs the SHA-256 digest of `msg`, see [`SigningKey::sign_prehash`].
+    pub fn sign(&self, msg: &[u8]) -> Result<Signature<C>, Error> {
+        self.sign_prehash(&sha256(msg))
+    }
+
+    /// Signs the message digest `prehash`, with the nonce derived deterministically as in
+    /// RFC 6979.
+    ///
+    /// The returned `s` is not normalized, see [`normalize_s`].
+    pub fn sign_prehash(&self, prehash: &[u8]) -> Result<Signature<C>, Error> {
+        let k = rfc6979_nonce::<C>(&self.scalar, prehash);
+        self.sign_prehash_with_nonce(prehash, &k)
+            .map(|(sig, _)| sig)
+    }
+
+    /// Signs the message digest `prehash` with the caller supplied nonce `k`, returning the
+    /// signature together with its recovery id for [`VerifyingKey::recover_from_prehash`].
+    ///
+    /// This is a hazardous function: reusing `k` for two different digests, or choosing it
+    /// predictably, reveals the secret key. Prefer [`SigningKey::sign_prehash`] unless the nonce
+    /// comes from a protocol that guarantees its secrecy and uniqueness.
+    ///
+    /// Fails if `k`, `r` or `s` is zero, in which case the caller should retry with another nonce.
+    /// The returned `s` is not normalized, see [`normalize_s`].
+    pub fn sign_prehash_with_nonce(
+        &self,
+        prehash: &[u8],
+        k: &C::Scalar,
+    ) -> Result<(Signature<C>, RecoveryId), Error> {
+        let z = <C::Scalar as Reduce<C::Uint>>::reduce_bytes(&bits2field::<C>(prehash));
+        let k_inv = Option::<C::Scalar>::from(<C::Scalar as Field>::invert(k)).ok_or(Error)?;
+
+        let nonce = CenoProjectivePoint::<C>::mul_by_generator(k).to_affine();
+        let x = nonce.x();
+        let r = <C::Scalar as Reduce<C::Uint>>::reduce_bytes(&x);
+        let s = k_inv * (z + r * self.scalar);
+        let sig = Signature::from_scalars(r, s)?;
+
+        let x_is_reduced = U256::from_be_bytes(&x.into()) >= <C as ECDSACurve>::ORDER;
+        Ok((sig, RecoveryId::new(nonce.y_is_odd().into(), x_is_reduced)))
+    }
+}
+
+impl<C: ECDSACurve> fmt::Debug for SigningKey<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SigningKey").finish_non_exhaustive()
+    }
+}
+
+impl<C: ECDSACurve> ConstantTimeEq for SigningKey<C> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.scalar.ct_eq(&other.scalar)
+    }
+}
+
+impl<C: ECDSACurve> PartialEq for SigningKey<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl<C: ECDSACurve> Eq for SigningKey<C> {}
+
+impl<C: ECDSACurve> Zeroize for SigningKey<C> {
+    /// Overwrites the secret scalar with zero, after which the key must not be used.
+    fn zeroize(&mut self) {
+        self.scalar.zeroize();
+    }
+}
+
+impl<C: ECDSACurve> Drop for SigningKey<C> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<C: ECDSACurve> ZeroizeOnDrop for SigningKey<C> {}
+
+/// An ECDSA public key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VerifyingKey<C: ECDSACurve> {
+    point: CenoAffinePoint<C>,
+}
+
+impl<C: ECDSACurve> VerifyingKey<C> {
+    /// Creates a verifying key from a curve point, returning an error for the identity.
+    pub fn from_affine(point: CenoAffinePoint<C>) -> Result<Self, Error> {
+        if point.is_identity().into() {
+            return Err(Error);
+        }
+
+        Ok(Self { point })
+    }
+
+    /// Returns the curve point of the key.
+    pub fn as_affine(&self) -> &CenoAffinePoint<C> {
+        &self.point
+    }
+
+    /// Recovers the public key that produced `sig` over the message digest `prehash`.
+    ///
+    /// The `recovery_id` selects the nonce point `R`, see [`RecoveryId`]. The key is recovered as
+    /// `Q = (s / r) · R - (z / r) · G` with a single accelerated [`LinearCombination::lincomb`].
+    ///
+    /// Fails with [`CryptoError::InvalidEncoding`] for a recovery id whose reduced X coordinate
+    /// does not fit the base field, with [`CryptoError::NotOnCurve`] if `R`
+    /// does not exist, and with [`CryptoError::IdentityPoint`] if the recovered key is the
+    /// identity.
+    pub fn recover_from_prehash(
+        prehash: &[u8],
+        sig: &Signature<C>,
+        recovery_id: RecoveryId,
+    ) -> Result<Self, CryptoError> {
+        let mut x = U256::from_be_bytes(&sig.r.to_repr().into());
+        if recovery_id.is_x_reduced() {
+            let (sum, carry) = x.overflowing_add(&<C as ECDSACurve>::ORDER);
+            if carry {
+                return Err(CryptoError::InvalidEncoding);
+            }
+            x = sum;
+        }
+
+        let y_is_odd = Choice::from(recovery_id.is_y_odd() as u8);
+        let nonce = Option::<CenoAffinePoint<C>>::from(CenoAffinePoint::<C>::decompress(
+            &x.to_be_bytes().into(),
+            y_is_odd,
+        ))
+        .ok_or(CryptoError::NotOnCurve)?;
+
+        let z = <C::Scalar as Reduce<C::Uint>>::reduce_bytes(&bits2field::<C>(prehash));
+        let r_inv = Option::<C::Scalar>::from(<C::Scalar as Field>::invert(&sig.r))
+            .ok_or(CryptoError::ZeroScalar)?;
+
+        let point = CenoProjectivePoint::<C>::lincomb(
+            &CenoProjectivePoint::<C>::from(nonce),
+            &(sig.s * r_inv),
+            &CenoProjectivePoint::<C>::generator(),
+            &-(z * r_inv),
+        );
+
+        Self::from_affine(point.to_affine()).map_err(|_| CryptoError::IdentityPoint)
+    }
+
+    /// Recovers every public key for which `sig` is valid over the message digest `prehash`.
+    ///
+    /// Each of the four recovery ids is tried with [`VerifyingKey::recover_from_prehash`], and
+    /// every recovered key is checked with [`VerifyingKey::verify_prehash`]. Usually two keys are
+    /// returned, one for each parity of the nonce point.
+    pub fn recover_all(prehash: &[u8], sig: &Signature<C>) -> Vec<Self> {
+        (0..=RecoveryId::MAX)
+            .filter_map(RecoveryId::from_byte)
+            .filter_map(|recovery_id| Self::recover_from_prehash(prehash, sig, recovery_id).ok())
+            .filter(|key| key.verify_prehash(prehash, sig).is_ok())
+            .collect()
+    }
+
+    /// Verifies `sig` over the SHA-256 digest of `msg`, see [`VerifyingKey::verify_prehash`].
+    pub fn verify(&self, msg: &[u8], sig: &Signature<C>) -> Result<(), Error> {
+        self.verify_prehash(&sha256(msg), sig)
+    }
+
+    /// Verifies `sig` over the message digest `prehash` of any length, without hashing it again.
+    ///
+    /// The digest is converted with `bits2int` of RFC 6979, keeping its leftmost 256 bits, which
+    /// is the bit length of the orders of the supported curves, and is then reduced modulo the
+    /// order, see [`VerifyingKey::verify_prehashed`]. This matches [`SigningKey::sign_prehash`].
+    pub fn verify_prehash(&self, prehash: &[u8], sig: &Signature<C>) -> Result<(), Error> {
+        self.verify_prehashed(&bits2field::<C>(prehash), sig)
+    }
+
+    /// Verifies `sig` over the message digest `z`.
+    ///
+    /// `R = (z / s) · G + (r / s) · Q` is computed with a single accelerated
+    /// [`LinearCombination::lincomb`], and the signature is valid if the X coordinate of `R`
+    /// reduces to `r`. Both low and high `s` are accepted.
+    pub fn verify_prehashed(&self, z: &FieldBytes<C>, sig: &Signature<C>) -> Result<(), Error> {
+        let z = <C::Scalar as Reduce<C::Uint>>::reduce_bytes(z);
+        let s_inv = Option::<C::Scalar>::from(<C::Scalar as Field>::invert(&sig.s)).ok_or(Error)?;
+
+        let point = CenoProjectivePoint::<C>::lincomb(
+            &CenoProjectivePoint::<C>::generator(),
+            &(z * s_inv),
+            &CenoProjectivePoint::<C>::from(self.point),
+            &(sig.r * s_inv),
+        )
+        .to_affine();
+
+        check_nonce_point(&point, sig)
+    }
+
+    /// Verifies `sig` over the message digest `z` like [`VerifyingKey::verify_prehashed`], but
+    /// rejects signatures whose `s` is not in low form.
+    pub fn verify_strict(&self, z: &FieldBytes<C>, sig: &Signature<C>) -> Result<(), Error> {
+        if sig.s.is_high().into() {
+            return Err(Error);
+        }
+
+        self.verify_prehashed(z, sig)
+    }
+}
+
+/// A public key prepared for verifying many signatures, with a precomputed table of multiples of
+/// its point.
+///
+/// Verification computes `(z / s) · G` with the generator table and `(r / s) · Q` with the table
+/// of `Q`, 129 additions in total, instead of the 256 doublings and about as many additions of
+/// the [`LinearCombination::lincomb`] in [`VerifyingKey`]. Building the table costs 1088
+/// additions, about two plain verifications, and it holds 1025 points, about 68 KiB, for as long
+/// as the key is kept. It pays off for a key that verifies more than a few signatures.
+pub struct PreparedVerifyingKey<C: ECDSACurve> {
+    key: VerifyingKey<C>,
+    table: GeneratorTable<C>,
+}
+
+impl<C: ECDSACurve> PreparedVerifyingKey<C> {
+    /// Precomputes the table of `key`.
+    pub fn new(key: VerifyingKey<C>) -> Self {
+        Self {
+            key,
+            table: GeneratorTable::new(key.point.inner),
+        }
+    }
+
+    /// Returns the public key that was prepared.
+    pub fn verifying_key(&self) -> &VerifyingKey<C> {
+        &self.key
+    }
+
+    /// Verifies `sig` over the SHA-256 digest of `msg`, like [`VerifyingKey::verify`].
+    pub fn verify(&self, msg: &[u8], sig: &Signature<C>) -> Result<(), Error> {
+        self.verify_prehash(&sha256(msg), sig)
+    }
+
+    /// Verifies `sig` over the message digest `prehash`, like [`VerifyingKey::verify_prehash`].
+    pub fn verify_prehash(&self, prehash: &[u8], sig: &Signature<C>) -> Result<(), Error> {
+        self.verify_prehashed(&bits2field::<C>(prehash), sig)
+    }
+
+    /// Verifies `sig` over the message digest `z`, like [`VerifyingKey::verify_prehashed`].
+    pub fn verify_prehashed(&self, z: &FieldBytes<C>, sig: &Signature<C>) -> Result<(), Error> {
+        let z = <C::Scalar as Reduce<C::Uint>>::reduce_bytes(z);
+        let s_inv = Option::<C::Scalar>::from(<C::Scalar as Field>::invert(&sig.s)).ok_or(Error)?;
+
+        let key_term = CenoAffinePoint::<C> {
+            inner: self.table.mul(&scalar_to_bits_le::<C>(&(sig.r * s_inv))),
+        };
+        let point = (CenoProjectivePoint::<C>::mul_by_generator(&(z * s_inv))
+            + CenoProjectivePoint::<C>::from(key_term))
+        .to_affine();
+
+        check_nonce_point(&point, sig)
+    }
+}
+
+impl<C: ECDSACurve> From<VerifyingKey<C>> for PreparedVerifyingKey<C> {
+    fn from(key: VerifyingKey<C>) -> Self {
+        Self::new(key)
+    }
+}
+
+/// Accepts `sig` if the recomputed nonce point `R` is not the identity and its X coordinate
+/// reduces to `r`.
+fn check_nonce_point<C: ECDSACurve>(
+    point: &CenoAffinePoint<C>,
+    sig: &Signature<C>,
+) -> Result<(), Error> {
+    if point.is_identity().into() {
+        return Err(Error);
+    }
+
+    match <C::Scalar as Reduce<C::Uint>>::reduce_bytes(&point.x()) == sig.r {
+        true => Ok(()),
+        false => Err(Error),
+    }
+}
+
+/// Verifies every `(key, msg, sig)` entry like [`VerifyingKey::verify`], returning true only if
+/// all signatures are valid.
+///
+/// The verification equations `(z / s) · G + (r / s) · Q - R = 0` are weighted by scalars
+/// derived from a hash of the whole batch and summed into a single [`CenoProjectivePoint::msm`].
+/// An ECDSA signature only fixes the X coordinate of its nonce point `R`, so each `R` is lifted
+/// with an even Y coordinate. When that guess or any signature is wrong, every entry is verified
+/// on its own instead.
+pub fn verify_batch<C: ECDSACurve>(entries: &[(VerifyingKey<C>, &[u8], Signature<C>)]) -> bool {
+    let combined = batch_equation(entries).map(|(points, scalars)| {
+        bool::from(CenoProjectivePoint::<C>::msm(&points, &scalars).is_identity())
+    });
+
+    combined == Some(true)
+        || entries
+            .iter()
+            .all(|(key, msg, sig)| key.verify(msg, sig).is_ok())
+}
+
+/// Verifies every `(key, msg, sig)` entry like [`verify_batch`], using the threads of the rayon
+/// pool.
+///
+/// The combined equation is computed with [`CenoProjectivePoint::par_msm`], and the fallback
+/// verifies the entries in parallel. This is only available off-target, and the worker threads
+/// need a backend for the precompiles, as described for [`CenoProjectivePoint::par_msm`].
+#[cfg(all(feature = "rayon", not(target_os = "zkvm")))]
+pub fn verify_batch_parallel<C: ECDSACurve>(
+    entries: &[(VerifyingKey<C>, &[u8], Signature<C>)],
+) -> bool {
+    use rayon::prelude::*;
+
+    let combined = batch_equation(entries).map(|(points, scalars)| {
+        bool::from(CenoProjectivePoint::<C>::par_msm(&points, &scalars).is_identity())
+    });
+
+    combined == Some(true)
+        || entries
+            .par_iter()
+            .all(|(key, msg, sig)| key.verify(msg, sig).is_ok())
+}
+
+/// Returns the terms of the weighted sum of the verification equations of `entries`, see
+/// [`verify_batch`], or none if a signature cannot be part of it.
+#[allow(clippy::type_complexity)]
+fn batch_equation<C: ECDSACurve>(
+    entries: &[(VerifyingKey<C>, &[u8], Signature<C>)],
+) -> Option<(Vec<CenoProjectivePoint<C>>, Vec<C::Scalar>)> {
+    let digests: Vec<FieldBytes<C>> = entries
+        .iter()
+        .map(|(_, msg, _)| bits2field::<C>(&sha256(msg)))
+        .collect();
+
+    let mut transcript = Sha256::new();
+    for ((key, _, sig), z) in entries.iter().zip(digests.iter()) {
+        transcript.update(key.point.to_bytes().as_ref());
+        transcript.update(z);
+        transcript.update(&sig.r.to_repr());
+        transcript.update(&sig.s.to_repr());
+    }
+    let seed = transcript.finalize();
+
+    let mut points = vec![CenoProjectivePoint::<C>::generator()];
+    let mut scalars = vec![C::Scalar::ZERO];
+    for (i, ((key, _, sig), z)) in entries.iter().zip(digests.iter()).enumerate() {
+        let mut hasher = Sha256::new();
+        hasher.update(&seed);
+        hasher.update(&(i as u64).to_be_bytes());
+        let weight =
+            <C::Scalar as Reduce<C::Uint>>::reduce_bytes(&bits2field::<C>(&hasher.finalize()));
+
+        let z = <C::Scalar as Reduce<C::Uint>>::reduce_bytes(z);
+        let s_inv = Option::<C::Scalar>::from(<C::Scalar as Field>::invert(&sig.s))?;
+        let nonce = Option::<CenoAffinePoint<C>>::from(CenoAffinePoint::<C>::decompress(
+            &sig.r.to_repr(),
+            Choice::from(0),
+        ))?;
+
+        scalars[0] += weight * z * s_inv;
+        points.push(CenoProjectivePoint::from(key.point));
+        scalars.push(weight * sig.r * s_inv);
+        points.push(CenoProjectivePoint::from(nonce));
+        scalars.push(-weight);
+    }
+
+    Some((points, scalars))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::secp256k1::{Scalar, Secp256k1};
+    use elliptic_curve::sec1::ToEncodedPoint;
+
+    type Entry = (VerifyingKey<Secp256k1>, Vec<u8>, Signature<Secp256k1>);
+
+    /// Returns `count` keys, each with a signature over its own message.
+    fn batch(count: u8) -> Vec<Entry> {
+        (1..=count)
+            .map(|i| {
+                let (signing_key, verifying_key) = keypair_from_seed::<Secp256k1>(&[i; 32]);
+                let msg = vec![i; i as usize];
+                let sig = signing_key.sign(&msg).unwrap();
+                (verifying_key, msg, sig)
+            })
+            .collect()
+    }
+
+    /// Borrows the messages of `entries`, as the batch verifiers expect.
+    fn borrowed(entries: &[Entry]) -> Vec<(VerifyingKey<Secp256k1>, &[u8], Signature<Secp256k1>)> {
+        entries
+            .iter()
+            .map(|(key, msg, sig)| (*key, &msg[..], *sig))
+            .collect()
+    }
+
+    /// Returns the low and high `s` forms of one signature over `prehash`.
+    fn low_and_high(
+        signing_key: &SigningKey<Secp256k1>,
+        prehash: &[u8; 32],
+    ) -> (Signature<Secp256k1>, Signature<Secp256k1>) {
+        let mut low = signing_key.sign_prehash(prehash).unwrap();
+        normalize_s(&mut low);
+        let high = Signature::from_scalars(low.r(), -low.s()).unwrap();
+        (low, high)
+    }
+
+    #[test]
+    fn signature_bytes_round_trip() {
+        let (_, _, sig) = batch(1).remove(0);
+        let bytes = sig.to_bytes();
+        assert_eq!(bytes[..32], sig.r().to_repr()[..]);
+        assert_eq!(Signature::<Secp256k1>::from_bytes(&bytes).unwrap(), sig);
+
+        // An unreduced `r` and a zero `s` are rejected.
+        let mut unreduced = bytes;
+        unreduced[..32].copy_from_slice(&Scalar::MODULUS.to_be_bytes());
+        assert!(Signature::<Secp256k1>::from_bytes(&unreduced).is_err());
+        let mut zero = bytes;
+        zero[32..].fill(0);
+        assert!(Signature::<Secp256k1>::from_bytes(&zero).is_err());
+    }
+
+    #[test]
+    fn ordered_bytes_round_trip() {
+        let (_, _, sig) = batch(1).remove(0);
+        assert_eq!(
+            sig.to_bytes_ordered(ComponentOrder::default()),
+            sig.to_bytes()
+        );
+
+        let swapped = sig.to_bytes_ordered(ComponentOrder::SFirst);
+        assert_eq!(swapped[..32], sig.s().to_repr()[..]);
+        assert_eq!(swapped[32..], sig.r().to_repr()[..]);
+        for order in [ComponentOrder::RFirst, ComponentOrder::SFirst] {
+            let bytes = sig.to_bytes_ordered(order);
+            assert_eq!(
+                Signature::<Secp256k1>::from_bytes_ordered(&bytes, order).unwrap(),
+                sig
+            );
+        }
+    }
+
+    #[test]
+    fn der_round_trip() {
+        let (signing_key, _) = keypair_from_seed::<Secp256k1>(&[9; 32]);
+        for prehash in [[1u8; 32], [2; 32], [3; 32]] {
+            let (low, high) = low_and_high(&signing_key, &prehash);
+            for sig in [low, high] {
+                let der = sig.to_der();
+                assert!(der.len() <= der::MAX_LEN);
+                assert_eq!(Signature::<Secp256k1>::from_der(&der).unwrap(), sig);
+            }
+
+            // k256 only accepts low `s`, and writes the same bytes.
+            let expected = k256::ecdsa::Signature::from_slice(&low.to_bytes()).unwrap();
+            assert_eq!(low.to_der(), expected.to_der().as_bytes());
+        }
+
+        // Small scalars have short encodings.
+        let sig =
+            Signature::<Secp256k1>::from_scalars(Scalar::ONE, Scalar::from_u64(0x80)).unwrap();
+        assert_eq!(
+            sig.to_der(),
+            [0x30, 0x07, 0x02, 0x01, 0x01, 0x02, 0x02, 0x00, 0x80]
+        );
+    }
+
+    #[test]
+    fn der_rejects_scalars_out_of_range() {
+        let (_, _, sig) = batch(1).remove(0);
+        let order: [u8; 32] = Scalar::MODULUS.to_be_bytes();
+        let r: [u8; 32] = sig.r().to_repr().into();
+        for (r, s) in [(order, r), (r, order), ([0; 32], r), (r, [0; 32])] {
+            assert!(Signature::<Secp256k1>::from_der(&der::encode(&r, &s)).is_err());
+        }
+
+        let mut trailing = sig.to_der();
+        trailing.push(0);
+        assert!(Signature::<Secp256k1>::from_der(&trailing).is_err());
+    }
+
+    #[test]
+    fn signing_keys_hide_the_secret() {
+        let (signing_key, _) = keypair_from_seed::<Secp256k1>(&[0xab; 32]);
+        let debug = format!("{signing_key:?}");
+        assert_eq!(debug, "SigningKey { .. }");
+        assert!(!debug.contains("ab"));
+
+        let (same, _) = keypair_from_seed::<Secp256k1>(&[0xab; 32]);
+        let (other, _) = keypair_from_seed::<Secp256k1>(&[0xcd; 32]);
+        assert!(bool::from(signing_key.ct_eq(&same)));
+        assert!(!bool::from(signing_key.ct_eq(&other)));
+        assert_eq!(signing_key, same);
+        assert_ne!(signing_key, other);
+    }
+
+    #[test]
+    fn signing_keys_zeroize() {
+        let (mut signing_key, _) = keypair_from_seed::<Secp256k1>(&[5; 32]);
+        assert!(!bool::from(signing_key.as_scalar().is_zero()));
+        signing_key.zeroize();
+        assert!(bool::from(signing_key.as_scalar().is_zero()));
+    }
+
+    #[test]
+    fn signing_keys_zeroize_on_drop() {
+        let (signing_key, _) = keypair_from_seed::<Secp256k1>(&[5; 32]);
+        let mut signing_key = std::mem::ManuallyDrop::new(signing_key);
+        assert!(!bool::from(signing_key.as_scalar().is_zero()));
+
+        // The memory stays in place after the drop, as a dropped key's would until it is reused.
+        unsafe { std::mem::ManuallyDrop::drop(&mut signing_key) };
+        assert!(bool::from(signing_key.as_scalar().is_zero()));
+    }
+
+    #[test]
+    fn keypair_from_seed_is_deterministic() {
+        let (signing_key, verifying_key) = keypair_from_seed::<Secp256k1>(&[9; 32]);
+        let (again, again_public) = keypair_from_seed::<Secp256k1>(&[9; 32]);
+        assert_eq!(signing_key.as_scalar(), again.as_scalar());
+        assert_eq!(verifying_key, again_public);
+        assert_ne!(keypair_from_seed::<Secp256k1>(&[10; 32]).1, verifying_key);
+
+        // A seed that is a valid secret key yields that key.
+        let mut seed = [0u8; 32];
+        seed[31] = 1;
+        let (_, public) = keypair_from_seed::<Secp256k1>(&seed);
+        assert_eq!(*public.as_affine(), CenoAffinePoint::generator());
+
+        let (x, y) = verifying_key.as_affine().coordinates().unwrap();
+        assert!(bool::from(
+            CenoAffinePoint::<Secp256k1>::from_field_elements(x, y).is_some()
+        ));
+    }
+
+    #[test]
+    fn keypair_from_seed_rederives_zero_scalars() {
+        for seed in [[0u8; 32], Scalar::MODULUS.to_be_bytes()] {
+            let (signing_key, verifying_key) = keypair_from_seed::<Secp256k1>(&seed);
+            assert!(!bool::from(signing_key.as_scalar().is_zero()));
+
+            let digest = sha256(&[&seed[..], &[0]].concat());
+            let expected =
+                <Scalar as Reduce<elliptic_curve::bigint::U256>>::reduce_bytes(&digest.into());
+            assert_eq!(*signing_key.as_scalar(), expected);
+            assert_eq!(verifying_key, signing_key.verifying_key());
+        }
+    }
+
+    #[test]
+    fn public_key_compressed_known_answers() {
+        let mut one = [0u8; 32];
+        one[31] = 1;
+        assert_eq!(
+            public_key_compressed::<Secp256k1>(&one).unwrap(),
+            hex_literal::hex!("0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798")
+        );
+        assert_eq!(
+            public_key_compressed::<crate::secp256r1::NistP256>(&one).unwrap(),
+            hex_literal::hex!("036b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296")
+        );
+
+        let secret =
+            hex_literal::hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
+        let expected = k256::SecretKey::from_slice(&secret).unwrap().public_key();
+        assert_eq!(
+            public_key_compressed::<Secp256k1>(&secret).unwrap()[..],
+            *expected.to_encoded_point(true).as_bytes()
+        );
+    }
+
+    #[test]
+    fn public_key_compressed_rejects_invalid_secrets() {
+        let order = Scalar::MODULUS.to_be_bytes();
+        for secret in [[0u8; 32], order, [0xff; 32]] {
+            assert!(public_key_compressed::<Secp256k1>(&secret).is_err());
+        }
+        // `n - 1` is the largest valid secret, and its key is `-G`.
+        let mut largest = order;
+        largest[31] -= 1;
+        assert_eq!(
+            public_key_compressed::<Secp256k1>(&largest).unwrap(),
+            hex_literal::hex!("0379be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798")
+        );
+    }
+
+    #[test]
+    fn verify_prehash_matches_verify() {
+        let (_, msg, sig) = batch(2).remove(1);
+        let (signing_key, verifying_key) = keypair_from_seed::<Secp256k1>(&[2; 32]);
+        let digest = sha256(&msg);
+        assert!(verifying_key.verify(&msg, &sig).is_ok());
+        assert!(verifying_key.verify_prehash(&digest, &sig).is_ok());
+        assert!(
+            verifying_key
+                .verify_prehash(&sha256(b"other"), &sig)
+                .is_err()
+        );
+
+        // Digests of other lengths are truncated or zero extended by `bits2int`, as in k256.
+        let k256_key = k256::ecdsa::VerifyingKey::from_sec1_bytes(
+            verifying_key.as_affine().to_encoded_point(false).as_bytes(),
+        )
+        .unwrap();
+        for prehash in [&[0x5a; 64][..], &[0xa5; 20][..]] {
+            let mut sig = signing_key.sign_prehash(prehash).unwrap();
+            assert!(verifying_key.verify_prehash(prehash, &sig).is_ok());
+            normalize_s(&mut sig);
+            let k256_sig = k256::ecdsa::Signature::from_slice(&sig.to_bytes()).unwrap();
+            assert!(
+                k256::ecdsa::signature::hazmat::PrehashVerifier::verify_prehash(
+                    &k256_key, prehash, &k256_sig
+                )
+                .is_ok()
+            );
+        }
+        let sig = signing_key.sign_prehash(&[0x5a; 64]).unwrap();
+        assert!(verifying_key.verify_prehash(&[0x5a; 32], &sig).is_ok());
+    }
+
+    #[test]
+    fn normalize_s_flips_only_high_s() {
+        let (signing_key, _) = keypair_from_seed::<Secp256k1>(&[3; 32]);
+        let (low, high) = low_and_high(&signing_key, &[0x42; 32]);
+        assert!(!bool::from(low.s().is_high()));
+
+        let mut sig = high;
+        assert!(normalize_s(&mut sig));
+        assert_eq!(sig, low);
+        assert!(!normalize_s(&mut sig));
+        assert_eq!(sig, low);
+    }
+
+    #[test]
+    fn verify_strict_rejects_high_s() {
+        let (signing_key, verifying_key) = keypair_from_seed::<Secp256k1>(&[3; 32]);
+        let prehash = [0x42; 32];
+        let (low, high) = low_and_high(&signing_key, &prehash);
+        let z = prehash.into();
+
+        assert!(verifying_key.verify_prehashed(&z, &low).is_ok());
+        assert!(verifying_key.verify_prehashed(&z, &high).is_ok());
+        assert!(verifying_key.verify_strict(&z, &low).is_ok());
+        assert!(verifying_key.verify_strict(&z, &high).is_err());
+    }
+
+    #[test]
+    fn verify_batch_detects_a_bad_entry() {
+        let mut entries = batch(5);
+        assert!(verify_batch(&borrowed(&entries)));
+        assert!(verify_batch::<Secp256k1>(&[]));
+
+        entries[3].1.push(0);
+        assert!(!verify_batch(&borrowed(&entries)));
+    }
+
+    #[test]
+    fn verify_batch_rejects_swapped_and_tampered_signatures() {
+        let mut entries = batch(4);
+        let (first, second) = (entries[0].2, entries[1].2);
+        entries[0].2 = second;
+        entries[1].2 = first;
+        assert!(!verify_batch(&borrowed(&entries)));
+
+        let mut entries = batch(4);
+        let sig = entries[2].2;
+        entries[2].2 = Signature::from_scalars(sig.r(), sig.s() + Scalar::ONE).unwrap();
+        assert!(!verify_batch(&borrowed(&entries)));
+
+        // Both `s` forms of a signature are accepted, whichever parity of `R` they imply.
+        let mut entries = batch(4);
+        for entry in &mut entries {
+            entry.2 = Signature::from_scalars(entry.2.r(), -entry.2.s()).unwrap();
+        }
+        assert!(verify_batch(&borrowed(&entries)));
+    }
+
+    #[test]
+    #[cfg(all(feature = "rayon", not(target_os = "zkvm")))]
+    fn verify_batch_parallel_matches_verify_batch() {
+        let mut entries = batch(5);
+        assert!(verify_batch_parallel(&borrowed(&entries)));
+        assert!(verify_batch(&borrowed(&entries)));
+
+        entries[3].1.push(0);
+        assert!(!verify_batch_parallel(&borrowed(&entries)));
+        assert!(!verify_batch(&borrowed(&entries)));
+    }
+
+    #[test]
+    fn prepared_key_matches_the_plain_key() {
+        let entries = batch(3);
+        for (key, _, _) in &entries {
+            let prepared = PreparedVerifyingKey::from(*key);
+            assert_eq!(prepared.verifying_key(), key);
+
+            // Every key is checked against every message and signature, so each one sees its
+            // own valid signature and the others' invalid ones.
+            for (_, msg, sig) in &entries {
+                assert_eq!(
+                    prepared.verify(msg, sig).is_ok(),
+                    key.verify(msg, sig).is_ok()
+                );
+                let tampered = Signature::from_scalars(sig.r(), sig.s() + Scalar::ONE).unwrap();
+                assert!(prepared.verify(msg, &tampered).is_err());
+                assert!(key.verify(msg, &tampered).is_err());
+            }
+            let (_, msg, sig) = &entries[0];
+            assert_eq!(
+                prepared.verify_prehash(&sha256(msg), sig).is_ok(),
+                key.verify(msg, sig).is_ok()
+            );
+        }
+    }
+
+    #[test]
+    fn prepared_key_verifies_on_p256() {
+        use crate::secp256r1::NistP256;
+
+        let (signing_key, key) = keypair_from_seed::<NistP256>(&[7; 32]);
+        let sig = signing_key.sign(b"message").unwrap();
+        let prepared = PreparedVerifyingKey::new(key);
+        assert!(prepared.verify(b"message", &sig).is_ok());
+        assert!(key.verify(b"message", &sig).is_ok());
+        assert!(prepared.verify(b"other message", &sig).is_err());
+    }
+
+    #[test]
+    fn sign_with_a_fixed_nonce() {
+        // The RFC 6979 nonce of key 1 for "Satoshi Nakamoto", and the resulting low `s` signature.
+        let signing_key = SigningKey::<Secp256k1>::from_scalar(Scalar::ONE).unwrap();
+        let prehash = sha256(b"Satoshi Nakamoto");
+        let k = Scalar::from_be_bytes(&hex_literal::hex!(
+            "8f8a276c19f4149656b280621e358cce24f5f52542772691ee69063b74f15d15"
+        ))
+        .unwrap();
+        let (mut sig, _) = signing_key.sign_prehash_with_nonce(&prehash, &k).unwrap();
+        normalize_s(&mut sig);
+        assert_eq!(
+            sig.to_bytes(),
+            hex_literal::hex!(
+                "934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d8"
+                "2442ce9d2b916064108014783e923ec36b49743e2ffa1c4496f01a512aafd9e5"
+            )
+        );
+        assert_eq!(
+            signing_key.sign_prehash_with_nonce(&prehash, &k).unwrap().0,
+            signing_key.sign_prehash(&prehash).unwrap()
+        );
+
+        // With `d = k = 1`, `R = G` has an even Y and `s = z + r`.
+        let prehash = [0x11; 32];
+        let (sig, recovery_id) = signing_key
+            .sign_prehash_with_nonce(&prehash, &Scalar::ONE)
+            .unwrap();
+        let g = CenoAffinePoint::<Secp256k1>::generator();
+        let r = <Scalar as Reduce<elliptic_curve::bigint::U256>>::reduce_bytes(&g.x());
+        let z = Scalar::from_be_bytes(&prehash).unwrap();
+        assert_eq!((sig.r(), sig.s()), (r, z + r));
+        assert_eq!(recovery_id, RecoveryId::new(false, false));
+
+        assert!(
+            signing_key
+                .sign_prehash_with_nonce(&prehash, &Scalar::ZERO)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn recover_returns_the_signing_key() {
+        for i in 1..=8u8 {
+            let (signing_key, verifying_key) = keypair_from_seed::<Secp256k1>(&[i; 32]);
+            let prehash = [i.wrapping_mul(37); 32];
+            let k = Scalar::from_u64(1000 + i as u64);
+            let (sig, recovery_id) = signing_key.sign_prehash_with_nonce(&prehash, &k).unwrap();
+
+            let recovered =
+                VerifyingKey::recover_from_prehash(&prehash, &sig, recovery_id).unwrap();
+            assert_eq!(recovered, verifying_key);
+
+            // k256 only accepts low `s`, and negating `s` negates `R` too.
+            let mut low = sig;
+            let flipped = normalize_s(&mut low);
+            let expected = k256::ecdsa::VerifyingKey::recover_from_prehash(
+                &prehash,
+                &k256::ecdsa::Signature::from_slice(&low.to_bytes()).unwrap(),
+                k256::ecdsa::RecoveryId::from_byte(recovery_id.to_byte() ^ flipped as u8).unwrap(),
+            )
+            .unwrap();
+            assert_eq!(
+                recovered.as_affine().to_encoded_point(false).as_bytes(),
+                expected.to_encoded_point(false).as_bytes()
+            );
+
+            // The other parity recovers a different key.
+            let flipped = RecoveryId::new(!recovery_id.is_y_odd(), false);
+            assert_ne!(
+                VerifyingKey::recover_from_prehash(&prehash, &sig, flipped).unwrap(),
+                verifying_key
+            );
+        }
+    }
+
+    #[test]
+    fn recover_all_contains_the_signer() {
+        for i in 1..=4u8 {
+            let (signing_key, verifying_key) = keypair_from_seed::<Secp256k1>(&[i; 32]);
+            let prehash = [i; 32];
+            let sig = signing_key.sign_prehash(&prehash).unwrap();
+
+            let keys = VerifyingKey::recover_all(&prehash, &sig);
+            assert_eq!(keys.len(), 2);
+            assert!(keys.contains(&verifying_key));
+            assert_ne!(keys[0], keys[1]);
+            for key in &keys {
+                assert!(key.verify_prehash(&prehash, &sig).is_ok());
+            }
+        }
+
+        // No key is valid for an `r` without a curve point.
+        let sig = Signature::<Secp256k1>::from_scalars(Scalar::from_u64(5), Scalar::ONE).unwrap();
+        assert!(VerifyingKey::recover_all(&[1; 32], &sig).is_empty());
+    }
+
+    #[test]
+    fn recovery_id_bytes_round_trip() {
+        for byte in 0..=RecoveryId::MAX {
+            let id = RecoveryId::from_byte(byte).unwrap();
+            assert_eq!(id.to_byte(), byte);
+            assert_eq!(u8::from(id), byte);
+            assert_eq!(RecoveryId::try_from(byte), Ok(id));
+            assert_eq!(id.is_y_odd(), byte & 1 != 0);
+            assert_eq!(id.is_x_reduced(), byte & 2 != 0);
+            assert_eq!(RecoveryId::new(id.is_y_odd(), id.is_x_reduced()), id);
+        }
+        for byte in RecoveryId::MAX + 1..=u8::MAX {
+            assert_eq!(RecoveryId::from_byte(byte), None);
+            assert!(RecoveryId::try_from(byte).is_err());
+        }
+    }
+
+    #[test]
+    fn recover_with_a_reduced_x() {
+        // No nonce with `R.x ≥ n` is known, so the signature is crafted instead: `r` is the
+        // smallest value for which `r + n` is the X coordinate of a curve point.
+        let prehash = [3; 32];
+        let (sig, key) = (1..)
+            .find_map(|r| {
+                let sig =
+                    Signature::<Secp256k1>::from_scalars(Scalar::from_u64(r), Scalar::ONE).ok()?;
+                let key = VerifyingKey::recover_from_prehash(
+                    &prehash,
+                    &sig,
+                    RecoveryId::new(false, true),
+                )
+                .ok()?;
+                Some((sig, key))
+            })
+            .unwrap();
+
+        assert!(key.verify_prehash(&prehash, &sig).is_ok());
+        assert!(VerifyingKey::recover_all(&prehash, &sig).contains(&key));
+
+        let expected = k256::ecdsa::VerifyingKey::recover_from_prehash(
+            &prehash,
+            &k256::ecdsa::Signature::from_slice(&sig.to_bytes()).unwrap(),
+            k256::ecdsa::RecoveryId::new(false, true),
+        )
+        .unwrap();
+        assert_eq!(
+            key.as_affine().to_encoded_point(false).as_bytes(),
+            expected.to_encoded_point(false).as_bytes()
+        );
+
+        // The other parity recovers the negated nonce point, and so a different valid key.
+        let odd = VerifyingKey::recover_from_prehash(&prehash, &sig, RecoveryId::new(true, true))
+            .unwrap();
+        assert_ne!(odd, key);
+        assert!(odd.verify_prehash(&prehash, &sig).is_ok());
+    }
+
+    #[test]
+    fn recover_rejects_reduced_x_that_overflows() {
+        let sig = Signature::<Secp256k1>::from_scalars(-Scalar::ONE, Scalar::ONE).unwrap();
+        assert_eq!(
+            VerifyingKey::recover_from_prehash(&[1; 32], &sig, RecoveryId::new(false, true))
+                .unwrap_err(),
+            CryptoError::InvalidEncoding
+        );
+    }
+
+    #[test]
+    fn recover_rejects_r_without_a_point() {
+        // There is no point with X = 5 on secp256k1, as 5^3 + 7 is not a square.
+        let sig = Signature::<Secp256k1>::from_scalars(Scalar::from_u64(5), Scalar::ONE).unwrap();
+        assert_eq!(
+            VerifyingKey::recover_from_prehash(&[1; 32], &sig, RecoveryId::new(false, false))
+                .unwrap_err(),
+            CryptoError::NotOnCurve
+        );
+    }
+
+    #[test]
+    fn recover_rejects_the_identity() {
+        // With `R = k · G`, `s = 1` and `z = k`, the key `(s · R - z · G) / r` is the identity.
+        let k = Scalar::from_u64(5);
+        let nonce = (CenoProjectivePoint::<Secp256k1>::generator() * k).to_affine();
+        let r = <Scalar as Reduce<elliptic_curve::bigint::U256>>::reduce_bytes(&nonce.x());
+        let sig = Signature::<Secp256k1>::from_scalars(r, Scalar::ONE).unwrap();
+        let recovery_id = RecoveryId::new(nonce.y_is_odd().into(), false);
+        assert_eq!(
+            VerifyingKey::recover_from_prehash(&k.to_be_bytes(), &sig, recovery_id).unwrap_err(),
+            CryptoError::IdentityPoint
+        );
+    }
+}
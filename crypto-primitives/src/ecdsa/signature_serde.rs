@@ -0,0 +1,97 @@
+//! Serde support for [`Signature`], as the fixed size bytes `r || s` of
+//! [`Signature::to_bytes`].
+//!
+//! Formats without native byte strings may also deserialize the signature from a sequence of 64
+//! bytes.
+
+use super::{ECDSACurve, Signature};
+use core::{fmt, marker::PhantomData};
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{Error as _, SeqAccess, Visitor},
+};
+
+impl<C: ECDSACurve> Serialize for Signature<C> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.to_bytes())
+    }
+}
+
+impl<'de, C: ECDSACurve> Deserialize<'de> for Signature<C> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_bytes(SignatureVisitor(PhantomData))
+    }
+}
+
+struct SignatureVisitor<C>(PhantomData<C>);
+
+impl<'de, C: ECDSACurve> Visitor<'de> for SignatureVisitor<C> {
+    type Value = Signature<C>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("64 bytes encoding the reduced, non-zero scalars r || s")
+    }
+
+    fn visit_bytes<E: serde::de::Error>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+        let bytes: &[u8; 64] = bytes
+            .try_into()
+            .map_err(|_| E::invalid_length(bytes.len(), &self))?;
+        Signature::from_bytes(bytes)
+            .map_err(|_| E::invalid_value(serde::de::Unexpected::Bytes(bytes), &self))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = [0u8; 64];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = seq
+                .next_element()?
+                .ok_or_else(|| A::Error::invalid_length(i, &self))?;
+        }
+        if seq.next_element::<u8>()?.is_some() {
+            return Err(A::Error::invalid_length(65, &self));
+        }
+        self.visit_bytes(&bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ecdsa::keypair_from_seed,
+        secp256k1::{Scalar, Secp256k1},
+    };
+
+    fn signature() -> Signature<Secp256k1> {
+        let (signing_key, _) = keypair_from_seed::<Secp256k1>(&[1; 32]);
+        signing_key.sign(b"serde").unwrap()
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let sig = signature();
+        let json = serde_json::to_string(&sig).unwrap();
+        let bytes: Vec<u8> = serde_json::from_str(&json).unwrap();
+        assert_eq!(bytes, sig.to_bytes());
+        assert_eq!(
+            serde_json::from_str::<Signature<Secp256k1>>(&json).unwrap(),
+            sig
+        );
+    }
+
+    #[test]
+    fn invalid_signatures_are_rejected() {
+        let bytes = signature().to_bytes().to_vec();
+        let decode = |bytes: &[u8]| {
+            serde_json::from_str::<Signature<Secp256k1>>(&serde_json::to_string(bytes).unwrap())
+        };
+        assert!(decode(&bytes).is_ok());
+        assert!(decode(&bytes[..63]).is_err());
+        assert!(decode(&[&bytes[..], &[0]].concat()).is_err());
+
+        let mut unreduced = bytes.clone();
+        unreduced[32..].copy_from_slice(&Scalar::MODULUS.to_be_bytes());
+        assert!(decode(&unreduced).is_err());
+        assert!(decode(&[0; 64]).is_err());
+    }
+}
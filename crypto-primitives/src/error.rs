@@ -0,0 +1,42 @@
+//! The error type of the fallible APIs of this crate.
+
+use core::fmt;
+
+/// An error returned when decoding, decompressing or recovering a curve point fails.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CryptoError {
+    /// The coordinates do not describe a point on the curve.
+    NotOnCurve,
+    /// The decompressed Y coordinate does not have the requested parity.
+    WrongParity,
+    /// The input is not a valid encoding, such as a field element that is not reduced.
+    InvalidEncoding,
+    /// The input has an unexpected length, given in bytes.
+    InvalidLength(usize),
+    /// The operation produced or was given the identity, which is not allowed in its place.
+    IdentityPoint,
+    /// A scalar that must be invertible is zero.
+    ZeroScalar,
+}
+
+impl fmt::Display for CryptoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CryptoError::NotOnCurve => f.write_str("point is not on the curve"),
+            CryptoError::WrongParity => f.write_str("y coordinate has the wrong parity"),
+            CryptoError::InvalidEncoding => f.write_str("invalid encoding"),
+            CryptoError::InvalidLength(len) => write!(f, "invalid length of {len} bytes"),
+            CryptoError::IdentityPoint => f.write_str("unexpected identity point"),
+            CryptoError::ZeroScalar => f.write_str("unexpected zero scalar"),
+        }
+    }
+}
+
+impl core::error::Error for CryptoError {}
+
+impl From<CryptoError> for elliptic_curve::Error {
+    /// Discards the context, for the APIs that follow the opaque errors of `elliptic_curve`.
+    fn from(_: CryptoError) -> Self {
+        elliptic_curve::Error
+    }
+}
@@ -0,0 +1,571 @@
+//! A macro implementing 256 bit prime fields on top of [`crate::uint256::U256`].
+//!
+//! The fields of the curves supported by the ECDSA machinery are all described by a 256 bit
+//! modulus, so a single implementation serves all of them. Multiplication is accelerated with
+//! the uint256 precompile, every other operation is done in software.
+
+/// Implements a prime field element type with the given modulus and [`elliptic_curve::ff`]
+/// constants.
+///
+/// Elements are always fully reduced, so the derived equality and the inner integer are
+/// canonical. All hex constants are big endian and have exactly 64 digits.
+macro_rules! impl_field {
+    (
+        $(#[$attr:meta])*
+        $name:ident,
+        curve = $curve:ty,
+        modulus = $modulus:literal,
+        two_inv = $two_inv:literal,
+        multiplicative_generator = $generator:literal,
+        s = $s:literal,
+        root_of_unity = $root:literal,
+        root_of_unity_inv = $root_inv:literal,
+        delta = $delta:literal,
+        t_minus_one_div_two = $tm1d2:literal $(,)?
+    ) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+        pub struct $name($crate::uint256::U256);
+
+        impl $name {
+            /// The modulus of the field.
+            pub const MODULUS: $crate::uint256::U256 = $crate::uint256::U256::from_be_hex($modulus);
+
+            /// The additive identity.
+            pub const ZERO: Self = Self($crate::uint256::U256::ZERO);
+
+            /// The multiplicative identity.
+            pub const ONE: Self = Self($crate::uint256::U256::ONE);
+
+            /// The exponent `p - 2` used to invert via Fermat's little theorem.
+            const MODULUS_MINUS_TWO: [u64; 4] = Self::MODULUS
+                .overflowing_sub(&$crate::uint256::U256::from_words([2, 0, 0, 0, 0, 0, 0, 0]))
+                .0
+                .to_u64_limbs();
+
+            /// The exponent `(t - 1) / 2`, where `p - 1 = 2^S * t`, used for Tonelli-Shanks.
+            const T_MINUS_ONE_DIV_TWO: [u64; 4] =
+                $crate::uint256::U256::from_be_hex($tm1d2).to_u64_limbs();
+
+            /// Creates a field element from an integer that must already be reduced.
+            pub const fn from_u256_unchecked(value: $crate::uint256::U256) -> Self {
+                Self(value)
+            }
+
+            /// Creates a field element from an integer, returning none if it is not reduced.
+            pub fn from_u256(value: $crate::uint256::U256) -> ::elliptic_curve::subtle::CtOption<Self> {
+                ::elliptic_curve::subtle::CtOption::new(Self(value), value.ct_lt(&Self::MODULUS))
+            }
+
+            /// Returns the canonical integer representing `self`.
+            pub const fn to_u256(&self) -> $crate::uint256::U256 {
+                self.0
+            }
+
+            /// Creates a field element from a `u64`.
+            pub const fn from_u64(value: u64) -> Self {
+                let mut words = [0u32; 8];
+                words[0] = value as u32;
+                words[1] = (value >> 32) as u32;
+                Self($crate::uint256::U256::from_words(words))
+            }
+
+            /// Returns true if the big endian `bytes` encode an integer smaller than the modulus,
+            /// in constant time.
+            ///
+            /// This is the check [`Self::from_be_bytes`] uses to reject non-canonical encodings.
+            pub fn is_canonical(bytes: &[u8; 32]) -> ::elliptic_curve::subtle::Choice {
+                $crate::uint256::U256::from_be_bytes(bytes).ct_lt(&Self::MODULUS)
+            }
+
+            /// Creates a field element from big endian bytes, returning none if they are not
+            /// reduced.
+            pub fn from_be_bytes(bytes: &[u8; 32]) -> ::elliptic_curve::subtle::CtOption<Self> {
+                Self::from_u256($crate::uint256::U256::from_be_bytes(bytes))
+            }
+
+            /// Returns the big endian bytes of `self`.
+            pub fn to_be_bytes(&self) -> [u8; 32] {
+                self.0.to_be_bytes()
+            }
+
+            fn select(a: &Self, b: &Self, choice: ::elliptic_curve::subtle::Choice) -> Self {
+                use ::elliptic_curve::subtle::ConditionallySelectable;
+
+                Self($crate::uint256::U256::conditional_select(&a.0, &b.0, choice))
+            }
+
+            fn add_inner(&self, rhs: &Self) -> Self {
+                let (sum, carry) = self.0.overflowing_add(&rhs.0);
+                let (reduced, borrow) = sum.overflowing_sub(&Self::MODULUS);
+
+                // The sum is reduced unless it overflowed or is at least the modulus.
+                let use_reduced = ::elliptic_curve::subtle::Choice::from((carry | !borrow) as u8);
+                Self::select(&Self(sum), &Self(reduced), use_reduced)
+            }
+
+            fn sub_inner(&self, rhs: &Self) -> Self {
+                let (diff, borrow) = self.0.overflowing_sub(&rhs.0);
+                let (wrapped, _) = diff.overflowing_add(&Self::MODULUS);
+
+                Self::select(
+                    &Self(diff),
+                    &Self(wrapped),
+                    ::elliptic_curve::subtle::Choice::from(borrow as u8),
+                )
+            }
+
+            fn mul_inner(&self, rhs: &Self) -> Self {
+                Self(self.0.mulmod(&rhs.0, &Self::MODULUS))
+            }
+        }
+
+        impl ::elliptic_curve::subtle::ConditionallySelectable for $name {
+            fn conditional_select(a: &Self, b: &Self, choice: ::elliptic_curve::subtle::Choice) -> Self {
+                Self::select(a, b, choice)
+            }
+        }
+
+        impl ::elliptic_curve::subtle::ConstantTimeEq for $name {
+            fn ct_eq(&self, other: &Self) -> ::elliptic_curve::subtle::Choice {
+                self.0.0[..].ct_eq(&other.0.0[..])
+            }
+        }
+
+        impl ::elliptic_curve::zeroize::DefaultIsZeroes for $name {}
+
+        impl From<u64> for $name {
+            fn from(value: u64) -> Self {
+                Self::from_u64(value)
+            }
+        }
+
+        impl ::core::ops::Neg for $name {
+            type Output = $name;
+
+            fn neg(self) -> $name {
+                Self::ZERO.sub_inner(&self)
+            }
+        }
+
+        impl ::core::ops::Neg for &$name {
+            type Output = $name;
+
+            fn neg(self) -> $name {
+                $name::ZERO.sub_inner(self)
+            }
+        }
+
+        $crate::field::impl_field_op!($name, Add, add, AddAssign, add_assign, add_inner);
+        $crate::field::impl_field_op!($name, Sub, sub, SubAssign, sub_assign, sub_inner);
+        $crate::field::impl_field_op!($name, Mul, mul, MulAssign, mul_assign, mul_inner);
+
+        impl ::core::iter::Sum for $name {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::ZERO, |acc, x| acc + x)
+            }
+        }
+
+        impl<'a> ::core::iter::Sum<&'a $name> for $name {
+            fn sum<I: Iterator<Item = &'a $name>>(iter: I) -> Self {
+                iter.fold(Self::ZERO, |acc, x| acc + x)
+            }
+        }
+
+        impl ::core::iter::Product for $name {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::ONE, |acc, x| acc * x)
+            }
+        }
+
+        impl<'a> ::core::iter::Product<&'a $name> for $name {
+            fn product<I: Iterator<Item = &'a $name>>(iter: I) -> Self {
+                iter.fold(Self::ONE, |acc, x| acc * x)
+            }
+        }
+
+        impl ::elliptic_curve::ff::Field for $name {
+            const ZERO: Self = Self::ZERO;
+            const ONE: Self = Self::ONE;
+
+            fn random(mut rng: impl ::elliptic_curve::rand_core::RngCore) -> Self {
+                // Rejection sampling yields a uniformly distributed element.
+                let mut bytes = [0u8; 32];
+                loop {
+                    rng.fill_bytes(&mut bytes);
+                    if let Some(element) = Option::from(Self::from_be_bytes(&bytes)) {
+                        return element;
+                    }
+                }
+            }
+
+            fn square(&self) -> Self {
+                self.mul_inner(self)
+            }
+
+            fn double(&self) -> Self {
+                self.add_inner(self)
+            }
+
+            fn invert(&self) -> ::elliptic_curve::subtle::CtOption<Self> {
+                ::elliptic_curve::subtle::CtOption::new(
+                    ::elliptic_curve::ff::Field::pow_vartime(self, Self::MODULUS_MINUS_TWO),
+                    !::elliptic_curve::ff::Field::is_zero(self),
+                )
+            }
+
+            fn sqrt(&self) -> ::elliptic_curve::subtle::CtOption<Self> {
+                ::elliptic_curve::ff::helpers::sqrt_tonelli_shanks(self, Self::T_MINUS_ONE_DIV_TWO)
+            }
+
+            fn sqrt_ratio(num: &Self, div: &Self) -> (::elliptic_curve::subtle::Choice, Self) {
+                ::elliptic_curve::ff::helpers::sqrt_ratio_generic(num, div)
+            }
+        }
+
+        impl ::elliptic_curve::ff::PrimeField for $name {
+            type Repr = ::elliptic_curve::FieldBytes<$curve>;
+
+            const MODULUS: &'static str = concat!("0x", $modulus);
+            const NUM_BITS: u32 = 256;
+            const CAPACITY: u32 = 255;
+            const TWO_INV: Self = Self($crate::uint256::U256::from_be_hex($two_inv));
+            const MULTIPLICATIVE_GENERATOR: Self = Self::from_u64($generator);
+            const S: u32 = $s;
+            const ROOT_OF_UNITY: Self = Self($crate::uint256::U256::from_be_hex($root));
+            const ROOT_OF_UNITY_INV: Self = Self($crate::uint256::U256::from_be_hex($root_inv));
+            const DELTA: Self = Self($crate::uint256::U256::from_be_hex($delta));
+
+            fn from_repr(repr: Self::Repr) -> ::elliptic_curve::subtle::CtOption<Self> {
+                let mut bytes = [0u8; 32];
+                bytes.copy_from_slice(&repr);
+                Self::from_be_bytes(&bytes)
+            }
+
+            fn to_repr(&self) -> Self::Repr {
+                self.to_be_bytes().into()
+            }
+
+            fn is_odd(&self) -> ::elliptic_curve::subtle::Choice {
+                ::elliptic_curve::subtle::Choice::from((self.0.0[0] & 1) as u8)
+            }
+        }
+    };
+}
+
+/// Implements a binary operator and its assigning variant, for every combination of owned and
+/// borrowed operands, on top of an inherent `fn(&Self, &Self) -> Self`.
+macro_rules! impl_field_op {
+    ($name:ident, $op:ident, $op_fn:ident, $assign:ident, $assign_fn:ident, $inner:ident) => {
+        impl ::core::ops::$op<$name> for $name {
+            type Output = $name;
+
+            fn $op_fn(self, rhs: $name) -> $name {
+                self.$inner(&rhs)
+            }
+        }
+
+        impl ::core::ops::$op<&$name> for $name {
+            type Output = $name;
+
+            fn $op_fn(self, rhs: &$name) -> $name {
+                self.$inner(rhs)
+            }
+        }
+
+        impl ::core::ops::$op<&$name> for &$name {
+            type Output = $name;
+
+            fn $op_fn(self, rhs: &$name) -> $name {
+                self.$inner(rhs)
+            }
+        }
+
+        impl ::core::ops::$assign<$name> for $name {
+            fn $assign_fn(&mut self, rhs: $name) {
+                *self = self.$inner(&rhs);
+            }
+        }
+
+        impl ::core::ops::$assign<&$name> for $name {
+            fn $assign_fn(&mut self, rhs: &$name) {
+                *self = self.$inner(rhs);
+            }
+        }
+    };
+}
+
+/// Implements the traits required of a [`elliptic_curve::CurveArithmetic::Scalar`] for a field
+/// type generated by [`impl_field`], whose modulus is the order of `$curve`.
+macro_rules! impl_scalar {
+    ($name:ident, $curve:ty) => {
+        impl $name {
+            /// `(n - 1) / 2`, the largest scalar that is not "high".
+            const FRAC_MODULUS_2: $crate::uint256::U256 = Self::MODULUS.shr(1);
+
+            fn from_uint(uint: &::elliptic_curve::bigint::U256) -> $crate::uint256::U256 {
+                $crate::uint256::U256::from_be_bytes(
+                    &::elliptic_curve::bigint::Encoding::to_be_bytes(uint),
+                )
+            }
+
+            fn to_uint(self) -> ::elliptic_curve::bigint::U256 {
+                ::elliptic_curve::bigint::Encoding::from_be_bytes(self.to_be_bytes())
+            }
+        }
+
+        impl AsRef<$name> for $name {
+            fn as_ref(&self) -> &$name {
+                self
+            }
+        }
+
+        impl From<::elliptic_curve::ScalarPrimitive<$curve>> for $name {
+            fn from(scalar: ::elliptic_curve::ScalarPrimitive<$curve>) -> Self {
+                Self::from_u256_unchecked(Self::from_uint(scalar.as_uint()))
+            }
+        }
+
+        impl From<$name> for ::elliptic_curve::ScalarPrimitive<$curve> {
+            fn from(scalar: $name) -> Self {
+                ::elliptic_curve::scalar::FromUintUnchecked::from_uint_unchecked(scalar.to_uint())
+            }
+        }
+
+        impl From<$name> for ::elliptic_curve::FieldBytes<$curve> {
+            fn from(scalar: $name) -> Self {
+                scalar.to_be_bytes().into()
+            }
+        }
+
+        impl From<$name> for ::elliptic_curve::bigint::U256 {
+            fn from(scalar: $name) -> Self {
+                scalar.to_uint()
+            }
+        }
+
+        impl ::elliptic_curve::scalar::FromUintUnchecked for $name {
+            type Uint = ::elliptic_curve::bigint::U256;
+
+            fn from_uint_unchecked(uint: Self::Uint) -> Self {
+                Self::from_u256_unchecked(Self::from_uint(&uint))
+            }
+        }
+
+        impl ::elliptic_curve::ops::Invert for $name {
+            type Output = ::elliptic_curve::subtle::CtOption<$name>;
+
+            fn invert(&self) -> Self::Output {
+                ::elliptic_curve::ff::Field::invert(self)
+            }
+        }
+
+        impl ::elliptic_curve::scalar::IsHigh for $name {
+            fn is_high(&self) -> ::elliptic_curve::subtle::Choice {
+                let (_, borrow) = Self::FRAC_MODULUS_2.overflowing_sub(&self.to_u256());
+                ::elliptic_curve::subtle::Choice::from(borrow as u8)
+            }
+        }
+
+        impl ::elliptic_curve::ops::Reduce<::elliptic_curve::bigint::U256> for $name {
+            type Bytes = ::elliptic_curve::FieldBytes<$curve>;
+
+            fn reduce(n: ::elliptic_curve::bigint::U256) -> Self {
+                // The modulus is larger than 2^255, so a single subtraction fully reduces.
+                let value = Self::from_uint(&n);
+                let (reduced, borrow) = value.overflowing_sub(&Self::MODULUS);
+                Self::select(
+                    &Self::from_u256_unchecked(reduced),
+                    &Self::from_u256_unchecked(value),
+                    ::elliptic_curve::subtle::Choice::from(borrow as u8),
+                )
+            }
+
+            fn reduce_bytes(bytes: &Self::Bytes) -> Self {
+                let mut be_bytes = [0u8; 32];
+                be_bytes.copy_from_slice(bytes);
+                Self::reduce(::elliptic_curve::bigint::Encoding::from_be_bytes(be_bytes))
+            }
+        }
+
+        impl ::core::ops::ShrAssign<usize> for $name {
+            fn shr_assign(&mut self, shift: usize) {
+                *self = Self::from_u256_unchecked(self.to_u256().shr(shift));
+            }
+        }
+
+        impl PartialOrd for $name {
+            fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $name {
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                self.to_u256().cmp(&other.to_u256())
+            }
+        }
+
+        // Scalar-on-the-left multiplication, which the orphan rule only allows for the concrete
+        // scalar types. Both orders go through the same accelerated `point * scalar`.
+
+        impl ::core::ops::Mul<$crate::ecdsa::CenoProjectivePoint<$curve>> for $name {
+            type Output = $crate::ecdsa::CenoProjectivePoint<$curve>;
+
+            fn mul(self, point: $crate::ecdsa::CenoProjectivePoint<$curve>) -> Self::Output {
+                point * self
+            }
+        }
+
+        impl ::core::ops::Mul<$crate::ecdsa::CenoProjectivePoint<$curve>> for &$name {
+            type Output = $crate::ecdsa::CenoProjectivePoint<$curve>;
+
+            fn mul(self, point: $crate::ecdsa::CenoProjectivePoint<$curve>) -> Self::Output {
+                point * self
+            }
+        }
+    };
+}
+
+pub(crate) use impl_field;
+pub(crate) use impl_field_op;
+pub(crate) use impl_scalar;
+
+#[cfg(test)]
+mod tests {
+    use crate::{ecdsa::Field, secp256k1, secp256r1};
+    use elliptic_curve::{
+        bigint::{Encoding, U256},
+        ff::PrimeField,
+        subtle::Choice,
+    };
+    use proptest::prelude::*;
+
+    fn modulus<F: PrimeField>() -> U256 {
+        U256::from_be_hex(&F::MODULUS[2..])
+    }
+
+    fn to_big<F: PrimeField>(element: &F) -> U256 {
+        U256::from_be_slice(element.to_repr().as_ref())
+    }
+
+    /// Returns the element `bytes mod p`, reduced with `crypto-bigint`.
+    fn from_bytes<F: PrimeField>(bytes: &[u8; 32]) -> F {
+        let reduced = U256::from_be_bytes(*bytes).wrapping_rem(&modulus::<F>());
+        let mut repr = F::Repr::default();
+        repr.as_mut().copy_from_slice(&reduced.to_be_bytes());
+        F::from_repr(repr).unwrap()
+    }
+
+    fn check_constants<F: PrimeField>() {
+        assert_eq!(F::TWO_INV.double(), F::ONE);
+        assert_eq!(F::ROOT_OF_UNITY * F::ROOT_OF_UNITY_INV, F::ONE);
+
+        let mut root = F::ROOT_OF_UNITY;
+        for _ in 0..F::S {
+            assert_ne!(root, F::ONE);
+            root = root.square();
+        }
+        assert_eq!(root, F::ONE);
+
+        let mut delta = F::MULTIPLICATIVE_GENERATOR;
+        for _ in 0..F::S {
+            delta = delta.square();
+        }
+        assert_eq!(delta, F::DELTA);
+
+        // The modulus itself is not a canonical encoding.
+        let mut repr = F::Repr::default();
+        repr.as_mut().copy_from_slice(&modulus::<F>().to_be_bytes());
+        assert!(bool::from(F::from_repr(repr).is_none()));
+        assert!(bool::from(F::ZERO.invert().is_none()));
+    }
+
+    /// Checks that `p - 1` is accepted while `p` and `p + 1` are rejected, by both
+    /// `is_canonical` and the decoder.
+    fn check_canonical<F: PrimeField>(
+        is_canonical: fn(&[u8; 32]) -> Choice,
+        decode: fn(&[u8; 32]) -> Option<F>,
+    ) {
+        let p = modulus::<F>();
+        let below = p.wrapping_sub(&U256::ONE).to_be_bytes();
+        assert!(bool::from(is_canonical(&below)));
+        assert_eq!(decode(&below), Some(-F::ONE));
+
+        for value in [p, p.wrapping_add(&U256::ONE), U256::MAX] {
+            let bytes = value.to_be_bytes();
+            assert!(!bool::from(is_canonical(&bytes)));
+            assert_eq!(decode(&bytes), None);
+        }
+    }
+
+    fn check_arithmetic<F: PrimeField>(a: &[u8; 32], b: &[u8; 32]) {
+        let (a, b) = (from_bytes::<F>(a), from_bytes::<F>(b));
+        let (x, y, p) = (to_big(&a), to_big(&b), modulus::<F>());
+
+        assert_eq!(to_big(&(a + b)), x.add_mod(&y, &p));
+        assert_eq!(to_big(&(a - b)), x.sub_mod(&y, &p));
+        assert_eq!(to_big(&-a), x.neg_mod(&p));
+        assert_eq!(to_big(&(a * b)), U256::const_rem_wide(x.mul_wide(&y), &p).0);
+
+        if !bool::from(a.is_zero()) {
+            assert_eq!(a * a.invert().unwrap(), F::ONE);
+        }
+        let root = a.square().sqrt().unwrap();
+        assert!(root == a || root == -a);
+    }
+
+    #[test]
+    fn secp256k1_constants() {
+        check_constants::<secp256k1::FieldElement>();
+        check_constants::<secp256k1::Scalar>();
+    }
+
+    #[test]
+    fn secp256r1_constants() {
+        check_constants::<secp256r1::FieldElement>();
+        check_constants::<secp256r1::Scalar>();
+    }
+
+    #[test]
+    fn secp256k1_rejects_non_canonical_encodings() {
+        check_canonical(secp256k1::FieldElement::is_canonical, |bytes| {
+            <secp256k1::FieldElement as Field<_>>::from_bytes(&(*bytes).into()).into_option()
+        });
+        check_canonical(secp256k1::Scalar::is_canonical, |bytes| {
+            secp256k1::Scalar::from_be_bytes(bytes).into_option()
+        });
+    }
+
+    #[test]
+    fn secp256r1_rejects_non_canonical_encodings() {
+        check_canonical(secp256r1::FieldElement::is_canonical, |bytes| {
+            <secp256r1::FieldElement as Field<_>>::from_bytes(&(*bytes).into()).into_option()
+        });
+        check_canonical(secp256r1::Scalar::is_canonical, |bytes| {
+            secp256r1::Scalar::from_be_bytes(bytes).into_option()
+        });
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        #[test]
+        fn secp256k1_arithmetic_matches_crypto_bigint(
+            a in any::<[u8; 32]>(),
+            b in any::<[u8; 32]>(),
+        ) {
+            check_arithmetic::<secp256k1::FieldElement>(&a, &b);
+            check_arithmetic::<secp256k1::Scalar>(&a, &b);
+        }
+
+        #[test]
+        fn secp256r1_arithmetic_matches_crypto_bigint(
+            a in any::<[u8; 32]>(),
+            b in any::<[u8; 32]>(),
+        ) {
+            check_arithmetic::<secp256r1::FieldElement>(&a, &b);
+            check_arithmetic::<secp256r1::Scalar>(&a, &b);
+        }
+    }
+}
@@ -1,4 +1,11 @@
+pub mod bls12381;
 pub mod bn254;
 pub mod ecdsa;
+pub mod error;
+pub use error::CryptoError;
+mod field;
 pub mod secp256k1;
+pub mod secp256r1;
+pub mod sha256;
+pub mod uint256;
 pub mod utils;
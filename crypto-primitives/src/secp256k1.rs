@@ -1,7 +1,145 @@
 //! Copied from <https://github.com/succinctlabs/sp1/blob/ebb517c1a3f3e3b95ee34bf211fb46a73cf108fe/crates/zkvm/lib/src/secp256k1.rs>
 
-use crate::utils::{AffinePoint, WeierstrassAffinePoint, WeierstrassPoint};
-use ceno_syscall::{syscall_secp256k1_add, syscall_secp256k1_double};
+use crate::{
+    CryptoError,
+    ecdsa::{CenoAffinePoint, CenoProjectivePoint, ECDSACurve, GeneratorTable, PrimeOrderCurve},
+    uint256,
+    utils::{AffinePoint, WeierstrassAffinePoint, WeierstrassPoint},
+};
+use ceno_syscall::{
+    Aligned4, syscall_secp256k1_add, syscall_secp256k1_decompress, syscall_secp256k1_double,
+};
+use elliptic_curve::{
+    Curve, CurveArithmetic, FieldBytesEncoding, PrimeCurve,
+    bigint::U256,
+    ff::{Field as _, PrimeField as _},
+    generic_array::typenum::consts::U32,
+};
+use std::sync::OnceLock;
+
+/// The base field of the secp256k1 curve.
+pub mod field;
+pub use field::FieldElement;
+
+/// The scalar field of the secp256k1 curve.
+pub mod scalar;
+pub use scalar::Scalar;
+
+/// BIP-340 Schnorr signatures.
+pub mod schnorr;
+
+/// Ethereum address derivation and signature recovery.
+pub mod ethereum;
+pub use ethereum::{eth_address, eth_ecrecover};
+
+/// Hashing to the curve, following RFC 9380.
+pub mod hash_to_curve;
+pub use hash_to_curve::hash_to_curve;
+
+/// Scalar multiplication with the GLV endomorphism.
+#[cfg(feature = "glv")]
+mod glv;
+
+/// Conversions from and to the `k256` point types.
+#[cfg(feature = "k256-interop")]
+mod k256_interop;
+
+/// The secp256k1 curve, `y^2 = x^3 + 7`, accelerated with the secp256k1 precompiles.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Secp256k1;
+
+impl Curve for Secp256k1 {
+    type FieldBytesSize = U32;
+    type Uint = U256;
+
+    const ORDER: U256 =
+        U256::from_be_hex("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141");
+}
+
+impl PrimeCurve for Secp256k1 {}
+
+impl FieldBytesEncoding<Secp256k1> for U256 {}
+
+impl CurveArithmetic for Secp256k1 {
+    type AffinePoint = CenoAffinePoint<Self>;
+    type ProjectivePoint = CenoProjectivePoint<Self>;
+    type Scalar = Scalar;
+}
+
+impl ECDSACurve for Secp256k1 {
+    type FieldElement = FieldElement;
+    type SP1AffinePoint = CenoSecp256k1Point;
+
+    const EQUATION_A: FieldElement = FieldElement::ZERO;
+    const EQUATION_B: FieldElement = FieldElement::from_u64(7);
+    const ORDER: uint256::U256 = Scalar::MODULUS;
+    const MODULUS: uint256::U256 = FieldElement::MODULUS;
+
+    fn decompress(point: &mut [u8; 64], is_odd: bool) {
+        syscall_secp256k1_decompress(point, is_odd);
+    }
+
+    fn generator_table() -> &'static GeneratorTable<Self> {
+        static TABLE: OnceLock<GeneratorTable<Secp256k1>> = OnceLock::new();
+        TABLE.get_or_init(GeneratorTable::generator)
+    }
+}
+
+impl PrimeOrderCurve for Secp256k1 {}
+
+/// Decompresses the point with the big endian X coordinate `x_be` and a Y coordinate of parity
+/// `is_odd`.
+///
+/// Unlike [`syscall_secp256k1_decompress`], which writes an unspecified Y when `x_be` has no
+/// point on the curve, the reconstructed point is checked before it is returned.
+///
+/// Fails with [`CryptoError::InvalidEncoding`] if `x_be` is not a reduced field element, with
+/// [`CryptoError::NotOnCurve`] if no point with the given X coordinate exists, and with
+/// [`CryptoError::WrongParity`] if the returned Y does not have the requested parity.
+pub fn try_decompress(x_be: &[u8; 32], is_odd: bool) -> Result<CenoSecp256k1Point, CryptoError> {
+    let x = Option::<FieldElement>::from(FieldElement::from_be_bytes(x_be))
+        .ok_or(CryptoError::InvalidEncoding)?;
+
+    let mut data = Aligned4::<16>::zeroed();
+    data.as_bytes_mut()[..32].copy_from_slice(x_be);
+    syscall_secp256k1_decompress(data.as_byte_array_mut(), is_odd);
+
+    let y = Option::<FieldElement>::from(FieldElement::from_be_bytes(
+        data.as_bytes()[32..].try_into().unwrap(),
+    ))
+    .ok_or(CryptoError::NotOnCurve)?;
+    if y.square() != x.square() * x + Secp256k1::EQUATION_B {
+        return Err(CryptoError::NotOnCurve);
+    }
+    if bool::from(y.is_odd()) != is_odd {
+        return Err(CryptoError::WrongParity);
+    }
+
+    let mut limbs = [0u32; N];
+    limbs[..N / 2].copy_from_slice(&x.to_u256().to_words());
+    limbs[N / 2..].copy_from_slice(&y.to_u256().to_words());
+    Ok(CenoSecp256k1Point::new(limbs))
+}
+
+/// Returns the big endian X coordinate of a compressed SEC1 point, without decompressing it.
+///
+/// This is for protocols that only use X, such as ECDH shared secrets and x-only keys. Only the
+/// encoding is validated: the tag must be `0x02` or `0x03` and X must be a reduced field element.
+/// Unlike [`try_decompress`], this does not check that X has a point on the curve, which holds for
+/// about half of all field elements. Callers that need this guarantee must decompress.
+///
+/// Fails with [`CryptoError::InvalidEncoding`] for any other tag or an unreduced X.
+pub fn x_coordinate_from_compressed(bytes: &[u8; 33]) -> Result<[u8; 32], CryptoError> {
+    if bytes[0] != 0x02 && bytes[0] != 0x03 {
+        return Err(CryptoError::InvalidEncoding);
+    }
+
+    let x: &[u8; 32] = bytes[1..].try_into().unwrap();
+    if !bool::from(FieldElement::is_canonical(x)) {
+        return Err(CryptoError::InvalidEncoding);
+    }
+    Ok(*x)
+}
 
 /// The number of limbs in [CenoSecp256k1Point].
 pub const N: usize = 16;
@@ -11,7 +149,52 @@ pub const N: usize = 16;
 #[repr(align(4))]
 pub struct CenoSecp256k1Point(pub WeierstrassPoint<N>);
 
+impl CenoSecp256k1Point {
+    /// Creates a point from the untagged `X || Y` form, with each coordinate big endian, as written
+    /// by [`syscall_secp256k1_decompress`].
+    ///
+    /// Returns none if a coordinate is not a reduced field element or the point is not on the
+    /// curve. The untagged form has no encoding of the identity, so all zeros return none as well.
+    pub fn from_untagged_bytes(bytes: &[u8; 64]) -> Option<Self> {
+        let x = FieldElement::from_be_bytes(bytes[..32].try_into().unwrap());
+        let y = FieldElement::from_be_bytes(bytes[32..].try_into().unwrap());
+        let (x, y) = Option::<FieldElement>::from(x).zip(Option::<FieldElement>::from(y))?;
+        if y.square() != x.square() * x + Secp256k1::EQUATION_B {
+            return None;
+        }
+        Some(Self::from_be_bytes(bytes))
+    }
+
+    /// Returns the untagged `X || Y` form of the point, with each coordinate big endian.
+    ///
+    /// This is the same as [`CenoSecp256k1Point::to_be_bytes`], so the identity is encoded as all
+    /// zeros, which [`CenoSecp256k1Point::from_untagged_bytes`] rejects.
+    pub fn to_untagged_bytes(&self) -> [u8; 64] {
+        self.to_be_bytes()
+    }
+}
+
+// A swapped coordinate or limb order in `GENERATOR` fails to compile.
+const _: () = assert!(CenoSecp256k1Point::GENERATOR.0.has_be_hex_coordinates(
+    "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+    "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+));
+
+impl AsRef<WeierstrassPoint<N>> for CenoSecp256k1Point {
+    fn as_ref(&self) -> &WeierstrassPoint<N> {
+        &self.0
+    }
+}
+
+impl AsMut<WeierstrassPoint<N>> for CenoSecp256k1Point {
+    fn as_mut(&mut self) -> &mut WeierstrassPoint<N> {
+        &mut self.0
+    }
+}
+
 impl WeierstrassAffinePoint<N> for CenoSecp256k1Point {
+    const MODULUS: &'static [u32] = &FieldElement::MODULUS.0;
+
     fn infinity() -> Self {
         Self(WeierstrassPoint::Infinity)
     }
@@ -66,3 +249,174 @@ impl AffinePoint<N> for CenoSecp256k1Point {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ceno_syscall::{DefaultHandler, SyscallHandler, set_syscall_handler, take_syscall_handler};
+    use k256::elliptic_curve::point::DecompressPoint;
+    use std::rc::Rc;
+
+    /// The big endian X coordinate of the generator.
+    fn generator_x() -> [u8; 32] {
+        CenoSecp256k1Point::GENERATOR.to_be_bytes()[..32]
+            .try_into()
+            .unwrap()
+    }
+
+    /// Decompresses with the opposite parity, as a faulty precompile would.
+    struct FlipParity;
+
+    impl SyscallHandler for FlipParity {
+        fn secp256k1_decompress(&self, point: &mut [u8; 64], is_odd: bool) {
+            DefaultHandler.secp256k1_decompress(point, !is_odd);
+        }
+    }
+
+    #[test]
+    fn generator_is_on_the_curve() {
+        let generator = CenoAffinePoint::<Secp256k1>::generator();
+        let (x, y) = generator.field_elements();
+        assert_eq!(y.square(), x.square() * x + FieldElement::from_u64(7));
+        assert_eq!(Secp256k1::EQUATION_A, FieldElement::ZERO);
+        assert_eq!(Secp256k1::EQUATION_B, FieldElement::from_u64(7));
+    }
+
+    #[test]
+    fn generator_matches_canonical_coordinates() {
+        let x = uint256::U256::from_be_hex(
+            "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+        );
+        let y = uint256::U256::from_be_hex(
+            "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+        );
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&x.to_be_bytes());
+        bytes[32..].copy_from_slice(&y.to_be_bytes());
+
+        let generator = CenoSecp256k1Point::from_be_bytes(&bytes);
+        assert_eq!(generator.limbs(), CenoSecp256k1Point::GENERATOR.limbs());
+
+        let x = FieldElement::from_be_bytes(bytes[..32].try_into().unwrap()).unwrap();
+        let y = FieldElement::from_be_bytes(bytes[32..].try_into().unwrap()).unwrap();
+        let point = CenoAffinePoint::<Secp256k1>::from_field_elements(x, y).unwrap();
+        assert_eq!(point, CenoAffinePoint::generator());
+    }
+
+    #[test]
+    fn generator_has_the_curve_order() {
+        let mut point = CenoSecp256k1Point::GENERATOR;
+        point.mul_assign(&Scalar::MODULUS.to_words());
+        assert!(point.is_identity());
+    }
+
+    #[test]
+    fn untagged_bytes_round_trip() {
+        let point = CenoSecp256k1Point::GENERATOR.scalar_mul(&[true, false, true]);
+        let bytes = point.to_untagged_bytes();
+        assert_eq!(bytes, point.to_be_bytes());
+        let decoded = CenoSecp256k1Point::from_untagged_bytes(&bytes).unwrap();
+        assert_eq!(decoded.to_untagged_bytes(), bytes);
+    }
+
+    #[test]
+    fn untagged_bytes_off_the_curve_are_rejected() {
+        let mut bytes = CenoSecp256k1Point::GENERATOR.to_untagged_bytes();
+        bytes[63] ^= 1;
+        assert!(CenoSecp256k1Point::from_untagged_bytes(&bytes).is_none());
+
+        // The identity has no untagged encoding, nor do unreduced coordinates.
+        assert!(CenoSecp256k1Point::from_untagged_bytes(&[0; 64]).is_none());
+        let mut unreduced = CenoSecp256k1Point::GENERATOR.to_untagged_bytes();
+        unreduced[..32].copy_from_slice(&FieldElement::MODULUS.to_be_bytes());
+        assert!(CenoSecp256k1Point::from_untagged_bytes(&unreduced).is_none());
+    }
+
+    #[test]
+    fn try_decompress_generator() {
+        let point = try_decompress(&generator_x(), false).unwrap();
+        assert_eq!(
+            point.to_be_bytes(),
+            CenoSecp256k1Point::GENERATOR.to_be_bytes()
+        );
+    }
+
+    #[test]
+    fn try_decompress_rejects_unreduced_x() {
+        assert_eq!(
+            try_decompress(&[0xff; 32], false).unwrap_err(),
+            CryptoError::InvalidEncoding
+        );
+    }
+
+    #[test]
+    fn try_decompress_rejects_x_off_the_curve() {
+        let x = (1u8..)
+            .map(|x| {
+                let mut bytes = [0u8; 32];
+                bytes[31] = x;
+                bytes
+            })
+            .find(|x| bool::from(k256::AffinePoint::decompress(x.into(), 0.into()).is_none()))
+            .unwrap();
+        assert_eq!(
+            try_decompress(&x, false).unwrap_err(),
+            CryptoError::NotOnCurve
+        );
+    }
+
+    #[test]
+    fn x_coordinate_from_compressed_matches_decompression() {
+        use elliptic_curve::{
+            group::{Group, GroupEncoding},
+            point::AffineCoordinates,
+        };
+
+        for k in [1, 2, 3, 1000, u64::MAX] {
+            let point =
+                (CenoProjectivePoint::<Secp256k1>::generator() * Scalar::from_u64(k)).to_affine();
+            let bytes: [u8; 33] = point.to_bytes()[..].try_into().unwrap();
+            let x = x_coordinate_from_compressed(&bytes).unwrap();
+
+            let decompressed = try_decompress(&x, bytes[0] == 0x03).unwrap();
+            assert_eq!(x[..], decompressed.to_be_bytes()[..32]);
+            assert_eq!(x[..], point.x()[..]);
+        }
+    }
+
+    #[test]
+    fn x_coordinate_from_compressed_checks_only_the_encoding() {
+        let mut bytes = [0u8; 33];
+        bytes[0] = 0x02;
+        bytes[1..].copy_from_slice(&generator_x());
+        for tag in [0x00, 0x01, 0x04, 0x06, 0xff] {
+            bytes[0] = tag;
+            assert_eq!(
+                x_coordinate_from_compressed(&bytes).unwrap_err(),
+                CryptoError::InvalidEncoding
+            );
+        }
+
+        bytes[0] = 0x03;
+        bytes[1..].copy_from_slice(&FieldElement::MODULUS.to_be_bytes());
+        assert_eq!(
+            x_coordinate_from_compressed(&bytes).unwrap_err(),
+            CryptoError::InvalidEncoding
+        );
+
+        // X = 5 has no point on the curve, which is not checked.
+        let mut five = [0u8; 32];
+        five[31] = 5;
+        bytes[1..].copy_from_slice(&five);
+        assert_eq!(x_coordinate_from_compressed(&bytes).unwrap(), five);
+        assert!(try_decompress(&five, true).is_err());
+    }
+
+    #[test]
+    fn try_decompress_rejects_wrong_parity() {
+        set_syscall_handler(Rc::new(FlipParity));
+        let result = try_decompress(&generator_x(), false);
+        take_syscall_handler();
+        assert_eq!(result.unwrap_err(), CryptoError::WrongParity);
+    }
+}
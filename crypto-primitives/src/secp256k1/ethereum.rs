@@ -0,0 +1,136 @@
+//! Ethereum specific helpers over secp256k1.
+
+use super::{CenoSecp256k1Point, Scalar, Secp256k1};
+use crate::{
+    ecdsa::{RecoveryId, Signature, VerifyingKey},
+    utils::WeierstrassAffinePoint,
+};
+use ceno_syscall::keccak::keccak256;
+use elliptic_curve::{Error, scalar::IsHigh};
+
+/// Derives the Ethereum address of a public key, the last 20 bytes of the Keccak-256 digest of its
+/// uncompressed encoding without the `0x04` prefix.
+///
+/// Returns an error for the identity, which has no encoding.
+pub fn eth_address(pubkey: &CenoSecp256k1Point) -> Result<[u8; 20], Error> {
+    if pubkey.is_infinity() {
+        return Err(Error);
+    }
+
+    let encoded = pubkey.to_be_bytes();
+    let digest = keccak256(&encoded);
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&digest[12..]);
+    Ok(address)
+}
+
+/// Recovers the Ethereum address that signed `msg_hash`, following the semantics of the `ecrecover`
+/// precompile at address `0x01`.
+///
+/// `v` may be given either as `27`/`28` or as the raw recovery id `0`/`1`. Unlike the precompile,
+/// signatures with a high `s` are rejected, as they are for Ethereum transactions since EIP-2.
+pub fn eth_ecrecover(
+    msg_hash: &[u8; 32],
+    v: u8,
+    r: &[u8; 32],
+    s: &[u8; 32],
+) -> Result<[u8; 20], Error> {
+    let recovery_id = match v {
+        0 | 1 => RecoveryId::new(v == 1, false),
+        27 | 28 => RecoveryId::new(v == 28, false),
+        _ => return Err(Error),
+    };
+
+    let r = Option::<Scalar>::from(Scalar::from_be_bytes(r)).ok_or(Error)?;
+    let s = Option::<Scalar>::from(Scalar::from_be_bytes(s)).ok_or(Error)?;
+    if s.is_high().into() {
+        return Err(Error);
+    }
+
+    let sig = Signature::<Secp256k1>::from_scalars(r, s)?;
+    let key = VerifyingKey::recover_from_prehash(msg_hash, &sig, recovery_id)?;
+    eth_address(&key.as_affine().inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecdsa::CenoProjectivePoint;
+    use elliptic_curve::ops::MulByGenerator;
+    use hex_literal::hex;
+
+    /// Returns the public key of the secret key `seckey`.
+    fn public_key(seckey: &[u8; 32]) -> CenoSecp256k1Point {
+        let d = Scalar::from_be_bytes(seckey).unwrap();
+        CenoProjectivePoint::<Secp256k1>::mul_by_generator(&d)
+            .to_affine()
+            .inner
+    }
+
+    #[test]
+    fn hardhat_default_accounts() {
+        let accounts = [
+            (
+                hex!("ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80"),
+                hex!("f39fd6e51aad88f6f4ce6ab8827279cfffb92266"),
+            ),
+            (
+                hex!("59c6995e998f97a5a0044966f0945389dc9e86dae88c7a8412f4603b6b78690d"),
+                hex!("70997970c51812dc3a010c7d01b50e0d17dc79c8"),
+            ),
+        ];
+        for (seckey, address) in accounts {
+            assert_eq!(eth_address(&public_key(&seckey)).unwrap(), address);
+        }
+    }
+
+    #[test]
+    fn identity_has_no_address() {
+        assert!(eth_address(&CenoSecp256k1Point::infinity()).is_err());
+    }
+
+    /// The signing hash, `r`, `s` and sender of the example transaction of EIP-155, signed with
+    /// chain id 1 and `v = 37`, so with the recovery id 0.
+    const EIP155_HASH: [u8; 32] =
+        hex!("daf5a779ae972f972197303d7b574746c7ef83eadac0f2791ad23db92e4c8e53");
+    const EIP155_R: [u8; 32] =
+        hex!("28ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276");
+    const EIP155_S: [u8; 32] =
+        hex!("67cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83");
+    const EIP155_SENDER: [u8; 20] = hex!("9d8a62f656a8d1615c1294fd71e9cfb3e4855a4f");
+
+    #[test]
+    fn ecrecover_eip155_example() {
+        for v in [0, 27] {
+            assert_eq!(
+                eth_ecrecover(&EIP155_HASH, v, &EIP155_R, &EIP155_S).unwrap(),
+                EIP155_SENDER
+            );
+        }
+        // The other parity recovers a different key.
+        assert_ne!(
+            eth_ecrecover(&EIP155_HASH, 28, &EIP155_R, &EIP155_S).ok(),
+            Some(EIP155_SENDER)
+        );
+        assert_eq!(
+            eth_address(&public_key(&[0x46; 32])).unwrap(),
+            EIP155_SENDER
+        );
+    }
+
+    #[test]
+    fn ecrecover_rejects_invalid_inputs() {
+        for v in [2, 26, 29, 37] {
+            assert!(eth_ecrecover(&EIP155_HASH, v, &EIP155_R, &EIP155_S).is_err());
+        }
+
+        // The high `s` form of the same signature.
+        let s = -Scalar::from_be_bytes(&EIP155_S).unwrap();
+        assert!(eth_ecrecover(&EIP155_HASH, 28, &EIP155_R, &s.to_be_bytes()).is_err());
+
+        let order = Scalar::MODULUS.to_be_bytes();
+        assert!(eth_ecrecover(&EIP155_HASH, 27, &[0; 32], &EIP155_S).is_err());
+        assert!(eth_ecrecover(&EIP155_HASH, 27, &order, &EIP155_S).is_err());
+        assert!(eth_ecrecover(&EIP155_HASH, 27, &EIP155_R, &[0; 32]).is_err());
+    }
+}
@@ -0,0 +1,34 @@
+//! The base field of the secp256k1 curve.
+
+use super::Secp256k1;
+use crate::{ecdsa::Field, field::impl_field};
+use elliptic_curve::{FieldBytes, subtle::CtOption};
+
+impl_field!(
+    /// An element of the secp256k1 base field, `p = 2^256 - 2^32 - 977`.
+    FieldElement,
+    curve = Secp256k1,
+    modulus = "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+    two_inv = "7fffffffffffffffffffffffffffffffffffffffffffffffffffffff7ffffe18",
+    multiplicative_generator = 3,
+    s = 1,
+    root_of_unity = "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2e",
+    root_of_unity_inv = "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2e",
+    delta = "0000000000000000000000000000000000000000000000000000000000000009",
+    t_minus_one_div_two = "3fffffffffffffffffffffffffffffffffffffffffffffffffffffffbfffff0b",
+);
+
+impl Field<Secp256k1> for FieldElement {
+    fn from_bytes(bytes: &FieldBytes<Secp256k1>) -> CtOption<Self> {
+        <Self as elliptic_curve::PrimeField>::from_repr(*bytes)
+    }
+
+    fn to_bytes(self) -> FieldBytes<Secp256k1> {
+        <Self as elliptic_curve::PrimeField>::to_repr(&self)
+    }
+
+    fn normalize(self) -> Self {
+        // Elements are always kept fully reduced.
+        self
+    }
+}
@@ -0,0 +1,187 @@
+//! Scalar multiplication with the GLV endomorphism of secp256k1.
+//!
+//! The map `φ(x, y) = (β · x, y)`, where `β` is a cube root of unity in the base field, is the
+//! multiplication by a cube root of unity `λ` in the scalar field. A scalar `k` is split into
+//! `k1 + k2 · λ` with both halves below `2^128` in absolute value, so `k · P = k1 · P + k2 · φ(P)`
+//! only needs 128 shared doublings instead of 256. The decomposition follows libsecp256k1.
+
+use super::{FieldElement, Scalar, Secp256k1};
+use crate::{
+    ecdsa::{CenoAffinePoint, CenoProjectivePoint},
+    uint256::U256,
+};
+use elliptic_curve::{scalar::IsHigh, subtle::ConditionallySelectable};
+
+/// The cube root of unity `β` in the base field, with `φ(P) = λ · P`.
+const BETA: FieldElement = FieldElement::from_u256_unchecked(U256::from_be_hex(
+    "7ae96a2b657c07106e64479eac3434e99cf0497512f58995c1396c28719501ee",
+));
+
+/// The negation of the cube root of unity `λ` in the scalar field.
+const MINUS_LAMBDA: Scalar = Scalar::from_u256_unchecked(U256::from_be_hex(
+    "ac9c52b33fa3cf1f5ad9e3fd77ed9ba4a880b9fc8ec739c2e0cfc810b51283cf",
+));
+
+/// The negation of the `b1` component of the reduced lattice basis.
+const MINUS_B1: Scalar = Scalar::from_u256_unchecked(U256::from_be_hex(
+    "00000000000000000000000000000000e4437ed6010e88286f547fa90abfe4c3",
+));
+
+/// The negation of the `b2` component of the reduced lattice basis.
+const MINUS_B2: Scalar = Scalar::from_u256_unchecked(U256::from_be_hex(
+    "fffffffffffffffffffffffffffffffe8a280ac50774346dd765cda83db1562c",
+));
+
+/// `round(2^384 · b2 / n)`.
+const G1: U256 =
+    U256::from_be_hex("3086d221a7d46bcde86c90e49284eb153daa8a1471e8ca7fe893209a45dbb031");
+
+/// `round(2^384 · -b1 / n)`.
+const G2: U256 =
+    U256::from_be_hex("e4437ed6010e88286f547fa90abfe4c4221208ac9df506c61571b4ae8ac47f71");
+
+/// Returns `round(a · b / 2^384)`, which is below `2^129` for the constants above.
+fn mul_shift_384(a: &U256, b: &U256) -> U256 {
+    let (a, b) = (a.to_words(), b.to_words());
+
+    let mut product = [0u32; 16];
+    for (i, &a_word) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &b_word) in b.iter().enumerate() {
+            let t = product[i + j] as u64 + a_word as u64 * b_word as u64 + carry;
+            product[i + j] = t as u32;
+            carry = t >> 32;
+        }
+        product[i + 8] = carry as u32;
+    }
+
+    // Bit 383 rounds the result, and cannot carry past bit 511.
+    let mut words = [0u32; 8];
+    words[..4].copy_from_slice(&product[12..]);
+    let round = U256::from_u64((product[11] >> 31) as u64);
+    U256::from_words(words).overflowing_add(&round).0
+}
+
+/// Splits `k` into `(k1, k2)` with `k = k1 + k2 · λ`, where both halves or their negations are
+/// below `2^128`.
+fn decompose_scalar(k: &Scalar) -> (Scalar, Scalar) {
+    let c1 = Scalar::from_u256_unchecked(mul_shift_384(&k.to_u256(), &G1)) * MINUS_B1;
+    let c2 = Scalar::from_u256_unchecked(mul_shift_384(&k.to_u256(), &G2)) * MINUS_B2;
+    let k2 = c1 + c2;
+    let k1 = *k + k2 * MINUS_LAMBDA;
+    (k1, k2)
+}
+
+impl CenoProjectivePoint<Secp256k1> {
+    /// Computes `k · self` with the GLV endomorphism.
+    ///
+    /// `k` is split into `k1 + k2 · λ`, each half is made positive by negating it together with
+    /// its point, and `k1 · self + k2 · φ(self)` is computed with
+    /// [`CenoProjectivePoint::double_scalar_mul`]. The halves have at most 128 bits, so this takes
+    /// about half the doublings of the plain [`Mul`](core::ops::Mul).
+    pub fn mul_glv(&self, k: &Scalar) -> Self {
+        let Some((x, y)) = self.coordinates() else {
+            return *self;
+        };
+        // `φ` maps curve points to curve points.
+        let endo = Self::from(CenoAffinePoint::from_field_elements_unchecked(x * BETA, y));
+
+        let (k1, k2) = decompose_scalar(k);
+        let (k1_is_high, k2_is_high) = (k1.is_high(), k2.is_high());
+        let k1 = Scalar::conditional_select(&k1, &-k1, k1_is_high);
+        let k2 = Scalar::conditional_select(&k2, &-k2, k2_is_high);
+
+        Self::double_scalar_mul(
+            &k1,
+            &self.conditional_negate(k1_is_high),
+            &k2,
+            &endo.conditional_negate(k2_is_high),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use elliptic_curve::{bigint::U256 as BigUint, group::Group, ops::Reduce};
+    use proptest::prelude::*;
+
+    type Point = CenoProjectivePoint<Secp256k1>;
+
+    /// Checks that `k = k1 + k2 · λ` and that both halves or their negations are below `2^128`.
+    fn check_decomposition(k: &Scalar) {
+        let (k1, k2) = decompose_scalar(k);
+        assert_eq!(k1 - k2 * MINUS_LAMBDA, *k);
+        for half in [k1, k2] {
+            let half = Scalar::conditional_select(&half, &-half, half.is_high());
+            let half = half.to_u256();
+            assert!(
+                (128..256).all(|i| !half.bit(i)),
+                "{k:?} splits into a wide half"
+            );
+        }
+    }
+
+    #[test]
+    fn endomorphism_constants() {
+        let lambda = -MINUS_LAMBDA;
+        assert_eq!(lambda * lambda * lambda, Scalar::ONE);
+        assert_ne!(lambda, Scalar::ONE);
+        assert_eq!(BETA * BETA * BETA, FieldElement::ONE);
+        assert_ne!(BETA, FieldElement::ONE);
+
+        // `φ(G) = (β · x, y)` is `λ · G`.
+        let (x, y) = Point::generator().coordinates().unwrap();
+        let endo = Point::from(CenoAffinePoint::from_field_elements(x * BETA, y).unwrap());
+        assert_eq!(endo, Point::generator() * lambda);
+    }
+
+    #[test]
+    fn decomposition_of_edge_scalars() {
+        let mut two_128 = [0u8; 32];
+        two_128[15] = 1;
+        let two_128 = <Scalar as Reduce<BigUint>>::reduce_bytes(&two_128.into());
+        for k in [
+            Scalar::ZERO,
+            Scalar::ONE,
+            -Scalar::ONE,
+            -MINUS_LAMBDA,
+            MINUS_LAMBDA,
+            two_128,
+            -two_128,
+            Scalar::from_u64(u64::MAX),
+        ] {
+            check_decomposition(&k);
+        }
+    }
+
+    #[test]
+    fn mul_glv_of_edge_cases() {
+        let g = Point::generator();
+        for k in [
+            Scalar::ZERO,
+            Scalar::ONE,
+            -Scalar::ONE,
+            -MINUS_LAMBDA,
+            MINUS_LAMBDA,
+        ] {
+            assert_eq!(g.mul_glv(&k), g * k);
+        }
+        assert_eq!(
+            Point::identity().mul_glv(&Scalar::from_u64(5)),
+            Point::identity()
+        );
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn mul_glv_matches_mul(p in any::<[u8; 32]>(), k in any::<[u8; 32]>()) {
+            let p = Point::generator() * <Scalar as Reduce<BigUint>>::reduce_bytes(&p.into());
+            let k = <Scalar as Reduce<BigUint>>::reduce_bytes(&k.into());
+            check_decomposition(&k);
+            prop_assert_eq!(p.mul_glv(&k), p * k);
+        }
+    }
+}
@@ -0,0 +1,283 @@
+//! Hashing to secp256k1 following RFC 9380, as the `secp256k1_XMD:SHA-256_SSWU_RO_` suite.
+//!
+//! Since `A = 0`, the simplified SWU map is applied on an isogenous curve `E'` and the result is
+//! carried over to secp256k1 by a 3-isogeny, see sections 6.6.3 and 8.7 of the RFC. All field
+//! arithmetic runs through [`FieldElement`], whose multiplication uses the uint256 precompile.
+
+use super::{CenoSecp256k1Point, FieldElement, N};
+use crate::{
+    sha256::Sha256,
+    uint256::U256,
+    utils::{AffinePoint, WeierstrassAffinePoint},
+};
+use elliptic_curve::{
+    ff::{Field as _, PrimeField as _},
+    subtle::{ConditionallySelectable, ConstantTimeEq},
+};
+
+/// The size of the SHA-256 output in bytes, `b_in_bytes` in the RFC.
+const B_IN_BYTES: usize = 32;
+
+/// The size of the SHA-256 input block in bytes, `r_in_bytes` in the RFC.
+const R_IN_BYTES: usize = 64;
+
+/// The number of bytes hashed per field element, `L = ceil((256 + 128) / 8)`.
+const L: usize = 48;
+
+/// `2^256 mod p`, the weight of the upper bytes of a hashed field element.
+const TWO_POW_256: FieldElement = FieldElement::from_u64(0x1000003d1);
+
+/// The coefficient `A'` of the isogenous curve `E'`.
+const ISO_A: FieldElement = fe("3f8731abdd661adca08a5558f0f5d272e953d363cb6f0e5d405447c01a444533");
+
+/// The coefficient `B'` of the isogenous curve `E'`.
+const ISO_B: FieldElement = FieldElement::from_u64(1771);
+
+/// The constant `Z` of the simplified SWU map, `-11`.
+const Z: FieldElement = fe("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc24");
+
+/// `-B' / A'`, the numerator of the SWU map's X coordinate.
+const MINUS_B_OVER_A: FieldElement =
+    fe("0bc56cee718538b2a00c4df5d3e87b0c6df4ff98e82d74fdaa01d58e8d2345c3");
+
+/// `B' / (Z · A')`, the SWU map's X coordinate in the exceptional case.
+const B_OVER_ZA: FieldElement =
+    fe("bb407e4438dd90ca6ba40716591522757e5c173c7232ad8b6c8bcd97de490391");
+
+/// The coefficients of the numerator of the isogeny's X coordinate, lowest degree first.
+const X_NUM: [FieldElement; 4] = [
+    fe("8e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38daaaaa8c7"),
+    fe("07d3d4c80bc321d5b9f315cea7fd44c5d595d2fc0bf63b92dfff1044f17c6581"),
+    fe("534c328d23f234e6e2a413deca25caece4506144037c40314ecbd0b53d9dd262"),
+    fe("8e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38daaaaa88c"),
+];
+
+/// The coefficients of the denominator of the isogeny's X coordinate, lowest degree first and
+/// without the leading `1`.
+const X_DEN: [FieldElement; 2] = [
+    fe("d35771193d94918a9ca34ccbb7b640dd86cd409542f8487d9fe6b745781eb49b"),
+    fe("edadc6f64383dc1df7c4b2d51b54225406d36b641f5e41bbc52a56612a8c6d14"),
+];
+
+/// The coefficients of the numerator of the isogeny's Y coordinate, lowest degree first.
+const Y_NUM: [FieldElement; 4] = [
+    fe("4bda12f684bda12f684bda12f684bda12f684bda12f684bda12f684b8e38e23c"),
+    fe("c75e0c32d5cb7c0fa9d0a54b12a0a6d5647ab046d686da6fdffc90fc201d71a3"),
+    fe("29a6194691f91a73715209ef6512e576722830a201be2018a765e85a9ecee931"),
+    fe("2f684bda12f684bda12f684bda12f684bda12f684bda12f684bda12f38e38d84"),
+];
+
+/// The coefficients of the denominator of the isogeny's Y coordinate, lowest degree first and
+/// without the leading `1`.
+const Y_DEN: [FieldElement; 3] = [
+    fe("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffff93b"),
+    fe("7a06534bb8bdb49fd5e9e6632722c2989467c1bfc8e8d978dfb425d2685c2573"),
+    fe("6484aa716545ca2cf3a70c3fa8fe337e0a3d21162f0d6299a7bf8192bfd2a76f"),
+];
+
+const fn fe(hex: &str) -> FieldElement {
+    FieldElement::from_u256_unchecked(U256::from_be_hex(hex))
+}
+
+/// Hashes `msg` to a point on secp256k1 with the domain separation tag `dst`, following the
+/// `secp256k1_XMD:SHA-256_SSWU_RO_` suite of RFC 9380.
+///
+/// The result is indistinguishable from a random point, and its discrete logarithm is unknown.
+pub fn hash_to_curve(msg: &[u8], dst: &[u8]) -> CenoSecp256k1Point {
+    let [u0, u1] = hash_to_field(msg, dst);
+    let mut point = map_to_curve(&u0);
+    point.complete_add_assign(&map_to_curve(&u1));
+    // secp256k1 has a cofactor of 1, so the sum needs no clearing.
+    point
+}
+
+/// Fills `out` with `expand_message_xmd` of RFC 9380 section 5.3.1, instantiated with SHA-256.
+///
+/// Panics if `out` is longer than `255 · 32` bytes.
+pub fn expand_message_xmd(msg: &[u8], dst: &[u8], out: &mut [u8]) {
+    let ell = out.len().div_ceil(B_IN_BYTES);
+    assert!(ell <= 255, "expand_message_xmd output is too long");
+
+    // Tags longer than 255 bytes are replaced by their digest, section 5.3.3.
+    let oversize;
+    let dst = if dst.len() > 255 {
+        let mut hasher = Sha256::new();
+        hasher.update(b"H2C-OVERSIZE-DST-");
+        hasher.update(dst);
+        oversize = hasher.finalize();
+        &oversize[..]
+    } else {
+        dst
+    };
+    let dst_len = [dst.len() as u8];
+
+    let mut hasher = Sha256::new();
+    hasher.update(&[0; R_IN_BYTES]);
+    hasher.update(msg);
+    hasher.update(&(out.len() as u16).to_be_bytes());
+    hasher.update(&[0]);
+    hasher.update(dst);
+    hasher.update(&dst_len);
+    let b0 = hasher.finalize();
+
+    let mut bi = [0u8; B_IN_BYTES];
+    for (i, chunk) in out.chunks_mut(B_IN_BYTES).enumerate() {
+        let mut hasher = Sha256::new();
+        // b_1 hashes b_0 itself, and every later block hashes b_0 xor the previous block.
+        for (byte, b0) in bi.iter_mut().zip(b0.iter()) {
+            *byte ^= b0;
+        }
+        hasher.update(&bi);
+        hasher.update(&[i as u8 + 1]);
+        hasher.update(dst);
+        hasher.update(&dst_len);
+        bi = hasher.finalize();
+        chunk.copy_from_slice(&bi[..chunk.len()]);
+    }
+}
+
+/// Hashes `msg` to two field elements, `hash_to_field` of RFC 9380 section 5.2 with `count = 2`.
+fn hash_to_field(msg: &[u8], dst: &[u8]) -> [FieldElement; 2] {
+    let mut uniform = [0u8; 2 * L];
+    expand_message_xmd(msg, dst, &mut uniform);
+
+    let mut elements = [FieldElement::ZERO; 2];
+    for (element, bytes) in elements.iter_mut().zip(uniform.chunks_exact(L)) {
+        // The 48 big endian bytes are split as `hi · 2^256 + lo`, where `hi` has 16 bytes and is
+        // already reduced, and `lo < 2^256 < 2p` needs at most one subtraction.
+        let mut hi = [0u8; 32];
+        hi[16..].copy_from_slice(&bytes[..16]);
+        let hi = FieldElement::from_u256_unchecked(U256::from_be_bytes(&hi));
+        let lo = U256::from_be_bytes(bytes[16..].try_into().unwrap());
+        let lo = FieldElement::from_u256_unchecked(lo.sub_if_ge(&FieldElement::MODULUS));
+        *element = hi * TWO_POW_256 + lo;
+    }
+    elements
+}
+
+/// Maps `u` to a point on secp256k1, the simplified SWU map onto `E'` followed by the isogeny.
+fn map_to_curve(u: &FieldElement) -> CenoSecp256k1Point {
+    let (x, y) = map_to_curve_simple_swu(u);
+    iso_map(&x, &y)
+}
+
+/// The simplified SWU map onto `E'` of RFC 9380 section 6.6.2, in constant time.
+fn map_to_curve_simple_swu(u: &FieldElement) -> (FieldElement, FieldElement) {
+    let z_u2 = Z * u.square();
+    let tv1 = z_u2.square() + z_u2;
+    let tv1 = tv1.invert().unwrap_or(FieldElement::ZERO);
+
+    // x1 = (-B' / A') · (1 + tv1), or B' / (Z · A') in the exceptional case tv1 = 0.
+    let x1 = FieldElement::conditional_select(
+        &(MINUS_B_OVER_A * (FieldElement::ONE + tv1)),
+        &B_OVER_ZA,
+        tv1.is_zero(),
+    );
+    let x2 = z_u2 * x1;
+
+    let y1 = curve_rhs(&x1).sqrt();
+    let y2 = curve_rhs(&x2).sqrt();
+    let gx1_is_square = y1.is_some();
+    let x = FieldElement::conditional_select(&x2, &x1, gx1_is_square);
+    let y = FieldElement::conditional_select(
+        &y2.unwrap_or(FieldElement::ZERO),
+        &y1.unwrap_or(FieldElement::ZERO),
+        gx1_is_square,
+    );
+
+    // The sign of y is chosen to match the sign of u.
+    let flip = !u.is_odd().ct_eq(&y.is_odd());
+    (x, FieldElement::conditional_select(&y, &-y, flip))
+}
+
+/// Evaluates `x^3 + A' · x + B'`, the right hand side of `E'`.
+fn curve_rhs(x: &FieldElement) -> FieldElement {
+    (x.square() + ISO_A) * x + ISO_B
+}
+
+/// Evaluates the monic polynomial with the lower coefficients `coeffs` at `x`.
+fn monic(coeffs: &[FieldElement], x: &FieldElement) -> FieldElement {
+    coeffs
+        .iter()
+        .rev()
+        .fold(FieldElement::ONE, |acc, coeff| acc * x + coeff)
+}
+
+/// Evaluates the polynomial with the coefficients `coeffs` at `x`.
+fn poly(coeffs: &[FieldElement], x: &FieldElement) -> FieldElement {
+    coeffs
+        .iter()
+        .rev()
+        .fold(FieldElement::ZERO, |acc, coeff| acc * x + coeff)
+}
+
+/// The 3-isogeny from `E'` to secp256k1 of RFC 9380 appendix E.1.
+fn iso_map(x: &FieldElement, y: &FieldElement) -> CenoSecp256k1Point {
+    let x_den = monic(&X_DEN, x);
+    let y_den = monic(&Y_DEN, x);
+
+    // The denominators only vanish on the kernel of the isogeny, which maps to the identity.
+    let is_kernel = x_den.is_zero() | y_den.is_zero();
+    let den_inv = (x_den * y_den).invert().unwrap_or(FieldElement::ZERO);
+    let iso_x = poly(&X_NUM, x) * y_den * den_inv;
+    let iso_y = *y * poly(&Y_NUM, x) * x_den * den_inv;
+
+    if bool::from(is_kernel) {
+        return CenoSecp256k1Point::infinity();
+    }
+    let mut limbs = [0u32; N];
+    limbs[..N / 2].copy_from_slice(&iso_x.to_u256().to_words());
+    limbs[N / 2..].copy_from_slice(&iso_y.to_u256().to_words());
+    CenoSecp256k1Point::new(limbs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    #[test]
+    fn expand_message_xmd_vectors() {
+        // RFC 9380 appendix K.1.
+        let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+        let mut out = [0u8; 32];
+        expand_message_xmd(b"", dst, &mut out);
+        assert_eq!(
+            out,
+            hex!("68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235")
+        );
+        expand_message_xmd(b"abc", dst, &mut out);
+        assert_eq!(
+            out,
+            hex!("d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615")
+        );
+    }
+
+    #[test]
+    fn hash_to_curve_vectors() {
+        // RFC 9380 appendix J.8.1.
+        let dst = b"QUUX-V01-CS02-with-secp256k1_XMD:SHA-256_SSWU_RO_";
+        let vectors: [(&[u8], [u8; 32], [u8; 32]); 3] = [
+            (
+                b"",
+                hex!("c1cae290e291aee617ebaef1be6d73861479c48b841eaba9b7b5852ddfeb1346"),
+                hex!("64fa678e07ae116126f08b022a94af6de15985c996c3a91b64c406a960e51067"),
+            ),
+            (
+                b"abc",
+                hex!("3377e01eab42db296b512293120c6cee72b6ecf9f9205760bd9ff11fb3cb2c4b"),
+                hex!("7f95890f33efebd1044d382a01b1bee0900fb6116f94688d487c6c7b9c8371f6"),
+            ),
+            (
+                b"abcdef0123456789",
+                hex!("bac54083f293f1fe08e4a70137260aa90783a5cb84d3f35848b324d0674b0e3a"),
+                hex!("4436476085d4c3c4508b60fcf4389c40176adce756b398bdee27bca19758d828"),
+            ),
+        ];
+        for (msg, x, y) in vectors {
+            let bytes = hash_to_curve(msg, dst).to_be_bytes();
+            assert_eq!(bytes[..32], x);
+            assert_eq!(bytes[32..], y);
+        }
+    }
+}
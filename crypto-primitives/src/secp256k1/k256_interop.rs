@@ -0,0 +1,94 @@
+//! Conversions between [`CenoSecp256k1Point`] and the points of the RustCrypto `k256` crate.
+//!
+//! Both directions go through the uncompressed SEC1 encoding, which the two crates share, and the
+//! identity maps to the identity.
+
+use super::{CenoSecp256k1Point, Secp256k1};
+use crate::ecdsa::CenoAffinePoint;
+use elliptic_curve::{
+    Error,
+    sec1::{FromEncodedPoint, ToEncodedPoint},
+};
+
+impl From<k256::AffinePoint> for CenoSecp256k1Point {
+    fn from(point: k256::AffinePoint) -> Self {
+        // Points of `k256` are always on the curve, so decoding them cannot fail.
+        CenoAffinePoint::<Secp256k1>::from_encoded_point(&point.to_encoded_point(false))
+            .unwrap()
+            .inner
+    }
+}
+
+impl TryFrom<CenoSecp256k1Point> for k256::AffinePoint {
+    type Error = Error;
+
+    /// Converts the point, returning an error if it is not on the curve, which can only happen
+    /// for points built from unchecked limbs.
+    fn try_from(point: CenoSecp256k1Point) -> Result<Self, Self::Error> {
+        let encoded = CenoAffinePoint::<Secp256k1> { inner: point }.to_encoded_point(false);
+        Option::from(k256::AffinePoint::from_encoded_point(&encoded)).ok_or(Error)
+    }
+}
+
+impl From<k256::PublicKey> for CenoSecp256k1Point {
+    fn from(key: k256::PublicKey) -> Self {
+        Self::from(*key.as_affine())
+    }
+}
+
+impl TryFrom<CenoSecp256k1Point> for k256::PublicKey {
+    type Error = Error;
+
+    /// Converts the point, returning an error for the identity or a point not on the curve.
+    fn try_from(point: CenoSecp256k1Point) -> Result<Self, Self::Error> {
+        k256::PublicKey::from_affine(k256::AffinePoint::try_from(point)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{AffinePoint, WeierstrassAffinePoint};
+    use elliptic_curve::{Group, PrimeField};
+
+    #[test]
+    fn generator_round_trips() {
+        let generator = k256::AffinePoint::GENERATOR;
+        let point: CenoSecp256k1Point = generator.into();
+        assert_eq!(
+            point.to_be_bytes(),
+            CenoSecp256k1Point::GENERATOR.to_be_bytes()
+        );
+        assert_eq!(k256::AffinePoint::try_from(point).unwrap(), generator);
+    }
+
+    #[test]
+    fn public_keys_round_trip() {
+        let secret = k256::Scalar::from_repr([7; 32].into()).unwrap();
+        let key = k256::PublicKey::from_affine((k256::ProjectivePoint::GENERATOR * secret).into())
+            .unwrap();
+        let point: CenoSecp256k1Point = key.into();
+        assert_eq!(k256::PublicKey::try_from(point).unwrap(), key);
+    }
+
+    #[test]
+    fn identity_maps_to_the_identity() {
+        let point: CenoSecp256k1Point = k256::AffinePoint::IDENTITY.into();
+        assert!(point.is_infinity());
+
+        let identity = k256::AffinePoint::try_from(CenoSecp256k1Point::infinity()).unwrap();
+        assert!(bool::from(
+            k256::ProjectivePoint::from(identity).is_identity()
+        ));
+        assert!(k256::PublicKey::try_from(CenoSecp256k1Point::infinity()).is_err());
+    }
+
+    #[test]
+    fn points_off_the_curve_are_rejected() {
+        let mut bytes = CenoSecp256k1Point::GENERATOR.to_be_bytes();
+        bytes[63] ^= 1;
+        let point = CenoSecp256k1Point::from_be_bytes(&bytes);
+        assert!(k256::AffinePoint::try_from(point).is_err());
+        assert!(k256::PublicKey::try_from(point).is_err());
+    }
+}
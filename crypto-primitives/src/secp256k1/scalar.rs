@@ -0,0 +1,20 @@
+//! The scalar field of the secp256k1 curve.
+
+use super::Secp256k1;
+use crate::field::{impl_field, impl_scalar};
+
+impl_field!(
+    /// An element of the secp256k1 scalar field, modulo the order of the curve.
+    Scalar,
+    curve = Secp256k1,
+    modulus = "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
+    two_inv = "7fffffffffffffffffffffffffffffff5d576e7357a4501ddfe92f46681b20a1",
+    multiplicative_generator = 7,
+    s = 6,
+    root_of_unity = "0c1dc060e7a91986df9879a3fbc483a898bdeab680756045992f4b5402b052f2",
+    root_of_unity_inv = "fd3ae181f12d7096efc7b0c75b8cbb7277a275910aa413c3b6fb30a0884f0d1c",
+    delta = "0000000000000000000cbc21fe4561c8d63b78e780e1341e199417c8c0bb7601",
+    t_minus_one_div_two = "01fffffffffffffffffffffffffffffffd755db9cd5e9140777fa4bd19a06c82",
+);
+
+impl_scalar!(Scalar, Secp256k1);
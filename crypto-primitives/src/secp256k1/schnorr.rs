@@ -0,0 +1,303 @@
+//! BIP-340 Schnorr signatures over secp256k1, see
+//! <https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki>.
+//!
+//! Public keys are x-only, so every point is implicitly the one with an even Y coordinate.
+
+use super::{FieldElement, Scalar, Secp256k1, try_decompress};
+use crate::{
+    ecdsa::{CenoAffinePoint, CenoProjectivePoint},
+    sha256::Sha256,
+};
+use elliptic_curve::{
+    Error, FieldBytes,
+    bigint::U256,
+    ff::Field as _,
+    group::Group,
+    ops::{LinearCombination, MulByGenerator, Reduce},
+    point::AffineCoordinates,
+};
+
+/// Computes the tagged hash `SHA256(SHA256(tag) || SHA256(tag) || parts...)`.
+fn tagged_hash(tag: &[u8], parts: &[&[u8]]) -> [u8; 32] {
+    let tag_hash = crate::sha256::sha256(tag);
+
+    let mut hasher = Sha256::new();
+    hasher.update(&tag_hash);
+    hasher.update(&tag_hash);
+    for part in parts {
+        hasher.update(part);
+    }
+    hasher.finalize()
+}
+
+/// Reduces a hash modulo the curve order.
+fn reduce(hash: [u8; 32]) -> Scalar {
+    <Scalar as Reduce<U256>>::reduce_bytes(&FieldBytes::<Secp256k1>::from(hash))
+}
+
+/// Signs `msg` with the secret key `seckey`, using `aux_rand` as auxiliary randomness.
+///
+/// Returns an error if `seckey` is zero or not smaller than the curve order.
+pub fn sign(seckey: &[u8; 32], msg: &[u8], aux_rand: &[u8; 32]) -> Result<[u8; 64], Error> {
+    let d = Option::<Scalar>::from(Scalar::from_be_bytes(seckey))
+        .filter(|d| !bool::from(d.is_zero()))
+        .ok_or(Error)?;
+
+    let public = CenoProjectivePoint::<Secp256k1>::mul_by_generator(&d).to_affine();
+    let d = match bool::from(public.y_is_odd()) {
+        true => -d,
+        false => d,
+    };
+    let public_x = public.x();
+
+    let aux_hash = tagged_hash(b"BIP0340/aux", &[aux_rand]);
+    let mut t = d.to_be_bytes();
+    for (byte, mask) in t.iter_mut().zip(aux_hash.iter()) {
+        *byte ^= mask;
+    }
+
+    let k = reduce(tagged_hash(b"BIP0340/nonce", &[&t, &public_x, msg]));
+    if bool::from(k.is_zero()) {
+        return Err(Error);
+    }
+
+    let nonce = CenoProjectivePoint::<Secp256k1>::mul_by_generator(&k).to_affine();
+    let k = match bool::from(nonce.y_is_odd()) {
+        true => -k,
+        false => k,
+    };
+    let nonce_x = nonce.x();
+
+    let e = reduce(tagged_hash(
+        b"BIP0340/challenge",
+        &[&nonce_x, &public_x, msg],
+    ));
+
+    let mut sig = [0u8; 64];
+    sig[..32].copy_from_slice(&nonce_x);
+    sig[32..].copy_from_slice(&(k + e * d).to_be_bytes());
+    Ok(sig)
+}
+
+/// Verifies the signature `sig` of `msg` under the x-only public key `pubkey_x`.
+pub fn verify(pubkey_x: &[u8; 32], msg: &[u8], sig: &[u8; 64]) -> bool {
+    let Ok(public) = try_decompress(pubkey_x, false) else {
+        return false;
+    };
+    let public = CenoProjectivePoint::from(CenoAffinePoint::<Secp256k1> { inner: public });
+
+    let nonce_x: &[u8; 32] = sig[..32].try_into().unwrap();
+    if bool::from(FieldElement::from_be_bytes(nonce_x).is_none()) {
+        return false;
+    }
+    let Some(s) = Option::<Scalar>::from(Scalar::from_be_bytes(sig[32..].try_into().unwrap()))
+    else {
+        return false;
+    };
+
+    let e = reduce(tagged_hash(b"BIP0340/challenge", &[nonce_x, pubkey_x, msg]));
+
+    // R = s · G - e · P
+    let nonce = CenoProjectivePoint::lincomb(
+        &CenoProjectivePoint::<Secp256k1>::generator(),
+        &s,
+        &public,
+        &-e,
+    )
+    .to_affine();
+
+    !bool::from(nonce.is_identity())
+        && !bool::from(nonce.y_is_odd())
+        && nonce.x()[..] == nonce_x[..]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    /// The secret key, public key, aux_rand, message and signature of a signing vector.
+    type SignVector = ([u8; 32], [u8; 32], [u8; 32], [u8; 32], [u8; 64]);
+
+    /// The BIP-340 signing vectors 0 to 3.
+    const SIGN_VECTORS: [SignVector; 4] = [
+        (
+            hex!("0000000000000000000000000000000000000000000000000000000000000003"),
+            hex!("F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9"),
+            hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+            hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+            hex!(
+                "E907831F80848D1069A5371B402410364BDF1C5F8307B0084C55F1CE2DCA8215"
+                "25F66A4A85EA8B71E482A74F382D2CE5EBEEE8FDB2172F477DF4900D310536C0"
+            ),
+        ),
+        (
+            hex!("B7E151628AED2A6ABF7158809CF4F3C762E7160F38B4DA56A784D9045190CFEF"),
+            hex!("DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659"),
+            hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+            hex!("243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89"),
+            hex!(
+                "6896BD60EEAE296DB48A229FF71DFE071BDE413E6D43F917DC8DCF8C78DE3341"
+                "8906D11AC976ABCCB20B091292BFF4EA897EFCB639EA871CFA95F6DE339E4B0A"
+            ),
+        ),
+        (
+            hex!("C90FDAA22168C234C4C6628B80DC1CD129024E088A67CC74020BBEA63B14E5C9"),
+            hex!("DD308AFEC5777E13121FA72B9CC1B7CC0139715309B086C960E18FD969774EB8"),
+            hex!("C87AA53824B4D7AE2EB035A2B5BBBCCC080E76CDC6D1692C4B0B62D798E6D906"),
+            hex!("7E2D58D8B3BCDF1ABADEC7829054F90DDA9805AAB56C77333024B9D0A508B75C"),
+            hex!(
+                "5831AAEED7B44BB74E5EAB94BA9D4294C49BCF2A60728D8B4C200F50DD313C1B"
+                "AB745879A5AD954A72C45A91C3A51D3C7ADEA98D82F8481E0E1E03674A6F3FB7"
+            ),
+        ),
+        (
+            hex!("0B432B2677937381AEF05BB02A66ECD012773062CF3FA2549E44F58ED2401710"),
+            hex!("25D1DFF95105F5253C4022F628A996AD3A0D95FBF21D468A1B33F8C160D8F517"),
+            hex!("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF"),
+            hex!("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF"),
+            hex!(
+                "7EB0509757E246F19449885651611CB965ECC1A187DD51B64FDA1EDC9637D5EC"
+                "97582B9CB13DB3933705B32BA982AF5AF25FD78881EBB32771FC5922EFC66EA3"
+            ),
+        ),
+    ];
+
+    /// The public key and message shared by most of the BIP-340 verification vectors.
+    const PUBLIC_KEY: [u8; 32] =
+        hex!("DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659");
+    const MESSAGE: [u8; 32] =
+        hex!("243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89");
+
+    #[test]
+    fn bip340_sign_vectors() {
+        for (seckey, pubkey, aux_rand, msg, expected) in SIGN_VECTORS {
+            let sig = sign(&seckey, &msg, &aux_rand).unwrap();
+            assert_eq!(sig, expected);
+            assert!(verify(&pubkey, &msg, &sig));
+        }
+    }
+
+    #[test]
+    fn bip340_variable_length_messages() {
+        let seckey = hex!("0340034003400340034003400340034003400340034003400340034003400340");
+        let vectors: [(&[u8], [u8; 64]); 4] = [
+            (
+                &[],
+                hex!(
+                    "71535DB165ECD9FBBC046E5FFAEA61186BB6AD436732FCCC25291A55895464CF"
+                    "6069CE26BF03466228F19A3A62DB8A649F2D560FAC652827D1AF0574E427AB63"
+                ),
+            ),
+            (
+                &hex!("11"),
+                hex!(
+                    "08A20A0AFEF64124649232E0693C583AB1B9934AE63B4C3511F3AE1134C6A303"
+                    "EA3173BFEA6683BD101FA5AA5DBC1996FE7CACFC5A577D33EC14564CEC2BACBF"
+                ),
+            ),
+            (
+                &hex!("0102030405060708090A0B0C0D0E0F1011"),
+                hex!(
+                    "5130F39A4059B43BC7CAC09A19ECE52B5D8699D1A71E3C52DA9AFDB6B50AC370"
+                    "C4A482B77BF960F8681540E25B6771ECE1E5A37FD80E5A51897C5566A97EA5A5"
+                ),
+            ),
+            (
+                &[0x99; 100],
+                hex!(
+                    "403B12B0D8555A344175EA7EC746566303321E5DBFA8BE6F091635163ECA79A8"
+                    "585ED3E3170807E7C03B720FC54C7B23897FCBA0E9D0B4A06894CFD249F22367"
+                ),
+            ),
+        ];
+        for (msg, expected) in vectors {
+            assert_eq!(sign(&seckey, msg, &[0; 32]).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn bip340_verify_vectors() {
+        assert!(verify(
+            &hex!("D69C3509BB99E412E68B0FE8544E72837DFA30746D8BE2AA65975F29D22DC7B9"),
+            &hex!("4DF3C3F68FCC83B27E9D42C90431A72499F17875C81A599B566C9889B9696703"),
+            &hex!(
+                "00000000000000000000003B78CE563F89A0ED9414F5AA28AD0D96D6795F9C63"
+                "76AFB1548AF603B3EB45C9F8207DEE1060CB71C04E80F593060B07D28308D7F4"
+            ),
+        ));
+
+        // The public key is not on the curve.
+        assert!(!verify(
+            &hex!("EEFDEA4CDB677750A420FEE807EACF21EB9898AE79B9768766E4FAA04A2D4A34"),
+            &MESSAGE,
+            &hex!(
+                "6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E177769"
+                "69E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B"
+            ),
+        ));
+        // The public key exceeds the field size.
+        assert!(!verify(
+            &hex!("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC30"),
+            &MESSAGE,
+            &hex!(
+                "6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E177769"
+                "69E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B"
+            ),
+        ));
+
+        let invalid: [[u8; 64]; 8] = [
+            // R has an odd Y.
+            hex!(
+                "FFF97BD5755EEEA420453A14355235D382F6472F8568A18B2F057A1460297556"
+                "3CC27944640AC607CD107AE10923D9EF7A73C643E166BE5EBEAFA34B1AC553E2"
+            ),
+            // The message is negated.
+            hex!(
+                "1FA62E331EDBC21C394792D2AB1100A7B432B013DF3F6FF4F99FCB33E0E1515F"
+                "28890B3EDB6E7189B630448B515CE4F8622A954CFE545735AAEA5134FCCDB2BD"
+            ),
+            // s is negated.
+            hex!(
+                "6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E177769"
+                "961764B3AA9B2FFCB6EF947B6887A226E8D7C93E00C5ED0C1834FF0D0C2E6DA6"
+            ),
+            // s · G - e · P is the identity, with x(R) = 0.
+            hex!(
+                "0000000000000000000000000000000000000000000000000000000000000000"
+                "123DDA8328AF9C23A94C1FEECFD123BA4FB73476F0D594DCB65C6425BD186051"
+            ),
+            // s · G - e · P is the identity, with x(R) = 1.
+            hex!(
+                "0000000000000000000000000000000000000000000000000000000000000001"
+                "7615FBAF5AE28864013C099742DEADB4DBA87F11AC6754F93780D5A1837CF197"
+            ),
+            // x(R) is not on the curve.
+            hex!(
+                "4A298DACAE57395A15D0795DDBFD1DCB564DA82B0F269BC70A74F8220429BA1D"
+                "69E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B"
+            ),
+            // x(R) is the field size.
+            hex!(
+                "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F"
+                "69E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B"
+            ),
+            // s is the curve order.
+            hex!(
+                "6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E177769"
+                "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141"
+            ),
+        ];
+        for sig in invalid {
+            assert!(!verify(&PUBLIC_KEY, &MESSAGE, &sig));
+        }
+    }
+
+    #[test]
+    fn sign_rejects_invalid_secret_keys() {
+        let order = Scalar::MODULUS.to_be_bytes();
+        for seckey in [[0; 32], order, [0xff; 32]] {
+            assert!(sign(&seckey, &MESSAGE, &[0; 32]).is_err());
+        }
+    }
+}
@@ -0,0 +1,225 @@
+//! The NIST P-256 curve, also known as secp256r1, accelerated with the secp256r1 precompiles.
+
+use crate::{
+    ecdsa::{CenoAffinePoint, CenoProjectivePoint, ECDSACurve, GeneratorTable, PrimeOrderCurve},
+    uint256,
+    utils::{AffinePoint, WeierstrassAffinePoint, WeierstrassPoint},
+};
+use ceno_syscall::{syscall_secp256r1_add, syscall_secp256r1_decompress, syscall_secp256r1_double};
+use elliptic_curve::{
+    Curve, CurveArithmetic, FieldBytesEncoding, PrimeCurve, bigint::U256,
+    generic_array::typenum::consts::U32,
+};
+use std::sync::OnceLock;
+
+/// The base field of the secp256r1 curve.
+pub mod field;
+pub use field::FieldElement;
+
+/// The scalar field of the secp256r1 curve.
+pub mod scalar;
+pub use scalar::Scalar;
+
+/// The NIST P-256 curve, `y^2 = x^3 - 3x + b`.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct NistP256;
+
+impl Curve for NistP256 {
+    type FieldBytesSize = U32;
+    type Uint = U256;
+
+    const ORDER: U256 =
+        U256::from_be_hex("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551");
+}
+
+impl PrimeCurve for NistP256 {}
+
+impl FieldBytesEncoding<NistP256> for U256 {}
+
+impl CurveArithmetic for NistP256 {
+    type AffinePoint = CenoAffinePoint<Self>;
+    type ProjectivePoint = CenoProjectivePoint<Self>;
+    type Scalar = Scalar;
+}
+
+impl ECDSACurve for NistP256 {
+    type FieldElement = FieldElement;
+    type SP1AffinePoint = CenoSecp256r1Point;
+
+    const EQUATION_A: FieldElement = FieldElement::from_u256_unchecked(uint256::U256::from_be_hex(
+        "ffffffff00000001000000000000000000000000fffffffffffffffffffffffc",
+    ));
+    const EQUATION_B: FieldElement = FieldElement::from_u256_unchecked(uint256::U256::from_be_hex(
+        "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b",
+    ));
+    const ORDER: uint256::U256 = Scalar::MODULUS;
+    const MODULUS: uint256::U256 = FieldElement::MODULUS;
+
+    fn decompress(point: &mut [u8; 64], is_odd: bool) {
+        syscall_secp256r1_decompress(point, is_odd);
+    }
+
+    fn generator_table() -> &'static GeneratorTable<Self> {
+        static TABLE: OnceLock<GeneratorTable<NistP256>> = OnceLock::new();
+        TABLE.get_or_init(GeneratorTable::generator)
+    }
+}
+
+impl PrimeOrderCurve for NistP256 {}
+
+/// The number of limbs in [CenoSecp256r1Point].
+pub const N: usize = 16;
+
+/// An affine point on the Secp256r1 curve.
+#[derive(Copy, Clone, Debug)]
+#[repr(align(4))]
+pub struct CenoSecp256r1Point(pub WeierstrassPoint<N>);
+
+// A swapped coordinate or limb order in `GENERATOR` fails to compile.
+const _: () = assert!(CenoSecp256r1Point::GENERATOR.0.has_be_hex_coordinates(
+    "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+    "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+));
+
+impl AsRef<WeierstrassPoint<N>> for CenoSecp256r1Point {
+    fn as_ref(&self) -> &WeierstrassPoint<N> {
+        &self.0
+    }
+}
+
+impl AsMut<WeierstrassPoint<N>> for CenoSecp256r1Point {
+    fn as_mut(&mut self) -> &mut WeierstrassPoint<N> {
+        &mut self.0
+    }
+}
+
+impl WeierstrassAffinePoint<N> for CenoSecp256r1Point {
+    const MODULUS: &'static [u32] = &FieldElement::MODULUS.0;
+
+    fn infinity() -> Self {
+        Self(WeierstrassPoint::Infinity)
+    }
+
+    fn is_infinity(&self) -> bool {
+        matches!(self.0, WeierstrassPoint::Infinity)
+    }
+}
+
+impl AffinePoint<N> for CenoSecp256r1Point {
+    /// The values are taken from SEC 2, section 2.4.2.
+    const GENERATOR: Self = Self(WeierstrassPoint::Affine([
+        3633889942, 4104206661, 770388896, 1996717441, 1671708914, 4173129445, 3777774151,
+        1796723186, 935285237, 3417718888, 1798397646, 734933847, 2081398294, 2397563722,
+        4263149467, 1340293858,
+    ]));
+
+    fn new(limbs: [u32; N]) -> Self {
+        Self(WeierstrassPoint::Affine(limbs))
+    }
+
+    fn identity() -> Self {
+        Self::infinity()
+    }
+
+    fn inner(&self) -> &WeierstrassPoint<N> {
+        &self.0
+    }
+
+    fn inner_mut(&mut self) -> &mut WeierstrassPoint<N> {
+        &mut self.0
+    }
+
+    fn is_identity(&self) -> bool {
+        self.is_infinity()
+    }
+
+    fn add_assign(&mut self, other: &Self) {
+        let a = self.limbs_mut();
+        let b = other.limbs_ref();
+        syscall_secp256r1_add(a, b);
+    }
+
+    fn complete_add_assign(&mut self, other: &Self) {
+        self.weierstrass_add_assign(other);
+    }
+
+    fn double(&mut self) {
+        match &mut self.0 {
+            WeierstrassPoint::Infinity => (),
+            WeierstrassPoint::Affine(limbs) => syscall_secp256r1_double(limbs),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use elliptic_curve::{ff::Field, group::Group, sec1::ToEncodedPoint};
+
+    #[test]
+    fn generator_matches_canonical_coordinates() {
+        let x = uint256::U256::from_be_hex(
+            "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+        );
+        let y = uint256::U256::from_be_hex(
+            "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+        );
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&x.to_be_bytes());
+        bytes[32..].copy_from_slice(&y.to_be_bytes());
+
+        let generator = CenoSecp256r1Point::from_be_bytes(&bytes);
+        assert_eq!(generator.limbs(), CenoSecp256r1Point::GENERATOR.limbs());
+
+        let x = FieldElement::from_be_bytes(bytes[..32].try_into().unwrap()).unwrap();
+        let y = FieldElement::from_be_bytes(bytes[32..].try_into().unwrap()).unwrap();
+        let point = CenoAffinePoint::<NistP256>::from_field_elements(x, y).unwrap();
+        assert_eq!(point, CenoAffinePoint::generator());
+    }
+
+    #[test]
+    fn generator_has_the_curve_order() {
+        let mut point = CenoSecp256r1Point::GENERATOR;
+        point.mul_assign(&Scalar::MODULUS.to_words());
+        assert!(point.is_identity());
+    }
+
+    #[test]
+    fn generator_is_on_the_curve() {
+        let (x, y) = CenoAffinePoint::<NistP256>::generator().field_elements();
+        assert_eq!(NistP256::EQUATION_A, -FieldElement::from_u64(3));
+        assert_eq!(
+            y.square(),
+            x.square() * x + NistP256::EQUATION_A * x + NistP256::EQUATION_B
+        );
+    }
+
+    #[test]
+    fn scalar_multiples_match_test_vectors() {
+        let generator = CenoProjectivePoint::<NistP256>::generator();
+        let (x, y) = (generator * Scalar::from_u64(2))
+            .to_affine()
+            .field_elements();
+        assert_eq!(
+            x.to_u256(),
+            uint256::U256::from_be_hex(
+                "7cf27b188d034f7e8a52380304b51ac3c08969e277f21b35a60b48fc47669978"
+            )
+        );
+        assert_eq!(
+            y.to_u256(),
+            uint256::U256::from_be_hex(
+                "07775510db8ed040293d9ac69f7430dbba7dade63ce982299e04b79d227873d1"
+            )
+        );
+
+        let k = 0x0123_4567_89ab_cdefu64;
+        let expected = (p256::ProjectivePoint::GENERATOR * p256::Scalar::from(k)).to_affine();
+        assert_eq!(
+            (generator * Scalar::from_u64(k))
+                .to_affine()
+                .to_encoded_point(false),
+            expected.to_encoded_point(false)
+        );
+    }
+}
@@ -0,0 +1,34 @@
+//! The base field of the secp256r1 curve.
+
+use super::NistP256;
+use crate::{ecdsa::Field, field::impl_field};
+use elliptic_curve::{FieldBytes, subtle::CtOption};
+
+impl_field!(
+    /// An element of the secp256r1 base field, `p = 2^256 - 2^224 + 2^192 + 2^96 - 1`.
+    FieldElement,
+    curve = NistP256,
+    modulus = "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+    two_inv = "7fffffff80000000800000000000000000000000800000000000000000000000",
+    multiplicative_generator = 6,
+    s = 1,
+    root_of_unity = "ffffffff00000001000000000000000000000000fffffffffffffffffffffffe",
+    root_of_unity_inv = "ffffffff00000001000000000000000000000000fffffffffffffffffffffffe",
+    delta = "0000000000000000000000000000000000000000000000000000000000000024",
+    t_minus_one_div_two = "3fffffffc00000004000000000000000000000003fffffffffffffffffffffff",
+);
+
+impl Field<NistP256> for FieldElement {
+    fn from_bytes(bytes: &FieldBytes<NistP256>) -> CtOption<Self> {
+        <Self as elliptic_curve::PrimeField>::from_repr(*bytes)
+    }
+
+    fn to_bytes(self) -> FieldBytes<NistP256> {
+        <Self as elliptic_curve::PrimeField>::to_repr(&self)
+    }
+
+    fn normalize(self) -> Self {
+        // Elements are always kept fully reduced.
+        self
+    }
+}
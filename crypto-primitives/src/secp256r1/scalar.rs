@@ -0,0 +1,20 @@
+//! The scalar field of the secp256r1 curve.
+
+use super::NistP256;
+use crate::field::{impl_field, impl_scalar};
+
+impl_field!(
+    /// An element of the secp256r1 scalar field, modulo the order of the curve.
+    Scalar,
+    curve = NistP256,
+    modulus = "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+    two_inv = "7fffffff800000007fffffffffffffffde737d56d38bcf4279dce5617e3192a9",
+    multiplicative_generator = 7,
+    s = 4,
+    root_of_unity = "ffc97f062a770992ba807ace842a3dfc1546cad004378daf0592d7fbb41e6602",
+    root_of_unity_inv = "a0a66a5562d46f2ac645fa0458131caee3ac117c794c4137379c7f0657c73764",
+    delta = "00000000000000000000000000000000000000000000000000001e39a5057d81",
+    t_minus_one_div_two = "07fffffff800000007fffffffffffffffde737d56d38bcf4279dce5617e3192a",
+);
+
+impl_scalar!(Scalar, NistP256);
@@ -0,0 +1,603 @@
+//! A 256 bit unsigned integer, with modular multiplication accelerated by the uint256
+//! precompile.
+//!
+//! [`ConstantTimeEq`], [`ConditionallySelectable`], [`U256::ct_lt`], [`U256::ct_gt`],
+//! [`U256::sub_if_ge`], [`U256::addmod`], [`U256::submod`], [`U256::powmod`] and the overflowing
+//! arithmetic run in constant time. The [`Ord`] and [`PartialEq`] implementations, as well as
+//! [`U256::is_zero`] and [`U256::bit`], return early or index by their argument and must not be
+//! used on secret values.
+
+use crate::CryptoError;
+use ceno_syscall::syscall_uint256_mul;
+use core::{cmp::Ordering, fmt};
+use elliptic_curve::subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+/// The number of limbs in [U256].
+pub const N: usize = 8;
+
+/// A 256 bit unsigned integer.
+///
+/// The integer is stored as little endian words, which is the layout expected by
+/// [`syscall_uint256_mul`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(align(4))]
+pub struct U256(pub [u32; N]);
+
+impl U256 {
+    /// The integer `0`.
+    pub const ZERO: Self = Self([0; N]);
+
+    /// The integer `1`.
+    pub const ONE: Self = Self([1, 0, 0, 0, 0, 0, 0, 0]);
+
+    /// Creates a new [`U256`] from a [`u64`].
+    pub const fn from_u64(value: u64) -> Self {
+        Self::from_u128(value as u128)
+    }
+
+    /// Creates a new [`U256`] from a [`u128`].
+    pub const fn from_u128(value: u128) -> Self {
+        let mut words = [0u32; N];
+        let mut i = 0;
+        while i < 4 {
+            words[i] = (value >> (32 * i)) as u32;
+            i += 1;
+        }
+        Self(words)
+    }
+
+    /// Creates a new [`U256`] from the given little endian words.
+    pub const fn from_words(words: [u32; N]) -> Self {
+        Self(words)
+    }
+
+    /// Returns the little endian words of `self`.
+    pub const fn to_words(self) -> [u32; N] {
+        self.0
+    }
+
+    /// Parses a big endian hex string of exactly 64 digits, without a `0x` prefix.
+    ///
+    /// Panics if the string is malformed, which turns into a compile error in `const` contexts.
+    pub const fn from_be_hex(hex: &str) -> Self {
+        let hex = hex.as_bytes();
+        assert!(hex.len() == 2 * 4 * N, "hex string must have 64 digits");
+
+        let mut words = [0u32; N];
+        let mut i = 0;
+        while i < hex.len() {
+            let digit = match hex[i] {
+                b'0'..=b'9' => hex[i] - b'0',
+                b'a'..=b'f' => hex[i] - b'a' + 10,
+                b'A'..=b'F' => hex[i] - b'A' + 10,
+                _ => panic!("invalid hex digit"),
+            };
+            let nibble = hex.len() - 1 - i;
+            words[nibble / 8] |= (digit as u32) << ((nibble % 8) * 4);
+            i += 1;
+        }
+
+        Self(words)
+    }
+
+    /// Creates a new [`U256`] from its big endian bytes.
+    pub fn from_be_bytes(bytes: &[u8; 4 * N]) -> Self {
+        let mut words = [0u32; N];
+        for (word, chunk) in words.iter_mut().zip(bytes.rchunks_exact(4)) {
+            *word = u32::from_be_bytes(chunk.try_into().unwrap());
+        }
+        Self(words)
+    }
+
+    /// Returns the big endian bytes of `self`.
+    pub fn to_be_bytes(&self) -> [u8; 4 * N] {
+        let mut bytes = [0u8; 4 * N];
+        for (chunk, word) in bytes.rchunks_exact_mut(4).zip(self.0.iter()) {
+            chunk.copy_from_slice(&word.to_be_bytes());
+        }
+        bytes
+    }
+
+    /// Creates a new [`U256`] from its little endian bytes.
+    pub fn from_le_bytes(bytes: &[u8; 4 * N]) -> Self {
+        let mut words = [0u32; N];
+        for (word, chunk) in words.iter_mut().zip(bytes.chunks_exact(4)) {
+            *word = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+        Self(words)
+    }
+
+    /// Returns the little endian bytes of `self`.
+    pub fn to_le_bytes(&self) -> [u8; 4 * N] {
+        let mut bytes = [0u8; 4 * N];
+        for (chunk, word) in bytes.chunks_exact_mut(4).zip(self.0.iter()) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Returns the little endian 64 bit limbs of `self`, as used by [`elliptic_curve::ff`]
+    /// exponents.
+    pub const fn to_u64_limbs(&self) -> [u64; N / 2] {
+        let mut limbs = [0u64; N / 2];
+        let mut i = 0;
+        while i < N / 2 {
+            limbs[i] = (self.0[2 * i] as u64) | ((self.0[2 * i + 1] as u64) << 32);
+            i += 1;
+        }
+        limbs
+    }
+
+    /// Returns true if `self` is zero.
+    pub fn is_zero(&self) -> bool {
+        self.0 == [0; N]
+    }
+
+    /// Returns bit `i` of `self`, with bit `0` being the least significant.
+    pub fn bit(&self, i: usize) -> bool {
+        (self.0[i / 32] >> (i % 32)) & 1 == 1
+    }
+
+    /// Computes `self + other`, returning the wrapped sum and whether it overflowed.
+    pub const fn overflowing_add(&self, other: &U256) -> (U256, bool) {
+        let mut res = [0u32; N];
+        let mut carry = false;
+        let mut i = 0;
+        while i < N {
+            let (sum, c1) = self.0[i].overflowing_add(other.0[i]);
+            let (sum, c2) = sum.overflowing_add(carry as u32);
+            res[i] = sum;
+            carry = c1 | c2;
+            i += 1;
+        }
+        (U256(res), carry)
+    }
+
+    /// Computes `self - other`, returning the wrapped difference and whether it borrowed.
+    pub const fn overflowing_sub(&self, other: &U256) -> (U256, bool) {
+        let mut res = [0u32; N];
+        let mut borrow = false;
+        let mut i = 0;
+        while i < N {
+            let (diff, b1) = self.0[i].overflowing_sub(other.0[i]);
+            let (diff, b2) = diff.overflowing_sub(borrow as u32);
+            res[i] = diff;
+            borrow = b1 | b2;
+            i += 1;
+        }
+        (U256(res), borrow)
+    }
+
+    /// Computes `self >> shift`.
+    pub const fn shr(&self, shift: usize) -> U256 {
+        let mut res = [0u32; N];
+        let (words, bits) = (shift / 32, shift % 32);
+        let mut i = 0;
+        while i + words < N {
+            let lo = self.0[i + words];
+            let hi = if i + words + 1 < N {
+                self.0[i + words + 1]
+            } else {
+                0
+            };
+            res[i] = if bits == 0 {
+                lo
+            } else {
+                (lo >> bits) | (hi << (32 - bits))
+            };
+            i += 1;
+        }
+        U256(res)
+    }
+
+    /// Returns true if `self < other`, in constant time.
+    pub fn ct_lt(&self, other: &U256) -> Choice {
+        let (_, borrow) = self.overflowing_sub(other);
+        Choice::from(borrow as u8)
+    }
+
+    /// Returns true if `self > other`, in constant time.
+    pub fn ct_gt(&self, other: &U256) -> Choice {
+        other.ct_lt(self)
+    }
+
+    /// Returns `self - modulus` if `self >= modulus` and `self` otherwise, in constant time.
+    ///
+    /// This is the final step of a modular reduction, bringing a value below `2 · modulus` into
+    /// the range `[0, modulus)`.
+    pub fn sub_if_ge(&self, modulus: &U256) -> U256 {
+        let (diff, borrow) = self.overflowing_sub(modulus);
+        U256::conditional_select(&diff, self, Choice::from(borrow as u8))
+    }
+
+    /// Computes `self + y mod modulus` in constant time, where `self` and `y` must be smaller than
+    /// `modulus`.
+    ///
+    /// Like [`U256::mulmod`], a zero `modulus` computes the sum modulo `2^256`.
+    pub fn addmod(&self, y: &U256, modulus: &U256) -> U256 {
+        let (sum, carry) = self.overflowing_add(y);
+        let (diff, borrow) = sum.overflowing_sub(modulus);
+
+        // The sum needs reducing if it overflowed or is at least the modulus. A zero modulus never
+        // borrows, so the wrapped sum is kept.
+        U256::conditional_select(&diff, &sum, Choice::from((borrow & !carry) as u8))
+    }
+
+    /// Computes `self - y mod modulus` in constant time, where `self` and `y` must be smaller than
+    /// `modulus`.
+    ///
+    /// Like [`U256::mulmod`], a zero `modulus` computes the difference modulo `2^256`.
+    pub fn submod(&self, y: &U256, modulus: &U256) -> U256 {
+        let (diff, borrow) = self.overflowing_sub(y);
+        let (sum, _) = diff.overflowing_add(modulus);
+        U256::conditional_select(&diff, &sum, Choice::from(borrow as u8))
+    }
+
+    /// Computes `self * y mod modulus` with the uint256 precompile.
+    ///
+    /// The precompile takes `y` and `modulus` as a single buffer of 16 words, `y` first, and treats
+    /// a zero modulus as `2^256`, so a zero `modulus` computes the product modulo `2^256`. Prefer
+    /// [`U256::wrapping_mul`] to state that intent.
+    pub fn mulmod(&self, y: &U256, modulus: &U256) -> U256 {
+        // The low half is `y` and the high half is the modulus.
+        let mut y_and_modulus = [0u32; 2 * N];
+        y_and_modulus[..N].copy_from_slice(&y.0);
+        y_and_modulus[N..].copy_from_slice(&modulus.0);
+
+        let mut res = *self;
+        syscall_uint256_mul(&mut res.0, &y_and_modulus);
+        res
+    }
+
+    /// Computes the wrapping product `self * y mod 2^256` with the uint256 precompile.
+    ///
+    /// This is [`U256::mulmod`] with a zero modulus, keeping only the low 256 bits of the product.
+    pub fn wrapping_mul(&self, y: &U256) -> U256 {
+        self.mulmod(y, &U256::ZERO)
+    }
+
+    /// Computes `self^exp mod modulus` with the uint256 precompile, in constant time in both
+    /// `self` and `exp`.
+    ///
+    /// Every one of the 256 exponent bits costs a squaring and a multiplication into a scratch
+    /// value, and the bit only selects whether the product is kept. Like [`U256::mulmod`], a zero
+    /// `modulus` computes the power modulo `2^256`.
+    pub fn powmod(&self, exp: &U256, modulus: &U256) -> U256 {
+        let mut res = U256::ONE;
+        for word in exp.0.iter().rev() {
+            for i in (0..32).rev() {
+                res = res.mulmod(&res, modulus);
+                let product = res.mulmod(self, modulus);
+                res =
+                    U256::conditional_select(&res, &product, Choice::from(((word >> i) & 1) as u8));
+            }
+        }
+        res
+    }
+}
+
+impl TryFrom<&[u8]> for U256 {
+    type Error = CryptoError;
+
+    /// Parses between 1 and 32 big endian bytes, as if they were padded with zeros on the left.
+    ///
+    /// Fails with [`CryptoError::InvalidLength`] for an empty slice or one longer than 32 bytes.
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        if bytes.is_empty() || bytes.len() > 4 * N {
+            return Err(CryptoError::InvalidLength(bytes.len()));
+        }
+
+        let mut padded = [0u8; 4 * N];
+        padded[4 * N - bytes.len()..].copy_from_slice(bytes);
+        Ok(Self::from_be_bytes(&padded))
+    }
+}
+
+impl From<u64> for U256 {
+    fn from(value: u64) -> Self {
+        Self::from_u64(value)
+    }
+}
+
+impl From<u128> for U256 {
+    fn from(value: u128) -> Self {
+        Self::from_u128(value)
+    }
+}
+
+impl ConstantTimeEq for U256 {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl ConditionallySelectable for U256 {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let mut res = [0u32; N];
+        for (i, limb) in res.iter_mut().enumerate() {
+            *limb = u32::conditional_select(&a.0[i], &b.0[i], choice);
+        }
+        U256(res)
+    }
+}
+
+impl fmt::LowerHex for U256 {
+    /// Formats `self` as exactly 64 big endian hex digits, the format of [`U256::from_be_hex`].
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        for word in self.0.iter().rev() {
+            write!(f, "{word:08x}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Ord for U256 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+}
+
+impl PartialOrd for U256 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use elliptic_curve::bigint::{self, Encoding};
+    use proptest::prelude::*;
+
+    /// Returns `value` as the reference integer of `crypto-bigint`.
+    fn big(value: &U256) -> bigint::U256 {
+        bigint::U256::from_be_bytes(value.to_be_bytes())
+    }
+
+    fn from_big(value: &bigint::U256) -> U256 {
+        U256::from_be_bytes(&value.to_be_bytes())
+    }
+
+    #[test]
+    fn formats_as_64_hex_digits() {
+        let hex = "0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20";
+        let value = U256::from_be_hex(hex);
+        assert_eq!(format!("{value:x}"), hex);
+        assert_eq!(format!("{value:#x}"), format!("0x{hex}"));
+        assert_eq!(format!("{:x}", U256::from_u64(1)), format!("{:0>64}", 1));
+    }
+
+    #[test]
+    fn byte_conversions_round_trip() {
+        let value =
+            U256::from_be_hex("0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20");
+        let be: [u8; 32] = core::array::from_fn(|i| i as u8 + 1);
+        let mut le = be;
+        le.reverse();
+
+        assert_eq!(value.to_be_bytes(), be);
+        assert_eq!(value.to_le_bytes(), le);
+        assert_eq!(U256::from_be_bytes(&be), value);
+        assert_eq!(U256::from_le_bytes(&le), value);
+        assert_eq!(value.0[0], 0x1d1e1f20);
+        assert_eq!(value.to_u64_limbs()[0], 0x191a1b1c1d1e1f20);
+        assert_eq!(U256::from_words(value.to_words()), value);
+        assert!(value.bit(5) && !value.bit(0) && !value.bit(255));
+        assert!(U256::ZERO.is_zero() && !U256::ONE.is_zero());
+    }
+
+    #[test]
+    fn constant_time_comparisons() {
+        let max = U256([u32::MAX; N]);
+        let high =
+            U256::from_be_hex("8000000000000000000000000000000000000000000000000000000000000000");
+        let pairs = [
+            (U256::ZERO, U256::ZERO),
+            (U256::ZERO, U256::ONE),
+            (U256::ONE, U256::ZERO),
+            (max, max),
+            (U256::ZERO, max),
+            (max, U256::ZERO),
+            (high, U256::from_u128(u128::MAX)),
+            (U256::from_u64(5), U256::from_u64(6)),
+        ];
+        for (a, b) in pairs {
+            assert_eq!(bool::from(a.ct_eq(&b)), a == b);
+            assert_eq!(bool::from(a.ct_lt(&b)), a < b);
+            assert_eq!(bool::from(a.ct_gt(&b)), a > b);
+        }
+    }
+
+    #[test]
+    fn sub_if_ge_reduces_once() {
+        let modulus = U256::from_u64(100);
+        assert_eq!(U256::from_u64(99).sub_if_ge(&modulus), U256::from_u64(99));
+        assert_eq!(U256::from_u64(100).sub_if_ge(&modulus), U256::ZERO);
+        assert_eq!(U256::from_u64(199).sub_if_ge(&modulus), U256::from_u64(99));
+
+        let max = U256([u32::MAX; N]);
+        assert_eq!(max.sub_if_ge(&max), U256::ZERO);
+        assert_eq!(U256::ZERO.sub_if_ge(&max), U256::ZERO);
+    }
+
+    #[test]
+    fn try_from_slices() {
+        assert_eq!(
+            U256::try_from(&[0x01, 0x02][..]).unwrap(),
+            U256::from_u64(0x0102)
+        );
+        let bytes: [u8; 32] = core::array::from_fn(|i| i as u8);
+        assert_eq!(
+            U256::try_from(&bytes[..]).unwrap(),
+            U256::from_be_bytes(&bytes)
+        );
+
+        assert_eq!(U256::try_from(&[][..]), Err(CryptoError::InvalidLength(0)));
+        assert_eq!(
+            U256::try_from(&[1; 33][..]),
+            Err(CryptoError::InvalidLength(33))
+        );
+    }
+
+    #[test]
+    fn small_integer_constructors() {
+        assert_eq!(U256::from_u64(0), U256::ZERO);
+        assert_eq!(U256::from(1u64), U256::ONE);
+        assert_eq!(
+            U256::from_u64(u64::MAX),
+            U256::from_be_hex("000000000000000000000000000000000000000000000000ffffffffffffffff")
+        );
+        assert_eq!(
+            U256::from(0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10u128),
+            U256::from_be_hex("000000000000000000000000000000000102030405060708090a0b0c0d0e0f10")
+        );
+    }
+
+    #[test]
+    fn conditional_select_picks_the_chosen_operand() {
+        let patterns = [
+            U256::ZERO,
+            U256::ONE,
+            U256([u32::MAX; N]),
+            U256([
+                0xaaaa_aaaa,
+                0x5555_5555,
+                0,
+                u32::MAX,
+                1,
+                0x8000_0000,
+                0x7fff_ffff,
+                2,
+            ]),
+        ];
+        for a in &patterns {
+            for b in &patterns {
+                assert_eq!(U256::conditional_select(a, b, Choice::from(0)), *a);
+                assert_eq!(U256::conditional_select(a, b, Choice::from(1)), *b);
+
+                let mut c = *a;
+                c.conditional_assign(b, Choice::from(1));
+                assert_eq!(c, *b);
+            }
+        }
+    }
+
+    #[test]
+    fn powmod_small_exponents_and_fermat() {
+        let p =
+            U256::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f");
+        let a = U256::from_u64(0x1234_5678_9abc_def0);
+
+        let mut expected = U256::ONE;
+        for e in 0..20 {
+            assert_eq!(a.powmod(&U256::from_u64(e), &p), expected);
+            expected = expected.mulmod(&a, &p);
+        }
+        let p_minus_one = p.overflowing_sub(&U256::ONE).0;
+        assert_eq!(a.powmod(&p_minus_one, &p), U256::ONE);
+        // A zero modulus works modulo 2^256.
+        let two = U256::from_u64(2);
+        let mut top_bit = U256::ZERO;
+        top_bit.0[N - 1] = 0x8000_0000;
+        assert_eq!(two.powmod(&U256::from_u64(255), &U256::ZERO), top_bit);
+        assert_eq!(two.powmod(&U256::from_u64(256), &U256::ZERO), U256::ZERO);
+    }
+
+    #[test]
+    fn addmod_and_submod_at_the_reduction_boundary() {
+        let p =
+            U256::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f");
+        let p_minus = |k: u64| p.overflowing_sub(&U256::from_u64(k)).0;
+        let one = U256::ONE;
+
+        // A sum of exactly the modulus reduces to zero, and one more to one.
+        assert_eq!(p_minus(1).addmod(&one, &p), U256::ZERO);
+        assert_eq!(p_minus(1).addmod(&U256::from_u64(2), &p), one);
+        assert_eq!(p_minus(2).addmod(&one, &p), p_minus(1));
+        // The sum of two large elements overflows 2^256 before it is reduced.
+        assert_eq!(p_minus(1).addmod(&p_minus(1), &p), p_minus(2));
+
+        // A borrow wraps around the modulus.
+        assert_eq!(U256::ZERO.submod(&one, &p), p_minus(1));
+        assert_eq!(one.submod(&p_minus(1), &p), U256::from_u64(2));
+        assert_eq!(one.submod(&one, &p), U256::ZERO);
+
+        // A zero modulus wraps modulo 2^256.
+        assert_eq!(
+            U256([u32::MAX; N]).addmod(&U256::from_u64(2), &U256::ZERO),
+            one
+        );
+        assert_eq!(U256::ZERO.submod(&one, &U256::ZERO), U256([u32::MAX; N]));
+    }
+
+    #[test]
+    fn mulmod_and_wrapping_mul() {
+        let max = U256([u32::MAX; N]);
+        assert_eq!(
+            U256::from_u64(7).mulmod(&U256::from_u64(9), &U256::from_u64(10)),
+            U256::from_u64(3)
+        );
+        // `(2^256 - 1)^2 = 1 mod (2^256 - 1)`, and `= 1 mod 2^256` as well.
+        assert_eq!(max.mulmod(&max, &max), U256::ZERO);
+        assert_eq!(max.mulmod(&max, &U256::ZERO), U256::ONE);
+        assert_eq!(max.wrapping_mul(&max), U256::ONE);
+
+        // `2^128 · 2^128` overflows to zero, and `2^255 · 3` keeps only `2^255`.
+        let two_128 = U256::from_u128(u128::MAX).overflowing_add(&U256::ONE).0;
+        assert_eq!(two_128.wrapping_mul(&two_128), U256::ZERO);
+        let high =
+            U256::from_be_hex("8000000000000000000000000000000000000000000000000000000000000000");
+        assert_eq!(high.wrapping_mul(&U256::from_u64(3)), high);
+        assert_eq!(
+            U256::from_u64(u64::MAX).wrapping_mul(&U256::from_u64(u64::MAX)),
+            U256::from_u128(u64::MAX as u128 * u64::MAX as u128)
+        );
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn arithmetic_matches_crypto_bigint(a in any::<[u32; N]>(), b in any::<[u32; N]>()) {
+            let (a, b) = (U256(a), U256(b));
+            let (sum, carry) = a.overflowing_add(&b);
+            prop_assert_eq!(sum, from_big(&big(&a).wrapping_add(&big(&b))));
+            prop_assert_eq!(carry, sum < a);
+
+            let (diff, borrow) = a.overflowing_sub(&b);
+            prop_assert_eq!(diff, from_big(&big(&a).wrapping_sub(&big(&b))));
+            prop_assert_eq!(borrow, a < b);
+
+            prop_assert_eq!(a.cmp(&b), big(&a).cmp(&big(&b)));
+            prop_assert_eq!(bool::from(a.ct_lt(&b)), a < b);
+            prop_assert_eq!(bool::from(a.ct_gt(&b)), a > b);
+            for shift in [0, 1, 31, 32, 100, 255] {
+                prop_assert_eq!(a.shr(shift), from_big(&big(&a).shr_vartime(shift)));
+            }
+        }
+
+        #[test]
+        fn mulmod_matches_crypto_bigint(
+            a in any::<[u32; N]>(),
+            b in any::<[u32; N]>(),
+            modulus in any::<[u32; N]>(),
+        ) {
+            let (a, b, modulus) = (U256(a), U256(b), U256(modulus));
+            prop_assume!(!modulus.is_zero());
+            let expected = bigint::U256::const_rem_wide(big(&a).mul_wide(&big(&b)), &big(&modulus));
+            prop_assert_eq!(a.mulmod(&b, &modulus), from_big(&expected.0));
+            prop_assert_eq!(a.wrapping_mul(&b), from_big(&big(&a).wrapping_mul(&big(&b))));
+
+            let (x, y, m) = (
+                big(&a).wrapping_rem(&big(&modulus)),
+                big(&b).wrapping_rem(&big(&modulus)),
+                big(&modulus),
+            );
+            prop_assert_eq!(from_big(&x).addmod(&from_big(&y), &modulus), from_big(&x.add_mod(&y, &m)));
+            prop_assert_eq!(from_big(&x).submod(&from_big(&y), &modulus), from_big(&x.sub_mod(&y, &m)));
+        }
+    }
+}
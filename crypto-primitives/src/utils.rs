@@ -1,4 +1,10 @@
 //! Copied from <https://github.com/succinctlabs/sp1/blob/ebb517c1a3f3e3b95ee34bf211fb46a73cf108fe/crates/zkvm/lib/src/utils.rs>
+use crate::uint256::{self, U256};
+use elliptic_curve::subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+/// Conversions between big endian bytes and little endian words or bits.
+pub mod bytes;
+
 pub trait AffinePoint<const N: usize>: Clone + Sized {
     /// The generator.
     const GENERATOR: Self;
@@ -15,21 +21,24 @@ pub trait AffinePoint<const N: usize>: Clone + Sized {
     /// Gets a mut reference to the inner [`WeierstrassPoint`].
     fn inner_mut(&mut self) -> &mut WeierstrassPoint<N>;
 
-    /// Returns a reference to the limbs.
+    /// Returns a reference to the limbs, or none for the infinity point.
+    fn limbs(&self) -> Option<&[u32; N]> {
+        self.inner().limbs()
+    }
+
+    /// Returns a reference to the limbs. If the point is the infinity point, this will panic.
     fn limbs_ref(&self) -> &[u32; N] {
-        match self.inner() {
-            WeierstrassPoint::Infinity => panic!("Infinity point has no limbs"),
-            WeierstrassPoint::Affine(limbs) => limbs,
-        }
+        self.limbs().expect("Infinity point has no limbs")
     }
 
     /// Returns a mutable reference to the limbs. If the point is the infinity point, this will
     /// panic.
+    ///
+    /// [`WeierstrassPoint::limbs_mut`] is the non-panicking variant.
     fn limbs_mut(&mut self) -> &mut [u32; N] {
-        match self.inner_mut() {
-            WeierstrassPoint::Infinity => panic!("Infinity point has no limbs"),
-            WeierstrassPoint::Affine(limbs) => limbs,
-        }
+        self.inner_mut()
+            .limbs_mut()
+            .expect("Infinity point has no limbs")
     }
 
     fn is_identity(&self) -> bool;
@@ -73,6 +82,10 @@ pub trait AffinePoint<const N: usize>: Clone + Sized {
     /// Adds the given [`AffinePoint`] to `self`. Can be optionally overridden to use a different
     /// implementation of addition in multi-scalar multiplication, which is used in secp256k1
     /// recovery.
+    ///
+    /// The default only calls [`AffinePoint::add_assign`], which need not handle the identity or
+    /// equal and opposite points. Curves override it with a complete addition, such as
+    /// [`WeierstrassAffinePoint::weierstrass_add_assign`], as all curves of this crate do.
     fn complete_add_assign(&mut self, other: &Self) {
         self.add_assign(other);
     }
@@ -101,6 +114,12 @@ pub trait AffinePoint<const N: usize>: Clone + Sized {
 
     /// Performs multi-scalar multiplication (MSM) on slices of bit vectors and points. Note:
     /// a_bits_le and b_bits_le should be in little endian order.
+    ///
+    /// Uses Shamir's trick: both scalars are walked from the most significant bit with a single
+    /// shared doubling per bit, adding `a`, `b` or the precomputed `a + b` as needed. Only
+    /// [`AffinePoint::complete_add_assign`] and [`AffinePoint::double`] are used, so this is a
+    /// correct default for any curve that overrides [`AffinePoint::complete_add_assign`] with a
+    /// complete addition, including when `a`, `b` or the result is the identity.
     fn multi_scalar_multiplication(
         a_bits_le: &[bool],
         a: Self,
@@ -110,18 +129,21 @@ pub trait AffinePoint<const N: usize>: Clone + Sized {
         // The length of the bit vectors must be the same.
         debug_assert!(a_bits_le.len() == b_bits_le.len());
 
+        let mut a_plus_b = a.clone();
+        a_plus_b.complete_add_assign(&b);
+
         let mut res: Self = Self::identity();
-        let mut temp_a = a.clone();
-        let mut temp_b = b.clone();
-        for (a_bit, b_bit) in a_bits_le.iter().zip(b_bits_le.iter()) {
-            if *a_bit {
-                res.complete_add_assign(&temp_a);
+        for (a_bit, b_bit) in a_bits_le.iter().zip(b_bits_le.iter()).rev() {
+            // Not every curve supports doubling the identity.
+            if !res.is_identity() {
+                res.double();
             }
-            if *b_bit {
-                res.complete_add_assign(&temp_b);
+            match (a_bit, b_bit) {
+                (true, true) => res.complete_add_assign(&a_plus_b),
+                (true, false) => res.complete_add_assign(&a),
+                (false, true) => res.complete_add_assign(&b),
+                (false, false) => (),
             }
-            temp_a.double();
-            temp_b.double();
         }
         res
     }
@@ -156,14 +178,284 @@ pub enum WeierstrassPoint<const N: usize> {
     Affine([u32; N]),
 }
 
+impl<const N: usize> WeierstrassPoint<N> {
+    /// Returns a reference to the limbs, `X` then `Y` as little endian words, or none for the
+    /// infinity point.
+    pub fn limbs(&self) -> Option<&[u32; N]> {
+        match self {
+            WeierstrassPoint::Infinity => None,
+            WeierstrassPoint::Affine(limbs) => Some(limbs),
+        }
+    }
+
+    /// Returns a mutable reference to the limbs, or none for the infinity point.
+    ///
+    /// Writing the limbs does not check that the point stays on the curve.
+    pub fn limbs_mut(&mut self) -> Option<&mut [u32; N]> {
+        match self {
+            WeierstrassPoint::Infinity => None,
+            WeierstrassPoint::Affine(limbs) => Some(limbs),
+        }
+    }
+
+    /// Writes the point into `bytes` as `X || Y`, with each coordinate big endian, which is the
+    /// layout used by most other libraries.
+    ///
+    /// The infinity point is written as all zeros, which is not on any of the supported curves.
+    pub fn write_be_coordinates(&self, bytes: &mut [u8]) {
+        assert_eq!(bytes.len(), 4 * N, "expected {} bytes", 4 * N);
+
+        let WeierstrassPoint::Affine(limbs) = self else {
+            bytes.fill(0);
+            return;
+        };
+
+        let (x, y) = limbs.split_at(N / 2);
+        let (x_bytes, y_bytes) = bytes.split_at_mut(2 * N);
+        for (coordinate, out) in [(x, x_bytes), (y, y_bytes)] {
+            for (chunk, word) in out.rchunks_exact_mut(4).zip(coordinate.iter()) {
+                chunk.copy_from_slice(&word.to_be_bytes());
+            }
+        }
+    }
+
+    /// Reads a point from `X || Y`, with each coordinate big endian, as written by
+    /// [`WeierstrassPoint::write_be_coordinates`].
+    ///
+    /// All zeros are read as the infinity point. The point is not checked to be on the curve.
+    pub fn read_be_coordinates(bytes: &[u8]) -> Self {
+        assert_eq!(bytes.len(), 4 * N, "expected {} bytes", 4 * N);
+
+        if bytes.iter().all(|&byte| byte == 0) {
+            return WeierstrassPoint::Infinity;
+        }
+
+        let mut limbs = [0u32; N];
+        let (x, y) = limbs.split_at_mut(N / 2);
+        let (x_bytes, y_bytes) = bytes.split_at(2 * N);
+        for (coordinate, input) in [(x, x_bytes), (y, y_bytes)] {
+            for (word, chunk) in coordinate.iter_mut().zip(input.rchunks_exact(4)) {
+                *word = u32::from_be_bytes(chunk.try_into().unwrap());
+            }
+        }
+        WeierstrassPoint::Affine(limbs)
+    }
+
+    /// Returns the limbs of the point as `B = 4 · N` bytes in memory order, with each little endian
+    /// word in turn, or none for the infinity point.
+    ///
+    /// The infinity point is encoded as all zeros by [`WeierstrassPoint::from_limb_bytes`], so
+    /// `to_limb_bytes().unwrap_or([0; B])` is a total encoding.
+    pub fn to_limb_bytes<const B: usize>(&self) -> Option<[u8; B]> {
+        const { assert!(B == 4 * N, "a point has 4 · N limb bytes") };
+
+        let WeierstrassPoint::Affine(limbs) = self else {
+            return None;
+        };
+        let mut bytes = [0u8; B];
+        for (chunk, limb) in bytes.chunks_exact_mut(4).zip(limbs.iter()) {
+            chunk.copy_from_slice(&limb.to_le_bytes());
+        }
+        Some(bytes)
+    }
+
+    /// Reads a point from `B = 4 · N` bytes in memory order, as written by
+    /// [`WeierstrassPoint::to_limb_bytes`].
+    ///
+    /// All zeros are read as the infinity point. The point is not checked to be on the curve.
+    pub fn from_limb_bytes<const B: usize>(bytes: &[u8; B]) -> Self {
+        const { assert!(B == 4 * N, "a point has 4 · N limb bytes") };
+
+        if bytes.iter().all(|&byte| byte == 0) {
+            return WeierstrassPoint::Infinity;
+        }
+        let mut limbs = [0u32; N];
+        for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(4)) {
+            *limb = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+        WeierstrassPoint::Affine(limbs)
+    }
+
+    /// Returns true if the point is affine with the coordinates `x` and `y`, each given as 64 big
+    /// endian hex digits.
+    ///
+    /// This is usable in `const` contexts, so hard-coded limbs can be checked against the
+    /// canonical coordinates at compile time.
+    pub const fn has_be_hex_coordinates(&self, x: &str, y: &str) -> bool {
+        assert!(N == 2 * uint256::N, "coordinates must have 256 bits");
+
+        let WeierstrassPoint::Affine(limbs) = self else {
+            return false;
+        };
+        let (x, y) = (
+            U256::from_be_hex(x).to_words(),
+            U256::from_be_hex(y).to_words(),
+        );
+        let mut i = 0;
+        while i < N / 2 {
+            if limbs[i] != x[i] || limbs[N / 2 + i] != y[i] {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Returns the infinity flag and the limbs of the point, with the infinity point mapped to
+    /// all-zero limbs so that both can be compared without branching on the variant.
+    pub(crate) fn ct_components(&self) -> (Choice, [u32; N]) {
+        match self {
+            WeierstrassPoint::Infinity => (Choice::from(1), [0; N]),
+            WeierstrassPoint::Affine(limbs) => (Choice::from(0), *limbs),
+        }
+    }
+}
+
+impl<const N: usize> ConstantTimeEq for WeierstrassPoint<N> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        let (self_is_infinity, self_limbs) = self.ct_components();
+        let (other_is_infinity, other_limbs) = other.ct_components();
+
+        // Both the flags and the limbs are always compared, so the result does not leak whether
+        // either operand is the infinity point.
+        let both_infinity = self_is_infinity & other_is_infinity;
+        self_is_infinity.ct_eq(&other_is_infinity)
+            & (both_infinity | self_limbs.ct_eq(&other_limbs))
+    }
+}
+
+impl<const N: usize> ConditionallySelectable for WeierstrassPoint<N> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let (a_is_infinity, a_limbs) = a.ct_components();
+        let (b_is_infinity, b_limbs) = b.ct_components();
+
+        // Every limb is selected, so only the variant of the result, and not `choice`, decides
+        // the one branch.
+        let limbs =
+            core::array::from_fn(|i| u32::conditional_select(&a_limbs[i], &b_limbs[i], choice));
+        match bool::from(Choice::conditional_select(
+            &a_is_infinity,
+            &b_is_infinity,
+            choice,
+        )) {
+            true => WeierstrassPoint::Infinity,
+            false => WeierstrassPoint::Affine(limbs),
+        }
+    }
+}
+
+/// Serializes the point as a tuple of its `N` limbs, with the infinity point encoded as all zeros
+/// like [`WeierstrassPoint::to_limb_bytes`].
+#[cfg(feature = "serde")]
+impl<const N: usize> serde::Serialize for WeierstrassPoint<N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeTuple;
+
+        let (_, limbs) = self.ct_components();
+        let mut tuple = serializer.serialize_tuple(N)?;
+        for limb in limbs.iter() {
+            tuple.serialize_element(limb)?;
+        }
+        tuple.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> serde::Deserialize<'de> for WeierstrassPoint<N> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct LimbsVisitor<const N: usize>;
+
+        impl<'de, const N: usize> serde::de::Visitor<'de> for LimbsVisitor<N> {
+            type Value = WeierstrassPoint<N>;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "a tuple of {N} limbs")
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<Self::Value, A::Error> {
+                let mut limbs = [0u32; N];
+                for (i, limb) in limbs.iter_mut().enumerate() {
+                    *limb = seq
+                        .next_element()?
+                        .ok_or_else(|| serde::de::Error::invalid_length(i, &self))?;
+                }
+                Ok(match limbs.iter().all(|&limb| limb == 0) {
+                    true => WeierstrassPoint::Infinity,
+                    false => WeierstrassPoint::Affine(limbs),
+                })
+            }
+        }
+
+        deserializer.deserialize_tuple(N, LimbsVisitor)
+    }
+}
+
 /// A trait for affine points on Weierstrass curves.
 pub trait WeierstrassAffinePoint<const N: usize>: AffinePoint<N> {
+    /// The prime of the base field, as `N / 2` little endian words.
+    const MODULUS: &'static [u32];
+
     /// The infinity point representation of the Weierstrass curve. Typically an enum variant.
     fn infinity() -> Self;
 
     /// Returns true if the point is the infinity point.
     fn is_infinity(&self) -> bool;
 
+    /// Returns the point as `X || Y`, with each coordinate big endian.
+    ///
+    /// This needs 256 bit coordinates, that is `N = 16`, which is checked at compile time. The
+    /// identity is encoded as all zeros, see [`WeierstrassPoint::write_be_coordinates`].
+    fn to_be_bytes(&self) -> [u8; 64] {
+        const { assert!(N == 16, "expected 256 bit coordinates") };
+        let mut bytes = [0u8; 64];
+        self.inner().write_be_coordinates(&mut bytes);
+        bytes
+    }
+
+    /// Creates a point from `X || Y`, with each coordinate big endian, without checking that it is
+    /// on the curve.
+    ///
+    /// This needs 256 bit coordinates, that is `N = 16`, which is checked at compile time. All
+    /// zeros decode to the identity, see [`WeierstrassPoint::read_be_coordinates`].
+    fn from_be_bytes(bytes: &[u8; 64]) -> Self {
+        const { assert!(N == 16, "expected 256 bit coordinates") };
+        let mut point = Self::infinity();
+        *point.inner_mut() = WeierstrassPoint::read_be_coordinates(bytes);
+        point
+    }
+
+    /// Negates `self` by replacing its Y coordinate with `p - Y`. The infinity point is left
+    /// unchanged.
+    fn negate_y(&mut self) {
+        if self.is_infinity() {
+            return;
+        }
+
+        let y = &mut self.limbs_mut()[N / 2..];
+        debug_assert!(Self::MODULUS.len() == y.len());
+        if y.iter().all(|&word| word == 0) {
+            return;
+        }
+
+        let mut borrow = false;
+        for (word, &modulus) in y.iter_mut().zip(Self::MODULUS.iter()) {
+            let (diff, b1) = modulus.overflowing_sub(*word);
+            let (diff, b2) = diff.overflowing_sub(borrow as u32);
+            *word = diff;
+            borrow = b1 | b2;
+        }
+    }
+
+    /// Subtracts `other` from `self` with a complete addition of `-other`.
+    fn sub_assign(&mut self, other: &Self) {
+        let mut negated = other.clone();
+        negated.negate_y();
+        self.weierstrass_add_assign(&negated);
+    }
+
     /// Performs the complete addition of two [`AffinePoint`]'s on a Weierstrass curve.
     /// For an addition of two points P1 and P2, the cases are:
     ///     1. P1 is infinity
@@ -209,4 +501,304 @@ pub trait WeierstrassAffinePoint<const N: usize>: AffinePoint<N> {
         // Case 5: Default addition.
         self.add_assign(other);
     }
+
+    /// Multiplies `self` by the scalar given as little endian bits.
+    ///
+    /// Uses a fixed-window double-and-add over [`AffinePoint::complete_add_assign`] and
+    /// [`AffinePoint::double`], so it is correct for any curve, including when `self` or the
+    /// result is the identity.
+    fn scalar_mul(&self, scalar_bits_le: &[bool]) -> Self {
+        const WINDOW: usize = 4;
+
+        // table[i] = i * self
+        let mut table = Vec::with_capacity(1 << WINDOW);
+        table.push(Self::infinity());
+        for i in 1..(1 << WINDOW) {
+            let mut next: Self = table[i - 1].clone();
+            next.complete_add_assign(self);
+            table.push(next);
+        }
+
+        let mut res = Self::infinity();
+        for window in scalar_bits_le.chunks(WINDOW).rev() {
+            // Not every curve supports doubling the infinity point.
+            if !res.is_infinity() {
+                for _ in 0..window.len() {
+                    res.double();
+                }
+            }
+
+            let digit = window
+                .iter()
+                .rev()
+                .fold(0, |acc, &bit| (acc << 1) | bit as usize);
+            res.complete_add_assign(&table[digit]);
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bn254::Bn254Point, secp256k1::CenoSecp256k1Point, secp256r1::CenoSecp256r1Point};
+    use proptest::prelude::*;
+
+    fn check_round_trip<P: WeierstrassAffinePoint<16>>(x: &str, y: &str) {
+        let bytes = P::GENERATOR.to_be_bytes();
+        assert_eq!(
+            U256::from_be_bytes(bytes[..32].try_into().unwrap()),
+            U256::from_be_hex(x)
+        );
+        assert_eq!(
+            U256::from_be_bytes(bytes[32..].try_into().unwrap()),
+            U256::from_be_hex(y)
+        );
+        assert_eq!(P::from_be_bytes(&bytes).limbs(), P::GENERATOR.limbs());
+
+        let identity = P::infinity().to_be_bytes();
+        assert_eq!(identity, [0; 64]);
+        assert!(P::from_be_bytes(&identity).is_infinity());
+    }
+
+    /// Returns the little endian bits of the little endian `words`.
+    fn bits_le(words: &[u32]) -> Vec<bool> {
+        words
+            .iter()
+            .flat_map(|word| (0..32).map(move |i| (word >> i) & 1 == 1))
+            .collect()
+    }
+
+    fn check_scalar_mul<P: WeierstrassAffinePoint<16>>() {
+        let mut expected = P::infinity();
+        for k in 0..40u32 {
+            let actual = P::GENERATOR.scalar_mul(&bits_le(&[k]));
+            assert_eq!(actual.is_infinity(), expected.is_infinity(), "k = {k}");
+            if !expected.is_infinity() {
+                assert_eq!(actual.limbs(), expected.limbs(), "k = {k}");
+            }
+            expected.complete_add_assign(&P::GENERATOR);
+        }
+
+        assert!(P::infinity().scalar_mul(&bits_le(&[5])).is_infinity());
+        assert!(P::GENERATOR.scalar_mul(&[]).is_infinity());
+    }
+
+    #[test]
+    fn scalar_mul_matches_repeated_addition() {
+        check_scalar_mul::<CenoSecp256k1Point>();
+        check_scalar_mul::<CenoSecp256r1Point>();
+        check_scalar_mul::<Bn254Point>();
+    }
+
+    /// Returns `a · x + b · y`, computed with two separate scalar multiplications.
+    fn naive_msm<P: WeierstrassAffinePoint<16>>(a: &[u32; 8], x: &P, b: &[u32; 8], y: &P) -> P {
+        let (mut ax, mut by) = (x.clone(), y.clone());
+        ax.mul_assign(a);
+        by.mul_assign(b);
+        ax.complete_add_assign(&by);
+        ax
+    }
+
+    fn check_msm(a: [u32; 8], x: CenoSecp256k1Point, b: [u32; 8], y: CenoSecp256k1Point) {
+        let expected = naive_msm(&a, &x, &b, &y);
+        let actual =
+            CenoSecp256k1Point::multi_scalar_multiplication(&bits_le(&a), x, &bits_le(&b), y);
+        assert_eq!(actual.is_infinity(), expected.is_infinity());
+        if !expected.is_infinity() {
+            assert_eq!(actual.limbs(), expected.limbs());
+        }
+    }
+
+    #[test]
+    fn msm_handles_the_identity() {
+        let g = CenoSecp256k1Point::GENERATOR;
+        let (a, b) = ([5, 0, 0, 0, 0, 0, 0, 0], [7, 0, 0, 0, 0, 0, 0, 0]);
+        check_msm(a, CenoSecp256k1Point::infinity(), b, g);
+        check_msm(a, g, b, CenoSecp256k1Point::infinity());
+        check_msm([0; 8], g, b, g);
+
+        // 5 · G + (n - 5) · G is the identity.
+        let mut minus_five = crate::secp256k1::Scalar::MODULUS.to_words();
+        minus_five[0] -= 5;
+        check_msm(a, g, minus_five, g);
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn msm_matches_separate_products(a in any::<[u32; 8]>(), b in any::<[u32; 8]>(), k in 1..100u32) {
+            let mut y = CenoSecp256k1Point::GENERATOR;
+            y.mul_assign(&[k, 0, 0, 0, 0, 0, 0, 0]);
+            check_msm(a, CenoSecp256k1Point::GENERATOR, b, y);
+        }
+    }
+
+    /// Asserts that `a` and `b` are the same point.
+    fn assert_same<P: WeierstrassAffinePoint<16>>(a: &P, b: &P) {
+        assert_eq!(a.is_infinity(), b.is_infinity());
+        if !a.is_infinity() {
+            assert_eq!(a.limbs_ref(), b.limbs_ref());
+        }
+    }
+
+    #[test]
+    fn limb_bytes_round_trip() {
+        let infinity = WeierstrassPoint::<16>::Infinity;
+        assert_eq!(infinity.to_limb_bytes::<64>(), None);
+        assert!(matches!(
+            WeierstrassPoint::<16>::from_limb_bytes(&[0; 64]),
+            WeierstrassPoint::Infinity
+        ));
+
+        for point in [CenoSecp256k1Point::GENERATOR.0, Bn254Point::GENERATOR.0] {
+            let bytes = point.to_limb_bytes::<64>().unwrap();
+            assert_eq!(bytes[..4], point.limbs().unwrap()[0].to_le_bytes());
+            let decoded = WeierstrassPoint::<16>::from_limb_bytes(&bytes);
+            assert_eq!(decoded.limbs(), point.limbs());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_round_trip() {
+        let point = CenoSecp256k1Point::GENERATOR.0;
+        let json = serde_json::to_string(&point).unwrap();
+        let limbs: [u32; 16] = serde_json::from_str(&json).unwrap();
+        assert_eq!(&limbs, point.limbs().unwrap());
+        let decoded: WeierstrassPoint<16> = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.limbs(), point.limbs());
+
+        let json = serde_json::to_string(&WeierstrassPoint::<16>::Infinity).unwrap();
+        assert_eq!(json, serde_json::to_string(&[0u32; 16]).unwrap());
+        let decoded: WeierstrassPoint<16> = serde_json::from_str(&json).unwrap();
+        assert!(matches!(decoded, WeierstrassPoint::Infinity));
+        assert!(serde_json::from_str::<WeierstrassPoint<16>>("[1, 2, 3]").is_err());
+    }
+
+    fn check_sub<P: WeierstrassAffinePoint<16>>() {
+        let p = P::GENERATOR.scalar_mul(&bits_le(&[11]));
+        let q = P::GENERATOR.scalar_mul(&bits_le(&[4]));
+        let mut minus_q = q.clone();
+        minus_q.negate_y();
+
+        let mut diff = p.clone();
+        diff.sub_assign(&q);
+        let mut expected = p.clone();
+        expected.complete_add_assign(&minus_q);
+        assert_same(&diff, &expected);
+        assert_same(&diff, &P::GENERATOR.scalar_mul(&bits_le(&[7])));
+
+        // P - P is the identity, and subtracting to or from the identity negates or keeps.
+        let mut zero = p.clone();
+        zero.sub_assign(&p);
+        assert!(zero.is_infinity());
+        let mut negated = P::infinity();
+        negated.sub_assign(&q);
+        assert_same(&negated, &minus_q);
+        let mut same = p.clone();
+        same.sub_assign(&P::infinity());
+        assert_same(&same, &p);
+    }
+
+    #[test]
+    fn sub_is_addition_of_the_negation() {
+        check_sub::<CenoSecp256k1Point>();
+        check_sub::<CenoSecp256r1Point>();
+        check_sub::<Bn254Point>();
+    }
+
+    fn check_limb_accessors<P>()
+    where
+        P: WeierstrassAffinePoint<16> + AsRef<WeierstrassPoint<16>> + AsMut<WeierstrassPoint<16>>,
+    {
+        let mut infinity = P::infinity();
+        assert!(infinity.limbs().is_none());
+        assert!(infinity.as_mut().limbs_mut().is_none());
+
+        let g = P::GENERATOR;
+        assert_eq!(g.limbs(), Some(g.limbs_ref()));
+        assert_eq!(g.as_ref().limbs(), g.limbs());
+
+        // Writing the negated Y coordinate through `AsMut` gives `-G`.
+        let mut minus_g = g.clone();
+        minus_g.negate_y();
+        let mut point = g.clone();
+        point.as_mut().limbs_mut().unwrap()[8..].copy_from_slice(&minus_g.limbs_ref()[8..]);
+        assert_same(&point, &minus_g);
+        assert_eq!(point.as_ref().limbs(), minus_g.limbs());
+
+        let mut sum = point;
+        sum.complete_add_assign(&g);
+        assert!(sum.is_infinity());
+    }
+
+    #[test]
+    fn limb_accessors_read_and_write() {
+        check_limb_accessors::<CenoSecp256k1Point>();
+        check_limb_accessors::<CenoSecp256r1Point>();
+        check_limb_accessors::<Bn254Point>();
+    }
+
+    #[test]
+    fn scalar_mul_by_the_order_is_the_identity() {
+        assert!(
+            Bn254Point::GENERATOR
+                .scalar_mul(&bits_le(&crate::bn254::ORDER))
+                .is_infinity()
+        );
+    }
+
+    #[test]
+    fn ct_eq_of_weierstrass_points() {
+        let infinity = WeierstrassPoint::<16>::Infinity;
+        let generator = *CenoSecp256k1Point::GENERATOR.inner();
+        let mut other = generator;
+        other.limbs_mut().unwrap()[15] ^= 1;
+        // All-zero limbs must not compare equal to the infinity point.
+        let zeros = WeierstrassPoint::<16>::Affine([0; 16]);
+
+        assert!(bool::from(infinity.ct_eq(&infinity)));
+        assert!(bool::from(generator.ct_eq(&generator)));
+        assert!(!bool::from(generator.ct_eq(&other)));
+        assert!(!bool::from(infinity.ct_eq(&generator)));
+        assert!(!bool::from(generator.ct_eq(&infinity)));
+        assert!(!bool::from(infinity.ct_eq(&zeros)));
+        assert!(!bool::from(zeros.ct_eq(&infinity)));
+    }
+
+    #[test]
+    fn conditional_select_of_weierstrass_points() {
+        let infinity = WeierstrassPoint::<16>::Infinity;
+        let generator = *CenoSecp256k1Point::GENERATOR.inner();
+        for (a, b) in [
+            (generator, infinity),
+            (infinity, generator),
+            (infinity, infinity),
+            (generator, generator),
+        ] {
+            let picked = WeierstrassPoint::conditional_select(&a, &b, Choice::from(0));
+            assert!(bool::from(picked.ct_eq(&a)));
+            let picked = WeierstrassPoint::conditional_select(&a, &b, Choice::from(1));
+            assert!(bool::from(picked.ct_eq(&b)));
+        }
+    }
+
+    #[test]
+    fn be_bytes_round_trip() {
+        check_round_trip::<CenoSecp256k1Point>(
+            "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+            "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+        );
+        check_round_trip::<CenoSecp256r1Point>(
+            "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+            "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+        );
+        check_round_trip::<Bn254Point>(
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "0000000000000000000000000000000000000000000000000000000000000002",
+        );
+    }
 }
@@ -0,0 +1,125 @@
+//! Conversions between big endian bytes and the little endian words and bits used by the
+//! precompiles.
+//!
+//! Every conversion is generic over its length and fails with [`CryptoError::InvalidLength`],
+//! holding the length of the input, when the input does not match the output exactly.
+
+use crate::CryptoError;
+
+/// Converts the big endian bytes of an integer into its `N` little endian words.
+///
+/// Fails if `bytes` is not exactly `4 · N` bytes long.
+pub fn be_bytes_to_le_words<const N: usize>(bytes: &[u8]) -> Result<[u32; N], CryptoError> {
+    if bytes.len() != 4 * N {
+        return Err(CryptoError::InvalidLength(bytes.len()));
+    }
+
+    let mut words = [0u32; N];
+    for (word, chunk) in words.iter_mut().zip(bytes.rchunks_exact(4)) {
+        *word = u32::from_be_bytes(chunk.try_into().unwrap());
+    }
+    Ok(words)
+}
+
+/// Converts the little endian words of an integer into its `B` big endian bytes.
+///
+/// Fails if `words` does not hold exactly `B / 4` words, with the length given in bytes.
+pub fn le_words_to_be_bytes<const B: usize>(words: &[u32]) -> Result<[u8; B], CryptoError> {
+    if 4 * words.len() != B {
+        return Err(CryptoError::InvalidLength(4 * words.len()));
+    }
+
+    let mut bytes = [0u8; B];
+    for (chunk, word) in bytes.rchunks_exact_mut(4).zip(words.iter()) {
+        chunk.copy_from_slice(&word.to_be_bytes());
+    }
+    Ok(bytes)
+}
+
+/// Converts the big endian bytes of an integer into its `BITS` bits, least significant first.
+///
+/// Fails if `bytes` is not exactly `BITS / 8` bytes long.
+pub fn be_bytes_to_le_bits<const BITS: usize>(bytes: &[u8]) -> Result<[bool; BITS], CryptoError> {
+    if 8 * bytes.len() != BITS {
+        return Err(CryptoError::InvalidLength(bytes.len()));
+    }
+
+    let mut bits = [false; BITS];
+    for (i, &byte) in bytes.iter().rev().enumerate() {
+        for j in 0..8 {
+            bits[i * 8 + j] = (byte >> j) & 1 == 1;
+        }
+    }
+    Ok(bits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn words_are_little_endian() {
+        let bytes: [u8; 32] = core::array::from_fn(|i| i as u8);
+        let words = be_bytes_to_le_words::<8>(&bytes).unwrap();
+        assert_eq!(words[0], 0x1c1d1e1f);
+        assert_eq!(words[7], 0x00010203);
+        assert_eq!(le_words_to_be_bytes::<32>(&words).unwrap(), bytes);
+
+        // The 48 byte elements of BLS12-381.
+        let bytes: [u8; 48] = core::array::from_fn(|i| 0xff - i as u8);
+        let words = be_bytes_to_le_words::<12>(&bytes).unwrap();
+        assert_eq!(words[0], 0xd3d2d1d0);
+        assert_eq!(words[11], 0xfffefdfc);
+        assert_eq!(le_words_to_be_bytes::<48>(&words).unwrap(), bytes);
+    }
+
+    #[test]
+    fn bits_are_least_significant_first() {
+        let mut bytes = [0u8; 32];
+        bytes[31] = 0b0000_0101;
+        bytes[0] = 0x80;
+        let bits = be_bytes_to_le_bits::<256>(&bytes).unwrap();
+        let set: Vec<usize> = (0..256).filter(|&i| bits[i]).collect();
+        assert_eq!(set, [0, 2, 255]);
+
+        let bits = be_bytes_to_le_bits::<384>(&[0x01; 48]).unwrap();
+        assert_eq!(bits.iter().filter(|&&bit| bit).count(), 48);
+        assert!(bits[0] && bits[376] && !bits[383]);
+    }
+
+    #[test]
+    fn length_mismatches_are_rejected() {
+        assert_eq!(
+            be_bytes_to_le_words::<8>(&[0; 31]),
+            Err(CryptoError::InvalidLength(31))
+        );
+        assert_eq!(
+            be_bytes_to_le_words::<12>(&[0; 32]),
+            Err(CryptoError::InvalidLength(32))
+        );
+        assert_eq!(
+            le_words_to_be_bytes::<32>(&[0; 12]),
+            Err(CryptoError::InvalidLength(48))
+        );
+        assert_eq!(
+            be_bytes_to_le_bits::<256>(&[0; 48]),
+            Err(CryptoError::InvalidLength(48))
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn words_round_trip(bytes in any::<[u8; 32]>()) {
+            let words = be_bytes_to_le_words::<8>(&bytes).unwrap();
+            prop_assert_eq!(le_words_to_be_bytes::<32>(&words).unwrap(), bytes);
+
+            let bits = be_bytes_to_le_bits::<256>(&bytes).unwrap();
+            for (i, word) in words.iter().enumerate() {
+                for j in 0..32 {
+                    prop_assert_eq!(bits[32 * i + j], (word >> j) & 1 == 1);
+                }
+            }
+        }
+    }
+}
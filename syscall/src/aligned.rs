@@ -0,0 +1,123 @@
+//! Buffers aligned along a four byte boundary, as required by every precompile.
+
+/// A buffer of `N` words, which is aligned along a four byte boundary by construction.
+///
+/// Byte inputs such as those of the decompress precompiles can be staged in the buffer and passed
+/// on through [`Aligned4::as_bytes_mut`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(C, align(4))]
+pub struct Aligned4<const N: usize>(pub [u32; N]);
+
+const _: () = assert!(core::mem::align_of::<Aligned4<1>>() == 4);
+
+impl<const N: usize> Aligned4<N> {
+    /// Creates a buffer of zeros.
+    pub const fn zeroed() -> Self {
+        Self([0; N])
+    }
+
+    /// Returns a pointer to the first word of the buffer.
+    pub fn as_ptr(&self) -> *const u32 {
+        self.0.as_ptr()
+    }
+
+    /// Returns a mutable pointer to the first word of the buffer.
+    pub fn as_mut_ptr(&mut self) -> *mut u32 {
+        self.0.as_mut_ptr()
+    }
+
+    /// Returns the `4 · N` bytes of the buffer, in memory order.
+    pub fn as_bytes(&self) -> &[u8] {
+        // SAFETY: `u32` has no padding and any byte is a valid `u8`, so the words can be viewed as
+        // bytes of the same total length, which are less strictly aligned.
+        unsafe { core::slice::from_raw_parts(self.0.as_ptr().cast(), 4 * N) }
+    }
+
+    /// Returns the `4 · N` bytes of the buffer mutably, in memory order.
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        // SAFETY: as in `as_bytes`, and every byte pattern is a valid `u32`.
+        unsafe { core::slice::from_raw_parts_mut(self.0.as_mut_ptr().cast(), 4 * N) }
+    }
+}
+
+impl<const N: usize> Default for Aligned4<N> {
+    fn default() -> Self {
+        Self::zeroed()
+    }
+}
+
+impl<const N: usize> AsRef<[u32; N]> for Aligned4<N> {
+    fn as_ref(&self) -> &[u32; N] {
+        &self.0
+    }
+}
+
+impl<const N: usize> AsMut<[u32; N]> for Aligned4<N> {
+    fn as_mut(&mut self) -> &mut [u32; N] {
+        &mut self.0
+    }
+}
+
+/// Implements the byte array conversions for buffers of `$words` words and `$bytes` bytes.
+macro_rules! impl_byte_conversions {
+    ($($words:literal => $bytes:literal),+ $(,)?) => {
+        $(
+            impl From<[u8; $bytes]> for Aligned4<$words> {
+                /// Copies the bytes into the buffer, preserving their memory order.
+                fn from(bytes: [u8; $bytes]) -> Self {
+                    let mut buffer = Self::zeroed();
+                    buffer.as_bytes_mut().copy_from_slice(&bytes);
+                    buffer
+                }
+            }
+
+            impl Aligned4<$words> {
+                /// Returns a copy of the bytes of the buffer, in memory order.
+                pub fn to_bytes(&self) -> [u8; $bytes] {
+                    self.as_bytes().try_into().unwrap()
+                }
+
+                /// Returns the bytes of the buffer as a mutable array, as taken by the byte
+                /// oriented precompiles.
+                pub fn as_byte_array_mut(&mut self) -> &mut [u8; $bytes] {
+                    self.as_bytes_mut().try_into().unwrap()
+                }
+            }
+        )+
+    };
+}
+
+impl_byte_conversions!(8 => 32, 16 => 64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn buffers_are_word_aligned() {
+        // A leading byte would misalign an unaligned field of the struct.
+        #[allow(dead_code)]
+        struct Packed(u8, Aligned4<16>, u8, Aligned4<8>);
+
+        let mut packed = Packed(0, Aligned4::zeroed(), 0, Aligned4::zeroed());
+        assert_eq!(packed.1.as_ptr() as usize % 4, 0);
+        assert_eq!(packed.3.as_mut_ptr() as usize % 4, 0);
+        assert_eq!(packed.1.as_bytes_mut().as_ptr() as usize % 4, 0);
+        assert_eq!(packed.3.as_byte_array_mut().as_ptr() as usize % 4, 0);
+    }
+
+    #[test]
+    fn byte_conversions_round_trip() {
+        let bytes: [u8; 64] = core::array::from_fn(|i| i as u8);
+        let buffer = Aligned4::<16>::from(bytes);
+        assert_eq!(buffer.to_bytes(), bytes);
+        assert_eq!(buffer.as_bytes(), &bytes[..]);
+        assert_eq!(buffer.0[1], u32::from_ne_bytes([4, 5, 6, 7]));
+
+        let mut buffer = Aligned4::<8>::default();
+        buffer.as_byte_array_mut()[31] = 0xff;
+        assert_eq!(buffer.as_ref()[7], u32::from_ne_bytes([0, 0, 0, 0xff]));
+        buffer.as_mut()[0] = u32::from_ne_bytes([1, 2, 3, 4]);
+        assert_eq!(buffer.to_bytes()[..4], [1, 2, 3, 4]);
+    }
+}
@@ -0,0 +1,216 @@
+//! Off-target dispatch of the precompiles to a replaceable [`SyscallHandler`].
+//!
+//! Outside the VM every syscall wrapper forwards to the handler installed for the current thread
+//! with [`set_syscall_handler`], or to [`DefaultHandler`] if there is none. Tests can install a
+//! handler that records calls or returns canned results, and forward everything else to
+//! [`DefaultHandler`].
+
+use crate::KECCAK_STATE_WORDS;
+#[cfg(feature = "soft")]
+use crate::soft;
+use std::{cell::RefCell, rc::Rc};
+
+/// Runs the software backend when the `soft` feature is enabled, and panics otherwise.
+macro_rules! fallback {
+    ($soft:expr) => {{
+        #[cfg(feature = "soft")]
+        $soft;
+        #[cfg(not(feature = "soft"))]
+        unreachable!()
+    }};
+}
+
+/// The behavior of every precompile outside the VM, with one method per syscall wrapper.
+///
+/// Each method receives the same buffers as its wrapper. The provided methods are those of
+/// [`DefaultHandler`], so an implementation only needs to override the syscalls it cares about.
+#[allow(unused_variables)]
+pub trait SyscallHandler {
+    /// Handles [`syscall_keccak_permute`](crate::syscall_keccak_permute).
+    fn keccak_permute(&self, state: &mut [u64; KECCAK_STATE_WORDS]) {
+        fallback!(soft::keccak_permute(state))
+    }
+
+    /// Handles [`syscall_secp256k1_add`](crate::syscall_secp256k1_add).
+    fn secp256k1_add(&self, p: &mut [u32; 16], q: &[u32; 16]) {
+        fallback!(soft::SECP256K1.add(p, q))
+    }
+
+    /// Handles [`syscall_secp256k1_double`](crate::syscall_secp256k1_double).
+    fn secp256k1_double(&self, p: &mut [u32; 16]) {
+        fallback!(soft::SECP256K1.double(p))
+    }
+
+    /// Handles [`syscall_secp256k1_decompress`](crate::syscall_secp256k1_decompress).
+    fn secp256k1_decompress(&self, point: &mut [u8; 64], is_odd: bool) {
+        fallback!(soft::SECP256K1.decompress(point, is_odd))
+    }
+
+    /// Handles [`syscall_secp256k1_invert`](crate::syscall_secp256k1_invert).
+    fn secp256k1_invert(&self, p: &mut [u32; 8]) {
+        fallback!(soft::secp256k1_scalar_invert(p))
+    }
+
+    /// Handles [`syscall_secp256r1_add`](crate::syscall_secp256r1_add).
+    fn secp256r1_add(&self, p: &mut [u32; 16], q: &[u32; 16]) {
+        fallback!(soft::SECP256R1.add(p, q))
+    }
+
+    /// Handles [`syscall_secp256r1_double`](crate::syscall_secp256r1_double).
+    fn secp256r1_double(&self, p: &mut [u32; 16]) {
+        fallback!(soft::SECP256R1.double(p))
+    }
+
+    /// Handles [`syscall_secp256r1_decompress`](crate::syscall_secp256r1_decompress).
+    fn secp256r1_decompress(&self, point: &mut [u8; 64], is_odd: bool) {
+        fallback!(soft::SECP256R1.decompress(point, is_odd))
+    }
+
+    /// Handles [`syscall_sha256_extend`](crate::syscall_sha256_extend).
+    fn sha256_extend(&self, w: &mut [u32; 64]) {
+        fallback!(soft::sha256_extend(w))
+    }
+
+    /// Handles [`syscall_bn254_add`](crate::syscall_bn254_add).
+    fn bn254_add(&self, p: &mut [u32; 16], q: &[u32; 16]) {
+        fallback!(soft::BN254.add(p, q))
+    }
+
+    /// Handles [`syscall_bn254_double`](crate::syscall_bn254_double).
+    fn bn254_double(&self, p: &mut [u32; 16]) {
+        fallback!(soft::BN254.double(p))
+    }
+
+    /// Handles [`syscall_bn254_fp_addmod`](crate::syscall_bn254_fp_addmod).
+    fn bn254_fp_addmod(&self, x: &mut [u32; 8], y: &[u32; 8]) {
+        fallback!(soft::BN254.field.add(x, y))
+    }
+
+    /// Handles [`syscall_bn254_fp_mulmod`](crate::syscall_bn254_fp_mulmod).
+    fn bn254_fp_mulmod(&self, x: &mut [u32; 8], y: &[u32; 8]) {
+        fallback!(soft::BN254.field.mul(x, y))
+    }
+
+    /// Handles [`syscall_bn254_fp2_addmod`](crate::syscall_bn254_fp2_addmod).
+    fn bn254_fp2_addmod(&self, x: &mut [u32; 16], y: &[u32; 16]) {
+        fallback!(soft::BN254.field.fp2_add(x, y))
+    }
+
+    /// Handles [`syscall_bn254_fp2_mulmod`](crate::syscall_bn254_fp2_mulmod).
+    fn bn254_fp2_mulmod(&self, x: &mut [u32; 16], y: &[u32; 16]) {
+        fallback!(soft::BN254.field.fp2_mul(x, y))
+    }
+
+    /// Handles [`syscall_bls12381_fp_addmod`](crate::syscall_bls12381_fp_addmod).
+    fn bls12381_fp_addmod(&self, x: &mut [u32; 12], y: &[u32; 12]) {
+        fallback!(soft::BLS12381_FP.add(x, y))
+    }
+
+    /// Handles [`syscall_bls12381_fp_mulmod`](crate::syscall_bls12381_fp_mulmod).
+    fn bls12381_fp_mulmod(&self, x: &mut [u32; 12], y: &[u32; 12]) {
+        fallback!(soft::BLS12381_FP.mul(x, y))
+    }
+
+    /// Handles [`syscall_bls12381_fp2_addmod`](crate::syscall_bls12381_fp2_addmod).
+    fn bls12381_fp2_addmod(&self, x: &mut [u32; 24], y: &[u32; 24]) {
+        fallback!(soft::BLS12381_FP.fp2_add(x, y))
+    }
+
+    /// Handles [`syscall_bls12381_fp2_mulmod`](crate::syscall_bls12381_fp2_mulmod).
+    fn bls12381_fp2_mulmod(&self, x: &mut [u32; 24], y: &[u32; 24]) {
+        fallback!(soft::BLS12381_FP.fp2_mul(x, y))
+    }
+
+    /// Handles [`syscall_uint256_mul`](crate::syscall_uint256_mul).
+    fn uint256_mul(&self, x: &mut [u32; 8], y_and_modulus: &[u32; 16]) {
+        fallback!(soft::uint256_mul(x, y_and_modulus))
+    }
+
+    /// Handles [`syscall_phantom_log_pc_cycle`](crate::syscall_phantom_log_pc_cycle).
+    ///
+    /// With the `soft` feature, which has no cycle counter, the marker is dropped.
+    fn phantom_log_pc_cycle(&self, label: &str) {
+        #[cfg(not(feature = "soft"))]
+        unreachable!("syscall_log_pc_cycle should only run inside zkvm");
+    }
+}
+
+/// The handler used when none is installed, running the software backends of the `soft` feature
+/// and panicking without them.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct DefaultHandler;
+
+impl SyscallHandler for DefaultHandler {}
+
+thread_local! {
+    static HANDLER: RefCell<Option<Rc<dyn SyscallHandler>>> = const { RefCell::new(None) };
+}
+
+/// Installs `handler` for the syscalls of the current thread, returning the previous one.
+pub fn set_syscall_handler(handler: Rc<dyn SyscallHandler>) -> Option<Rc<dyn SyscallHandler>> {
+    HANDLER.with(|current| current.replace(Some(handler)))
+}
+
+/// Removes the handler of the current thread, which falls back to [`DefaultHandler`].
+pub fn take_syscall_handler() -> Option<Rc<dyn SyscallHandler>> {
+    HANDLER.with(|current| current.take())
+}
+
+/// Runs `f` with the handler of the current thread.
+///
+/// The handler is cloned out of the thread local first, so it may itself issue syscalls.
+pub(crate) fn dispatch(f: impl FnOnce(&dyn SyscallHandler)) {
+    match HANDLER.with(|current| current.borrow().clone()) {
+        Some(handler) => f(&*handler),
+        None => f(&DefaultHandler),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keccak::keccak256;
+    use std::cell::Cell;
+
+    /// Counts the permutations without computing them.
+    #[derive(Default)]
+    struct CountKeccak {
+        permutations: Cell<usize>,
+    }
+
+    impl SyscallHandler for CountKeccak {
+        fn keccak_permute(&self, _state: &mut [u64; KECCAK_STATE_WORDS]) {
+            self.permutations.set(self.permutations.get() + 1);
+        }
+    }
+
+    #[test]
+    fn keccak256_permutes_once_per_block() {
+        let counter = Rc::new(CountKeccak::default());
+        set_syscall_handler(counter.clone());
+
+        // The rate is 136 bytes, and the padding of a full last block takes another one.
+        for (len, permutations) in [(0, 1), (135, 1), (136, 2), (300, 3), (408, 4)] {
+            counter.permutations.set(0);
+            keccak256(&vec![0xa5; len]);
+            assert_eq!(counter.permutations.get(), permutations, "{len} bytes");
+        }
+        take_syscall_handler();
+    }
+
+    #[test]
+    fn handlers_are_replaced_and_taken() {
+        assert!(take_syscall_handler().is_none());
+        assert!(set_syscall_handler(Rc::new(CountKeccak::default())).is_none());
+        assert!(set_syscall_handler(Rc::new(DefaultHandler)).is_some());
+        assert!(take_syscall_handler().is_some());
+        assert!(take_syscall_handler().is_none());
+    }
+
+    #[test]
+    #[cfg(not(feature = "soft"))]
+    #[should_panic]
+    fn secp256k1_invert_panics_without_soft() {
+        DefaultHandler.secp256k1_invert(&mut [1, 0, 0, 0, 0, 0, 0, 0]);
+    }
+}
@@ -0,0 +1,334 @@
+//! Keccak sponges built on [`syscall_keccak_permute`].
+//!
+//! The same permutation backs Keccak-256 as used by Ethereum, the FIPS 202 SHA3 hashes and the
+//! SHAKE extendable output functions, which only differ in their rate and padding byte.
+
+use crate::{KECCAK_STATE_WORDS, syscall_keccak_permute};
+
+/// The size of the Keccak-f[1600] state in bytes.
+const STATE_BYTES: usize = 8 * KECCAK_STATE_WORDS;
+
+/// A Keccak sponge with a configurable rate and padding byte.
+#[derive(Clone, Debug)]
+pub struct Sponge {
+    state: [u64; KECCAK_STATE_WORDS],
+    rate: usize,
+    pad: u8,
+    offset: usize,
+    squeezing: bool,
+}
+
+impl Sponge {
+    /// Creates a sponge absorbing `rate` bytes per permutation, and padding the message with
+    /// `pad` followed by zeros and a final `0x80` bit.
+    ///
+    /// Panics if `rate` is zero or not smaller than the state.
+    pub fn new(rate: usize, pad: u8) -> Self {
+        assert!(
+            rate > 0 && rate < STATE_BYTES,
+            "rate must be between 1 and 199 bytes"
+        );
+
+        Self {
+            state: [0; KECCAK_STATE_WORDS],
+            rate,
+            pad,
+            offset: 0,
+            squeezing: false,
+        }
+    }
+
+    /// The sponge of Keccak-256, as used by Ethereum.
+    pub fn keccak256() -> Self {
+        Self::new(136, 0x01)
+    }
+
+    /// The sponge of SHA3-256.
+    pub fn sha3_256() -> Self {
+        Self::new(136, 0x06)
+    }
+
+    /// The sponge of SHAKE128.
+    pub fn shake128() -> Self {
+        Self::new(168, 0x1f)
+    }
+
+    /// The sponge of SHAKE256.
+    pub fn shake256() -> Self {
+        Self::new(136, 0x1f)
+    }
+
+    fn xor_byte(&mut self, index: usize, byte: u8) {
+        self.state[index / 8] ^= (byte as u64) << (8 * (index % 8));
+    }
+
+    fn byte(&self, index: usize) -> u8 {
+        (self.state[index / 8] >> (8 * (index % 8))) as u8
+    }
+
+    /// Absorbs `data` into the sponge.
+    ///
+    /// Panics if the sponge has already started squeezing.
+    pub fn absorb(&mut self, data: &[u8]) {
+        assert!(!self.squeezing, "cannot absorb after squeezing");
+
+        for &byte in data {
+            self.xor_byte(self.offset, byte);
+            self.offset += 1;
+            if self.offset == self.rate {
+                syscall_keccak_permute(&mut self.state);
+                self.offset = 0;
+            }
+        }
+    }
+
+    /// Pads the absorbed message and switches the sponge to squeezing.
+    fn pad(&mut self) {
+        self.xor_byte(self.offset, self.pad);
+        self.xor_byte(self.rate - 1, 0x80);
+        syscall_keccak_permute(&mut self.state);
+        self.offset = 0;
+        self.squeezing = true;
+    }
+
+    /// Fills `out` with the next bytes of output.
+    ///
+    /// The first call pads the message, and later calls continue the output stream, so the
+    /// sponge can produce output of any length.
+    pub fn squeeze(&mut self, out: &mut [u8]) {
+        if !self.squeezing {
+            self.pad();
+        }
+
+        for byte in out.iter_mut() {
+            if self.offset == self.rate {
+                syscall_keccak_permute(&mut self.state);
+                self.offset = 0;
+            }
+            *byte = self.byte(self.offset);
+            self.offset += 1;
+        }
+    }
+
+    /// Returns the first 32 bytes of output, as a fixed length digest.
+    pub fn finalize(mut self) -> [u8; 32] {
+        let mut digest = [0u8; 32];
+        self.squeeze(&mut digest);
+        digest
+    }
+}
+
+/// Computes the Keccak-256 digest of `data`.
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    let mut sponge = Sponge::keccak256();
+    sponge.absorb(data);
+    sponge.finalize()
+}
+
+/// Computes the Keccak-256 digest of `left || right`, as hashed by Merkle trees and commitments.
+///
+/// The 64 byte input fits into a single padded block, so this runs exactly one
+/// [`syscall_keccak_permute`] without going through the [`Sponge`]. The digest is the same as
+/// that of [`keccak256`] over the concatenation.
+pub fn keccak256_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut state = absorb_pair(left, right);
+    syscall_keccak_permute(&mut state);
+    squeeze_digest(&state)
+}
+
+/// Computes the SHA3-256 digest of `data`.
+pub fn sha3_256(data: &[u8]) -> [u8; 32] {
+    let mut sponge = Sponge::sha3_256();
+    sponge.absorb(data);
+    sponge.finalize()
+}
+
+/// Fills `out` with the SHAKE128 output of `input`, for any output length.
+pub fn shake128(input: &[u8], out: &mut [u8]) {
+    let mut sponge = Sponge::shake128();
+    sponge.absorb(input);
+    sponge.squeeze(out);
+}
+
+/// Fills `out` with the SHAKE256 output of `input`, for any output length.
+pub fn shake256(input: &[u8], out: &mut [u8]) {
+    let mut sponge = Sponge::shake256();
+    sponge.absorb(input);
+    sponge.squeeze(out);
+}
+
+/// Returns the sponge state after absorbing and padding `left || right` for Keccak-256, ready for
+/// its single permutation.
+pub(crate) fn absorb_pair(left: &[u8; 32], right: &[u8; 32]) -> [u64; KECCAK_STATE_WORDS] {
+    let mut state = [0u64; KECCAK_STATE_WORDS];
+    for (word, chunk) in state
+        .iter_mut()
+        .zip(left.chunks_exact(8).chain(right.chunks_exact(8)))
+    {
+        *word = u64::from_le_bytes(chunk.try_into().unwrap());
+    }
+
+    // The padding byte `0x01` follows the message at byte 64, and the final `0x80` bit ends the
+    // 136 byte rate at byte 135.
+    state[8] ^= 0x01;
+    state[16] ^= 0x80 << 56;
+    state
+}
+
+/// Returns the Keccak-256 digest held by a permuted state.
+pub(crate) fn squeeze_digest(state: &[u64; KECCAK_STATE_WORDS]) -> [u8; 32] {
+    let mut digest = [0u8; 32];
+    for (chunk, word) in digest.chunks_exact_mut(8).zip(state.iter()) {
+        chunk.copy_from_slice(&word.to_le_bytes());
+    }
+    digest
+}
+
+#[cfg(all(test, feature = "soft"))]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+    use sha3::{
+        Digest,
+        digest::{ExtendableOutput, Update, XofReader},
+    };
+
+    #[test]
+    fn nist_vectors() {
+        assert_eq!(
+            sha3_256(b""),
+            hex!("a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a")
+        );
+        assert_eq!(
+            sha3_256(b"abc"),
+            hex!("3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532")
+        );
+
+        let mut digest = [0u8; 32];
+        Sponge::shake128().squeeze(&mut digest);
+        assert_eq!(
+            digest,
+            hex!("7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef26")
+        );
+    }
+
+    #[test]
+    fn keccak256_of_the_empty_string() {
+        assert_eq!(
+            keccak256(b""),
+            hex!("c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470")
+        );
+    }
+
+    #[test]
+    fn digests_match_sha3_around_the_rate() {
+        let data: Vec<u8> = (0..400).map(|i| i as u8).collect();
+        for len in [0, 1, 135, 136, 137, 167, 168, 169, 272, 400] {
+            let data = &data[..len];
+            assert_eq!(
+                keccak256(data),
+                <[u8; 32]>::from(sha3::Keccak256::digest(data))
+            );
+            assert_eq!(
+                sha3_256(data),
+                <[u8; 32]>::from(sha3::Sha3_256::digest(data))
+            );
+
+            let mut sponge = Sponge::sha3_256();
+            for chunk in data.chunks(7) {
+                sponge.absorb(chunk);
+            }
+            assert_eq!(sponge.finalize(), sha3_256(data));
+        }
+    }
+
+    #[test]
+    fn shake_output_matches_sha3_in_pieces() {
+        let data = b"The quick brown fox jumps over the lazy dog";
+
+        let mut expected = [0u8; 500];
+        let mut shake = sha3::Shake128::default();
+        shake.update(data);
+        shake.finalize_xof().read(&mut expected);
+
+        let mut sponge = Sponge::shake128();
+        sponge.absorb(data);
+        let mut actual = [0u8; 500];
+        for chunk in actual.chunks_mut(97) {
+            sponge.squeeze(chunk);
+        }
+        assert_eq!(actual, expected);
+
+        let mut expected = [0u8; 300];
+        let mut shake = sha3::Shake256::default();
+        shake.update(data);
+        shake.finalize_xof().read(&mut expected);
+
+        let mut sponge = Sponge::shake256();
+        sponge.absorb(data);
+        let mut actual = [0u8; 300];
+        sponge.squeeze(&mut actual);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn shake_known_answers() {
+        let mut digest = [0u8; 32];
+        shake128(b"", &mut digest);
+        assert_eq!(
+            digest,
+            hex!("7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef26")
+        );
+        shake256(b"", &mut digest);
+        assert_eq!(
+            digest,
+            hex!("46b9dd2b0ba88d13233b3feb743eeb243fcd52ea62b81b82b50c27646ed5762f")
+        );
+    }
+
+    #[test]
+    fn shake_output_lengths() {
+        let data = b"abc";
+        for len in [0, 1, 31, 135, 136, 137, 167, 168, 169, 1000, 5000] {
+            let mut expected = vec![0u8; len];
+            let mut actual = vec![0u8; len];
+
+            let mut shake = sha3::Shake128::default();
+            shake.update(data);
+            shake.finalize_xof().read(&mut expected);
+            shake128(data, &mut actual);
+            assert_eq!(actual, expected, "SHAKE128 with {len} bytes");
+
+            let mut shake = sha3::Shake256::default();
+            shake.update(data);
+            shake.finalize_xof().read(&mut expected);
+            shake256(data, &mut actual);
+            assert_eq!(actual, expected, "SHAKE256 with {len} bytes");
+        }
+    }
+
+    #[test]
+    fn keccak256_pair_matches_the_concatenation() {
+        // Pseudo-random pairs, each word the hash of the one before.
+        let mut left = [0u8; 32];
+        let mut right = [0xff; 32];
+        for _ in 0..64 {
+            let expected = keccak256(&[left, right].concat());
+            assert_eq!(keccak256_pair(&left, &right), expected);
+            assert_eq!(
+                expected,
+                <[u8; 32]>::from(sha3::Keccak256::digest([left, right].concat()))
+            );
+            left = <[u8; 32]>::from(sha3::Keccak256::digest(right));
+            right = expected;
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot absorb after squeezing")]
+    fn absorb_after_squeeze_panics() {
+        let mut sponge = Sponge::shake128();
+        sponge.squeeze(&mut [0; 8]);
+        sponge.absorb(b"late");
+    }
+}
@@ -1,6 +1,27 @@
 #[cfg(target_os = "zkvm")]
 use core::arch::asm;
 
+/// Buffers satisfying the alignment required by the precompiles.
+pub mod aligned;
+pub use aligned::Aligned4;
+
+/// Keccak-256, SHA3 and SHAKE on top of the Keccak permutation.
+pub mod keccak;
+
+/// Keccak-256 Merkle trees, hashed a level at a time.
+pub mod merkle;
+pub use merkle::merkle_root;
+
+/// Software implementations of the precompiles, selected off-target by the `soft` feature.
+#[cfg(all(not(target_os = "zkvm"), feature = "soft"))]
+mod soft;
+
+/// Off-target dispatch of the precompiles to a replaceable handler.
+#[cfg(not(target_os = "zkvm"))]
+pub mod handler;
+#[cfg(not(target_os = "zkvm"))]
+pub use handler::{DefaultHandler, SyscallHandler, set_syscall_handler, take_syscall_handler};
+
 pub const KECCAK_PERMUTE: u32 = 0x00_01_01_09;
 pub const SECP256K1_ADD: u32 = 0x00_01_01_0A;
 pub const SECP256K1_DOUBLE: u32 = 0x00_00_01_0B;
@@ -17,6 +38,10 @@ pub const BN254_FP2_MUL: u32 = 0x00_01_01_2B;
 pub const BLS12381_DECOMPRESS: u32 = 0x00_00_01_1C;
 pub const BLS12381_ADD: u32 = 0x00_01_01_1E;
 pub const BLS12381_DOUBLE: u32 = 0x00_00_01_1F;
+pub const BLS12381_FP_ADD: u32 = 0x00_01_01_71;
+pub const BLS12381_FP_MUL: u32 = 0x00_01_01_73;
+pub const BLS12381_FP2_ADD: u32 = 0x00_01_01_77;
+pub const BLS12381_FP2_MUL: u32 = 0x00_01_01_79;
 pub const SECP256R1_ADD: u32 = 0x00_01_01_2C;
 pub const SECP256R1_DOUBLE: u32 = 0x00_00_01_2D;
 pub const SECP256R1_DECOMPRESS: u32 = 0x00_00_01_2E;
@@ -24,8 +49,91 @@ pub const UINT256_MUL: u32 = 0x00_01_01_1D;
 
 pub const PHANTOM_LOG_PC_CYCLE: u32 = 0x00_00_00_03;
 
+/// The syscalls understood by the VM, with the codes above as discriminants.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[repr(u32)]
+pub enum Syscall {
+    KeccakPermute = KECCAK_PERMUTE,
+    Secp256k1Add = SECP256K1_ADD,
+    Secp256k1Double = SECP256K1_DOUBLE,
+    Secp256k1Decompress = SECP256K1_DECOMPRESS,
+    Secp256k1ScalarSqrt = SECP256K1_SCALAR_SQRT,
+    Secp256k1ScalarInvert = SECP256K1_SCALAR_INVERT,
+    ShaExtend = SHA_EXTEND,
+    Bn254Add = BN254_ADD,
+    Bn254Double = BN254_DOUBLE,
+    Bn254FpAdd = BN254_FP_ADD,
+    Bn254FpMul = BN254_FP_MUL,
+    Bn254Fp2Add = BN254_FP2_ADD,
+    Bn254Fp2Mul = BN254_FP2_MUL,
+    Bls12381Decompress = BLS12381_DECOMPRESS,
+    Bls12381Add = BLS12381_ADD,
+    Bls12381Double = BLS12381_DOUBLE,
+    Bls12381FpAdd = BLS12381_FP_ADD,
+    Bls12381FpMul = BLS12381_FP_MUL,
+    Bls12381Fp2Add = BLS12381_FP2_ADD,
+    Bls12381Fp2Mul = BLS12381_FP2_MUL,
+    Secp256r1Add = SECP256R1_ADD,
+    Secp256r1Double = SECP256R1_DOUBLE,
+    Secp256r1Decompress = SECP256R1_DECOMPRESS,
+    Uint256Mul = UINT256_MUL,
+    PhantomLogPcCycle = PHANTOM_LOG_PC_CYCLE,
+}
+
+impl Syscall {
+    /// Every syscall, in the order of their declaration.
+    pub const ALL: [Syscall; 25] = [
+        Self::KeccakPermute,
+        Self::Secp256k1Add,
+        Self::Secp256k1Double,
+        Self::Secp256k1Decompress,
+        Self::Secp256k1ScalarSqrt,
+        Self::Secp256k1ScalarInvert,
+        Self::ShaExtend,
+        Self::Bn254Add,
+        Self::Bn254Double,
+        Self::Bn254FpAdd,
+        Self::Bn254FpMul,
+        Self::Bn254Fp2Add,
+        Self::Bn254Fp2Mul,
+        Self::Bls12381Decompress,
+        Self::Bls12381Add,
+        Self::Bls12381Double,
+        Self::Bls12381FpAdd,
+        Self::Bls12381FpMul,
+        Self::Bls12381Fp2Add,
+        Self::Bls12381Fp2Mul,
+        Self::Secp256r1Add,
+        Self::Secp256r1Double,
+        Self::Secp256r1Decompress,
+        Self::Uint256Mul,
+        Self::PhantomLogPcCycle,
+    ];
+
+    /// Returns the code passed in `t0` to invoke this syscall.
+    pub const fn code(self) -> u32 {
+        self as u32
+    }
+
+    /// Returns the syscall with the given code, if there is one.
+    pub fn from_code(code: u32) -> Option<Self> {
+        Self::ALL.into_iter().find(|syscall| syscall.code() == code)
+    }
+}
+
 pub const KECCAK_STATE_WORDS: usize = 25;
 
+/// Asserts in debug builds that each pointer is aligned along a four byte boundary, as required
+/// by every precompile.
+macro_rules! debug_assert_aligned {
+    ($($ptr:expr),+ $(,)?) => {
+        $(debug_assert!(
+            ($ptr as usize) % 4 == 0,
+            concat!("`", stringify!($ptr), "` must be aligned along a four byte boundary"),
+        );)+
+    };
+}
+
 /// Based on https://github.com/succinctlabs/sp1/blob/013c24ea2fa15a0e7ed94f7d11a7ada4baa39ab9/crates/zkvm/entrypoint/src/syscalls/keccak_permute.rs
 /// Executes the Keccak256 permutation on the given state.
 ///
@@ -35,6 +143,8 @@ pub const KECCAK_STATE_WORDS: usize = 25;
 ///   byte boundary.
 #[allow(unused_variables)]
 pub fn syscall_keccak_permute(state: &mut [u64; KECCAK_STATE_WORDS]) {
+    debug_assert_aligned!(state.as_ptr());
+
     #[cfg(target_os = "zkvm")]
     unsafe {
         asm!(
@@ -44,7 +154,22 @@ pub fn syscall_keccak_permute(state: &mut [u64; KECCAK_STATE_WORDS]) {
         );
     }
     #[cfg(not(target_os = "zkvm"))]
-    unreachable!()
+    handler::dispatch(|handler| handler.keccak_permute(state));
+}
+
+/// Executes the Keccak256 permutation on each of the given states.
+///
+/// The VM has no batched precompile, so this is a convenience that issues one
+/// [`syscall_keccak_permute`] per state.
+///
+/// ### Spec
+///
+/// - The caller must ensure that `states` is valid pointer to contiguous data that is aligned
+///   along a four byte boundary.
+pub fn syscall_keccak_permute_batch(states: &mut [[u64; KECCAK_STATE_WORDS]]) {
+    for state in states.iter_mut() {
+        syscall_keccak_permute(state);
+    }
 }
 
 /// Based on https://github.com/succinctlabs/sp1/blob/dbe622aa4a6a33c88d76298c2a29a1d7ef7e90df/crates/zkvm/entrypoint/src/syscalls/secp256k1.rs
@@ -60,6 +185,8 @@ pub fn syscall_keccak_permute(state: &mut [u64; KECCAK_STATE_WORDS]) {
 /// - The result is stored in the first point.
 #[allow(unused_variables)]
 pub fn syscall_secp256k1_add(p: &mut [u32; 16], q: &[u32; 16]) {
+    debug_assert_aligned!(p.as_ptr(), q.as_ptr());
+
     #[cfg(target_os = "zkvm")]
     unsafe {
         let p = p.as_mut_ptr();
@@ -73,7 +200,7 @@ pub fn syscall_secp256k1_add(p: &mut [u32; 16], q: &[u32; 16]) {
     }
 
     #[cfg(not(target_os = "zkvm"))]
-    unreachable!()
+    handler::dispatch(|handler| handler.secp256k1_add(p, q));
 }
 
 /// Based on: https://github.com/succinctlabs/sp1/blob/dbe622aa4a6a33c88d76298c2a29a1d7ef7e90df/crates/zkvm/entrypoint/src/syscalls/secp256k1.rs
@@ -87,6 +214,8 @@ pub fn syscall_secp256k1_add(p: &mut [u32; 16], q: &[u32; 16]) {
 /// - The result is stored in p
 #[allow(unused_variables)]
 pub fn syscall_secp256k1_double(p: &mut [u32; 16]) {
+    debug_assert_aligned!(p.as_ptr());
+
     #[cfg(target_os = "zkvm")]
     unsafe {
         let p = p.as_mut_ptr();
@@ -98,7 +227,7 @@ pub fn syscall_secp256k1_double(p: &mut [u32; 16]) {
     }
 
     #[cfg(not(target_os = "zkvm"))]
-    unreachable!()
+    handler::dispatch(|handler| handler.secp256k1_double(p));
 }
 
 /// Decompresses a compressed Secp256k1 point.
@@ -113,6 +242,8 @@ pub fn syscall_secp256k1_double(p: &mut [u32; 16]) {
 ///   boundary.
 #[allow(unused_variables)]
 pub fn syscall_secp256k1_decompress(point: &mut [u8; 64], is_odd: bool) {
+    debug_assert_aligned!(point.as_ptr());
+
     #[cfg(target_os = "zkvm")]
     {
         let p = point.as_mut_ptr();
@@ -127,11 +258,92 @@ pub fn syscall_secp256k1_decompress(point: &mut [u8; 64], is_odd: bool) {
     }
 
     #[cfg(not(target_os = "zkvm"))]
-    unreachable!()
+    handler::dispatch(|handler| handler.secp256k1_decompress(point, is_odd));
+}
+
+/// Adds two Secp256r1 points.
+///
+/// ### Spec
+/// - The caller must ensure that `p` and `q` are valid pointers to data that is aligned along a four
+///   byte boundary.
+/// - Point representation: the same as for [`syscall_secp256k1_add`].
+/// - The caller must ensure that `p` and `q` are valid points on the `secp256r1` curve, and that `p` and `q` are not equal to each other.
+/// - The result is stored in the first point.
+#[allow(unused_variables)]
+pub fn syscall_secp256r1_add(p: &mut [u32; 16], q: &[u32; 16]) {
+    debug_assert_aligned!(p.as_ptr(), q.as_ptr());
+
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        let p = p.as_mut_ptr();
+        let q = q.as_ptr();
+        asm!(
+        "ecall",
+        in("t0") SECP256R1_ADD,
+        in("a0") p,
+        in("a1") q
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    handler::dispatch(|handler| handler.secp256r1_add(p, q));
+}
+
+/// Double a Secp256r1 point.
+///
+/// ### Spec
+/// - The caller must ensure that `p` is a valid pointer to data that is aligned along a four byte boundary.
+/// - Point representation: the same as for [`syscall_secp256k1_double`].
+/// - The result is stored in p
+#[allow(unused_variables)]
+pub fn syscall_secp256r1_double(p: &mut [u32; 16]) {
+    debug_assert_aligned!(p.as_ptr());
+
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        let p = p.as_mut_ptr();
+        asm!(
+        "ecall",
+        in("t0") SECP256R1_DOUBLE,
+        in("a0") p
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    handler::dispatch(|handler| handler.secp256r1_double(p));
+}
+
+/// Decompresses a compressed Secp256r1 point.
+///
+/// ### Spec
+/// - The input layout is the same as for [`syscall_secp256k1_decompress`].
+/// - The caller must ensure that `point` is valid pointer to data that is aligned along a four byte
+///   boundary.
+#[allow(unused_variables)]
+pub fn syscall_secp256r1_decompress(point: &mut [u8; 64], is_odd: bool) {
+    debug_assert_aligned!(point.as_ptr());
+
+    #[cfg(target_os = "zkvm")]
+    {
+        let p = point.as_mut_ptr();
+        unsafe {
+            asm!(
+            "ecall",
+            in("t0") SECP256R1_DECOMPRESS,
+            in("a0") p,
+            in("a1") is_odd as u8
+            );
+        }
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    handler::dispatch(|handler| handler.secp256r1_decompress(point, is_odd));
 }
 
 #[allow(unused_variables)]
 pub fn syscall_secp256k1_invert(p: &mut [u32; 8]) {
+    debug_assert_aligned!(p.as_ptr());
+
     #[cfg(target_os = "zkvm")]
     {
         let p = p.as_mut_ptr();
@@ -143,12 +355,17 @@ pub fn syscall_secp256k1_invert(p: &mut [u32; 8]) {
             );
         }
     }
+
+    #[cfg(not(target_os = "zkvm"))]
+    handler::dispatch(|handler| handler.secp256k1_invert(p));
 }
 
 /// Based on: https://github.com/succinctlabs/sp1/blob/2aed8fea16a67a5b2983ffc471b2942c2f2512c8/crates/zkvm/entrypoint/src/syscalls/sha_extend.rs#L12
 /// Executes the SHA256 extend operation on the given word array.
 #[allow(unused_variables)]
 pub fn syscall_sha256_extend(w: &mut [u32; 64]) {
+    debug_assert_aligned!(w.as_ptr());
+
     #[cfg(target_os = "zkvm")]
     {
         let w = w.as_mut_ptr();
@@ -162,7 +379,7 @@ pub fn syscall_sha256_extend(w: &mut [u32; 64]) {
     }
 
     #[cfg(not(target_os = "zkvm"))]
-    unreachable!()
+    handler::dispatch(|handler| handler.sha256_extend(w));
 }
 
 /// Adds two Bn254 points.
@@ -176,6 +393,8 @@ pub fn syscall_sha256_extend(w: &mut [u32; 64]) {
 #[allow(unused_variables)]
 #[unsafe(no_mangle)]
 pub extern "C" fn syscall_bn254_add(p: &mut [u32; 16], q: &[u32; 16]) {
+    debug_assert_aligned!(p.as_ptr(), q.as_ptr());
+
     #[cfg(target_os = "zkvm")]
     {
         let p = p.as_mut_ptr();
@@ -191,7 +410,7 @@ pub extern "C" fn syscall_bn254_add(p: &mut [u32; 16], q: &[u32; 16]) {
     }
 
     #[cfg(not(target_os = "zkvm"))]
-    unreachable!()
+    handler::dispatch(|handler| handler.bn254_add(p, q));
 }
 
 /// Double a Bn254 point.
@@ -205,6 +424,8 @@ pub extern "C" fn syscall_bn254_add(p: &mut [u32; 16], q: &[u32; 16]) {
 #[allow(unused_variables)]
 #[unsafe(no_mangle)]
 pub extern "C" fn syscall_bn254_double(p: &mut [u32; 16]) {
+    debug_assert_aligned!(p.as_ptr());
+
     #[cfg(target_os = "zkvm")]
     {
         let p = p.as_mut_ptr();
@@ -219,7 +440,7 @@ pub extern "C" fn syscall_bn254_double(p: &mut [u32; 16]) {
     }
 
     #[cfg(not(target_os = "zkvm"))]
-    unreachable!()
+    handler::dispatch(|handler| handler.bn254_double(p));
 }
 
 /// Fp addition operation.
@@ -228,6 +449,8 @@ pub extern "C" fn syscall_bn254_double(p: &mut [u32; 16]) {
 #[allow(unused_variables)]
 #[unsafe(no_mangle)]
 pub extern "C" fn syscall_bn254_fp_addmod(x: &mut [u32; 8], y: &[u32; 8]) {
+    debug_assert_aligned!(x.as_ptr(), y.as_ptr());
+
     #[cfg(target_os = "zkvm")]
     {
         let x = x.as_mut_ptr();
@@ -243,7 +466,7 @@ pub extern "C" fn syscall_bn254_fp_addmod(x: &mut [u32; 8], y: &[u32; 8]) {
     }
 
     #[cfg(not(target_os = "zkvm"))]
-    unreachable!()
+    handler::dispatch(|handler| handler.bn254_fp_addmod(x, y));
 }
 
 /// Fp multiplication operation.
@@ -252,6 +475,8 @@ pub extern "C" fn syscall_bn254_fp_addmod(x: &mut [u32; 8], y: &[u32; 8]) {
 #[allow(unused_variables)]
 #[unsafe(no_mangle)]
 pub extern "C" fn syscall_bn254_fp_mulmod(x: &mut [u32; 8], y: &[u32; 8]) {
+    debug_assert_aligned!(x.as_ptr(), y.as_ptr());
+
     #[cfg(target_os = "zkvm")]
     {
         let x = x.as_mut_ptr();
@@ -267,7 +492,7 @@ pub extern "C" fn syscall_bn254_fp_mulmod(x: &mut [u32; 8], y: &[u32; 8]) {
     }
 
     #[cfg(not(target_os = "zkvm"))]
-    unreachable!()
+    handler::dispatch(|handler| handler.bn254_fp_mulmod(x, y));
 }
 
 /// BN254 Fp2 addition operation.
@@ -276,6 +501,8 @@ pub extern "C" fn syscall_bn254_fp_mulmod(x: &mut [u32; 8], y: &[u32; 8]) {
 #[allow(unused_variables)]
 #[unsafe(no_mangle)]
 pub extern "C" fn syscall_bn254_fp2_addmod(x: &mut [u32; 16], y: &[u32; 16]) {
+    debug_assert_aligned!(x.as_ptr(), y.as_ptr());
+
     #[cfg(target_os = "zkvm")]
     {
         let x = x.as_mut_ptr();
@@ -291,7 +518,7 @@ pub extern "C" fn syscall_bn254_fp2_addmod(x: &mut [u32; 16], y: &[u32; 16]) {
     }
 
     #[cfg(not(target_os = "zkvm"))]
-    unreachable!()
+    handler::dispatch(|handler| handler.bn254_fp2_addmod(x, y));
 }
 
 /// BN254 Fp2 multiplication operation.
@@ -300,6 +527,8 @@ pub extern "C" fn syscall_bn254_fp2_addmod(x: &mut [u32; 16], y: &[u32; 16]) {
 #[allow(unused_variables)]
 #[unsafe(no_mangle)]
 pub extern "C" fn syscall_bn254_fp2_mulmod(x: &mut [u32; 16], y: &[u32; 16]) {
+    debug_assert_aligned!(x.as_ptr(), y.as_ptr());
+
     #[cfg(target_os = "zkvm")]
     {
         let x = x.as_mut_ptr();
@@ -315,15 +544,123 @@ pub extern "C" fn syscall_bn254_fp2_mulmod(x: &mut [u32; 16], y: &[u32; 16]) {
     }
 
     #[cfg(not(target_os = "zkvm"))]
-    unreachable!()
+    handler::dispatch(|handler| handler.bn254_fp2_mulmod(x, y));
+}
+
+/// BLS12-381 Fp addition operation.
+///
+/// The result is written over the first input.
+#[allow(unused_variables)]
+#[unsafe(no_mangle)]
+pub extern "C" fn syscall_bls12381_fp_addmod(x: &mut [u32; 12], y: &[u32; 12]) {
+    debug_assert_aligned!(x.as_ptr(), y.as_ptr());
+
+    #[cfg(target_os = "zkvm")]
+    {
+        let x = x.as_mut_ptr();
+        let y = y.as_ptr();
+        unsafe {
+            asm!(
+            "ecall",
+            in("t0") BLS12381_FP_ADD,
+            in("a0") x,
+            in("a1") y,
+            );
+        }
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    handler::dispatch(|handler| handler.bls12381_fp_addmod(x, y));
+}
+
+/// BLS12-381 Fp multiplication operation.
+///
+/// The result is written over the first input.
+#[allow(unused_variables)]
+#[unsafe(no_mangle)]
+pub extern "C" fn syscall_bls12381_fp_mulmod(x: &mut [u32; 12], y: &[u32; 12]) {
+    debug_assert_aligned!(x.as_ptr(), y.as_ptr());
+
+    #[cfg(target_os = "zkvm")]
+    {
+        let x = x.as_mut_ptr();
+        let y = y.as_ptr();
+        unsafe {
+            asm!(
+            "ecall",
+            in("t0") BLS12381_FP_MUL,
+            in("a0") x,
+            in("a1") y,
+            );
+        }
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    handler::dispatch(|handler| handler.bls12381_fp_mulmod(x, y));
+}
+
+/// BLS12-381 Fp2 addition operation.
+///
+/// The result is written over the first input.
+#[allow(unused_variables)]
+#[unsafe(no_mangle)]
+pub extern "C" fn syscall_bls12381_fp2_addmod(x: &mut [u32; 24], y: &[u32; 24]) {
+    debug_assert_aligned!(x.as_ptr(), y.as_ptr());
+
+    #[cfg(target_os = "zkvm")]
+    {
+        let x = x.as_mut_ptr();
+        let y = y.as_ptr();
+        unsafe {
+            asm!(
+            "ecall",
+            in("t0") BLS12381_FP2_ADD,
+            in("a0") x,
+            in("a1") y,
+            );
+        }
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    handler::dispatch(|handler| handler.bls12381_fp2_addmod(x, y));
+}
+
+/// BLS12-381 Fp2 multiplication operation.
+///
+/// The result is written over the first input.
+#[allow(unused_variables)]
+#[unsafe(no_mangle)]
+pub extern "C" fn syscall_bls12381_fp2_mulmod(x: &mut [u32; 24], y: &[u32; 24]) {
+    debug_assert_aligned!(x.as_ptr(), y.as_ptr());
+
+    #[cfg(target_os = "zkvm")]
+    {
+        let x = x.as_mut_ptr();
+        let y = y.as_ptr();
+        unsafe {
+            asm!(
+            "ecall",
+            in("t0") BLS12381_FP2_MUL,
+            in("a0") x,
+            in("a1") y,
+            );
+        }
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    handler::dispatch(|handler| handler.bls12381_fp2_mulmod(x, y));
 }
 
 /// Uint256 multiplication operation.
 ///
+/// Computes `x * y mod modulus`, where `y_and_modulus` holds the little endian words of `y`
+/// followed by those of `modulus`. A zero modulus computes the wrapping product `x * y mod 2^256`.
 /// The result is written over the first input.
 #[allow(unused_variables)]
 #[unsafe(no_mangle)]
 pub extern "C" fn syscall_uint256_mul(x: &mut [u32; 8], y_and_modulus: &[u32; 16]) {
+    debug_assert_aligned!(x.as_ptr(), y_and_modulus.as_ptr());
+
     #[cfg(target_os = "zkvm")]
     {
         let x = x.as_mut_ptr();
@@ -339,10 +676,11 @@ pub extern "C" fn syscall_uint256_mul(x: &mut [u32; 8], y_and_modulus: &[u32; 16
     }
 
     #[cfg(not(target_os = "zkvm"))]
-    unreachable!()
+    handler::dispatch(|handler| handler.uint256_mul(x, y_and_modulus));
 }
 
 /// phantom syscall
+#[allow(unused_variables)]
 pub fn syscall_phantom_log_pc_cycle(label: &str) {
     #[cfg(target_os = "zkvm")]
     unsafe {
@@ -358,5 +696,96 @@ pub fn syscall_phantom_log_pc_cycle(label: &str) {
     }
 
     #[cfg(not(target_os = "zkvm"))]
-    unreachable!("syscall_log_pc_cycle should only run inside zkvm");
+    handler::dispatch(|handler| handler.phantom_log_pc_cycle(label));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn syscall_codes_round_trip() {
+        for syscall in Syscall::ALL {
+            assert_eq!(Syscall::from_code(syscall.code()), Some(syscall));
+        }
+
+        let mut codes: Vec<u32> = Syscall::ALL.iter().map(|syscall| syscall.code()).collect();
+        codes.sort_unstable();
+        codes.dedup();
+        assert_eq!(codes.len(), Syscall::ALL.len(), "codes must be distinct");
+
+        assert_eq!(Syscall::from_code(0), None);
+        assert_eq!(Syscall::from_code(u32::MAX), None);
+    }
+
+    #[test]
+    fn syscall_codes_match_the_constants() {
+        let expected = [
+            (Syscall::KeccakPermute, KECCAK_PERMUTE),
+            (Syscall::Secp256k1Add, SECP256K1_ADD),
+            (Syscall::Secp256k1Double, SECP256K1_DOUBLE),
+            (Syscall::Secp256k1Decompress, SECP256K1_DECOMPRESS),
+            (Syscall::Secp256k1ScalarSqrt, SECP256K1_SCALAR_SQRT),
+            (Syscall::Secp256k1ScalarInvert, SECP256K1_SCALAR_INVERT),
+            (Syscall::ShaExtend, SHA_EXTEND),
+            (Syscall::Bn254Add, BN254_ADD),
+            (Syscall::Bn254Double, BN254_DOUBLE),
+            (Syscall::Bn254FpAdd, BN254_FP_ADD),
+            (Syscall::Bn254FpMul, BN254_FP_MUL),
+            (Syscall::Bn254Fp2Add, BN254_FP2_ADD),
+            (Syscall::Bn254Fp2Mul, BN254_FP2_MUL),
+            (Syscall::Bls12381Decompress, BLS12381_DECOMPRESS),
+            (Syscall::Bls12381Add, BLS12381_ADD),
+            (Syscall::Bls12381Double, BLS12381_DOUBLE),
+            (Syscall::Bls12381FpAdd, BLS12381_FP_ADD),
+            (Syscall::Bls12381FpMul, BLS12381_FP_MUL),
+            (Syscall::Bls12381Fp2Add, BLS12381_FP2_ADD),
+            (Syscall::Bls12381Fp2Mul, BLS12381_FP2_MUL),
+            (Syscall::Secp256r1Add, SECP256R1_ADD),
+            (Syscall::Secp256r1Double, SECP256R1_DOUBLE),
+            (Syscall::Secp256r1Decompress, SECP256R1_DECOMPRESS),
+            (Syscall::Uint256Mul, UINT256_MUL),
+            (Syscall::PhantomLogPcCycle, PHANTOM_LOG_PC_CYCLE),
+        ];
+        assert_eq!(expected.len(), Syscall::ALL.len());
+        for (syscall, code) in expected {
+            assert_eq!(syscall.code(), code);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "soft")]
+    fn keccak_permute_batch_matches_single_permutations() {
+        let mut states: Vec<[u64; KECCAK_STATE_WORDS]> = (0..5u64)
+            .map(|i| core::array::from_fn(|j| i * 0x0101_0101 + j as u64))
+            .collect();
+        let mut expected = states.clone();
+        for state in expected.iter_mut() {
+            syscall_keccak_permute(state);
+        }
+
+        syscall_keccak_permute_batch(&mut states);
+        assert_eq!(states, expected);
+
+        syscall_keccak_permute_batch(&mut []);
+    }
+
+    /// Returns a 64 byte buffer that starts one byte past a four byte boundary.
+    fn misaligned(buffer: &mut Aligned4<17>) -> &mut [u8; 64] {
+        (&mut buffer.as_bytes_mut()[1..65]).try_into().unwrap()
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "must be aligned along a four byte boundary")]
+    fn misaligned_secp256k1_decompress_input_panics() {
+        syscall_secp256k1_decompress(misaligned(&mut Aligned4::zeroed()), false);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "must be aligned along a four byte boundary")]
+    fn misaligned_secp256r1_decompress_input_panics() {
+        syscall_secp256r1_decompress(misaligned(&mut Aligned4::zeroed()), true);
+    }
 }
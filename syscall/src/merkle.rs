@@ -0,0 +1,122 @@
+//! Keccak-256 Merkle trees, hashing every level of the tree with [`syscall_keccak_permute_batch`].
+//!
+//! A parent is the Keccak-256 digest of its two children `left || right`. The 64 byte input fits
+//! into a single block of the sponge, so each parent costs exactly one permutation.
+
+use crate::{
+    keccak::{absorb_pair, squeeze_digest},
+    syscall_keccak_permute_batch,
+};
+
+/// How a level with an odd number of nodes is handled.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum OddNode {
+    /// The last node is paired with itself, as in Bitcoin's transaction trees.
+    Duplicate,
+    /// The last node is moved up to the next level unchanged.
+    CarryUp,
+}
+
+/// Computes the root of the Merkle tree over `leaves`, with `odd` deciding how levels with an odd
+/// number of nodes are handled.
+///
+/// A single leaf is its own root, and an empty tree has the all zero root.
+pub fn merkle_root(leaves: &[[u8; 32]], odd: OddNode) -> [u8; 32] {
+    if leaves.is_empty() {
+        return [0; 32];
+    }
+
+    let mut level = leaves.to_vec();
+    let mut states = Vec::with_capacity(level.len().div_ceil(2));
+    while level.len() > 1 {
+        let carried = match (level.len() % 2, odd) {
+            (0, _) => None,
+            (_, OddNode::Duplicate) => {
+                level.push(*level.last().unwrap());
+                None
+            }
+            (_, OddNode::CarryUp) => level.pop(),
+        };
+
+        states.clear();
+        states.extend(
+            level
+                .chunks_exact(2)
+                .map(|pair| absorb_pair(&pair[0], &pair[1])),
+        );
+        syscall_keccak_permute_batch(&mut states);
+
+        level.clear();
+        level.extend(states.iter().map(squeeze_digest));
+        level.extend(carried);
+    }
+    level[0]
+}
+
+#[cfg(all(test, feature = "soft"))]
+mod tests {
+    use super::*;
+    use sha3::{Digest, Keccak256};
+
+    fn hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+        Keccak256::new()
+            .chain_update(left)
+            .chain_update(right)
+            .finalize()
+            .into()
+    }
+
+    /// Computes the root one pair at a time with the `sha3` crate.
+    fn reference_root(leaves: &[[u8; 32]], odd: OddNode) -> [u8; 32] {
+        match leaves {
+            [] => [0; 32],
+            [leaf] => *leaf,
+            _ => {
+                let parents: Vec<_> = leaves
+                    .chunks(2)
+                    .map(|pair| match (pair, odd) {
+                        ([left, right], _) => hash(left, right),
+                        ([last], OddNode::Duplicate) => hash(last, last),
+                        ([last], OddNode::CarryUp) => *last,
+                        _ => unreachable!(),
+                    })
+                    .collect();
+                reference_root(&parents, odd)
+            }
+        }
+    }
+
+    fn leaves(count: u8) -> Vec<[u8; 32]> {
+        (0..count).map(|i| [i; 32]).collect()
+    }
+
+    #[test]
+    fn small_trees() {
+        let [a, b, c] = [[1; 32], [2; 32], [3; 32]];
+        assert_eq!(merkle_root(&[], OddNode::Duplicate), [0; 32]);
+        assert_eq!(merkle_root(&[a], OddNode::CarryUp), a);
+        assert_eq!(merkle_root(&[a, b], OddNode::CarryUp), hash(&a, &b));
+        assert_eq!(
+            merkle_root(&[a, b, c], OddNode::Duplicate),
+            hash(&hash(&a, &b), &hash(&c, &c))
+        );
+        assert_eq!(
+            merkle_root(&[a, b, c], OddNode::CarryUp),
+            hash(&hash(&a, &b), &c)
+        );
+    }
+
+    #[test]
+    fn roots_match_the_reference() {
+        for count in [2, 5, 6, 7, 8, 13, 32, 33] {
+            for odd in [OddNode::Duplicate, OddNode::CarryUp] {
+                let leaves = leaves(count);
+                assert_eq!(
+                    merkle_root(&leaves, odd),
+                    reference_root(&leaves, odd),
+                    "{count} leaves with {odd:?}"
+                );
+            }
+        }
+    }
+}
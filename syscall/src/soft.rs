@@ -0,0 +1,554 @@
+//! Software implementations of the precompiles, used off-target when the `soft` feature is enabled.
+//!
+//! Every function here mirrors the semantics of the corresponding VM precompile on the same
+//! buffers, so code built on top of the syscall wrappers runs unchanged on the host. None of this
+//! is constant time, and it is only meant for testing.
+
+use crypto_bigint::{
+    U256, U384, Uint,
+    modular::runtime_mod::{DynResidue, DynResidueParams},
+};
+
+/// Parses little endian words into an integer of the same width.
+fn to_uint<const LIMBS: usize>(words: &[u32]) -> Uint<LIMBS> {
+    let bytes: Vec<u8> = words.iter().flat_map(|word| word.to_le_bytes()).collect();
+    Uint::from_le_slice(&bytes)
+}
+
+/// Writes an integer into little endian words of the same width.
+fn from_uint<const LIMBS: usize>(value: &Uint<LIMBS>, words: &mut [u32]) {
+    let bytes: Vec<u8> = value
+        .as_limbs()
+        .iter()
+        .flat_map(|limb| limb.0.to_le_bytes())
+        .collect();
+    for (word, chunk) in words.iter_mut().zip(bytes.chunks_exact(4)) {
+        *word = u32::from_le_bytes(chunk.try_into().unwrap());
+    }
+}
+
+/// A prime field given by its modulus.
+#[derive(Copy, Clone)]
+pub(crate) struct PrimeField<const LIMBS: usize> {
+    params: DynResidueParams<LIMBS>,
+}
+
+impl<const LIMBS: usize> PrimeField<LIMBS> {
+    const fn new(modulus: &Uint<LIMBS>) -> Self {
+        Self {
+            params: DynResidueParams::new(modulus),
+        }
+    }
+
+    fn element(&self, words: &[u32]) -> DynResidue<LIMBS> {
+        DynResidue::new(&to_uint(words), self.params)
+    }
+
+    fn store(element: &DynResidue<LIMBS>, words: &mut [u32]) {
+        from_uint(&element.retrieve(), words)
+    }
+
+    fn invert(element: &DynResidue<LIMBS>) -> DynResidue<LIMBS> {
+        element.invert().0
+    }
+
+    /// Computes a square root, using that every supported modulus is `3 mod 4`.
+    fn sqrt(&self, element: &DynResidue<LIMBS>) -> DynResidue<LIMBS> {
+        let exp = self
+            .params
+            .modulus()
+            .wrapping_add(&Uint::ONE)
+            .shr_vartime(2);
+        element.pow(&exp)
+    }
+
+    /// Computes `x + y` over little endian words.
+    pub(crate) fn add(&self, x: &mut [u32], y: &[u32]) {
+        let res = self.element(x) + self.element(y);
+        Self::store(&res, x);
+    }
+
+    /// Computes `x * y` over little endian words.
+    pub(crate) fn mul(&self, x: &mut [u32], y: &[u32]) {
+        let res = self.element(x) * self.element(y);
+        Self::store(&res, x);
+    }
+
+    /// Computes `x + y` over the quadratic extension `c0 + c1 u`, with `u^2 = -1`.
+    pub(crate) fn fp2_add(&self, x: &mut [u32], y: &[u32]) {
+        let (x0, x1) = x.split_at_mut(x.len() / 2);
+        let (y0, y1) = y.split_at(y.len() / 2);
+        self.add(x0, y0);
+        self.add(x1, y1);
+    }
+
+    /// Computes `x * y` over the quadratic extension `c0 + c1 u`, with `u^2 = -1`.
+    pub(crate) fn fp2_mul(&self, x: &mut [u32], y: &[u32]) {
+        let half = x.len() / 2;
+        let (a0, a1) = (self.element(&x[..half]), self.element(&x[half..]));
+        let (b0, b1) = (self.element(&y[..half]), self.element(&y[half..]));
+
+        let c0 = a0 * b0 - a1 * b1;
+        let c1 = a0 * b1 + a1 * b0;
+        Self::store(&c0, &mut x[..half]);
+        Self::store(&c1, &mut x[half..]);
+    }
+}
+
+/// A short Weierstrass curve `y^2 = x^3 + a x + b`, with points laid out as the little endian
+/// words of `X` followed by those of `Y`.
+#[derive(Copy, Clone)]
+pub(crate) struct WeierstrassCurve<const LIMBS: usize> {
+    pub(crate) field: PrimeField<LIMBS>,
+    a: Uint<LIMBS>,
+    b: Uint<LIMBS>,
+}
+
+impl<const LIMBS: usize> WeierstrassCurve<LIMBS> {
+    fn coordinates(&self, point: &[u32]) -> (DynResidue<LIMBS>, DynResidue<LIMBS>) {
+        let (x, y) = point.split_at(point.len() / 2);
+        (self.field.element(x), self.field.element(y))
+    }
+
+    fn store(point: &mut [u32], x: &DynResidue<LIMBS>, y: &DynResidue<LIMBS>) {
+        let (x_words, y_words) = point.split_at_mut(point.len() / 2);
+        PrimeField::store(x, x_words);
+        PrimeField::store(y, y_words);
+    }
+
+    /// Computes `p + q`, for points that are neither equal nor opposite.
+    pub(crate) fn add(&self, p: &mut [u32], q: &[u32]) {
+        let (x1, y1) = self.coordinates(p);
+        let (x2, y2) = self.coordinates(q);
+
+        let lambda = (y2 - y1) * PrimeField::invert(&(x2 - x1));
+        let x3 = lambda.square() - x1 - x2;
+        let y3 = lambda * (x1 - x3) - y1;
+        Self::store(p, &x3, &y3);
+    }
+
+    /// Computes `2 p`, for a point that is not of order two.
+    pub(crate) fn double(&self, p: &mut [u32]) {
+        let (x, y) = self.coordinates(p);
+        let a = DynResidue::new(&self.a, self.field.params);
+
+        let three_x_squared = x.square() + x.square() + x.square();
+        let lambda = (three_x_squared + a) * PrimeField::invert(&(y + y));
+        let x3 = lambda.square() - x - x;
+        let y3 = lambda * (x - x3) - y;
+        Self::store(p, &x3, &y3);
+    }
+
+    /// Recovers `Y` from the big endian `X` in the first half of `point`, choosing the root with
+    /// the given parity, and writes it big endian into the second half.
+    ///
+    /// As in the VM, an `X` without a matching point yields an unspecified `Y`.
+    pub(crate) fn decompress(&self, point: &mut [u8], is_odd: bool) {
+        let half = point.len() / 2;
+        let x_words: Vec<u32> = point[..half]
+            .rchunks_exact(4)
+            .map(|chunk| u32::from_be_bytes(chunk.try_into().unwrap()))
+            .collect();
+        let x = self.field.element(&x_words);
+        let a = DynResidue::new(&self.a, self.field.params);
+        let b = DynResidue::new(&self.b, self.field.params);
+
+        let mut y = self.field.sqrt(&(x.square() * x + a * x + b));
+        if (y.retrieve().as_limbs()[0].0 & 1 == 1) != is_odd {
+            y = -y;
+        }
+
+        let mut y_words = vec![0u32; half / 4];
+        PrimeField::store(&y, &mut y_words);
+        for (chunk, word) in point[half..].rchunks_exact_mut(4).zip(y_words.iter()) {
+            chunk.copy_from_slice(&word.to_be_bytes());
+        }
+    }
+}
+
+pub(crate) const SECP256K1: WeierstrassCurve<{ U256::LIMBS }> = WeierstrassCurve {
+    field: PrimeField::new(&U256::from_be_hex(
+        "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+    )),
+    a: U256::ZERO,
+    b: U256::from_u64(7),
+};
+
+pub(crate) const SECP256K1_SCALAR: PrimeField<{ U256::LIMBS }> = PrimeField::new(
+    &U256::from_be_hex("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141"),
+);
+
+pub(crate) const SECP256R1: WeierstrassCurve<{ U256::LIMBS }> = WeierstrassCurve {
+    field: PrimeField::new(&U256::from_be_hex(
+        "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+    )),
+    a: U256::from_be_hex("ffffffff00000001000000000000000000000000fffffffffffffffffffffffc"),
+    b: U256::from_be_hex("5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b"),
+};
+
+pub(crate) const BN254: WeierstrassCurve<{ U256::LIMBS }> = WeierstrassCurve {
+    field: PrimeField::new(&U256::from_be_hex(
+        "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47",
+    )),
+    a: U256::ZERO,
+    b: U256::from_u64(3),
+};
+
+pub(crate) const BLS12381_FP: PrimeField<{ U384::LIMBS }> = PrimeField::new(&U384::from_be_hex(
+    "1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab",
+));
+
+/// Computes `x * y mod modulus`, or `x * y mod 2^256` for a zero modulus.
+pub(crate) fn uint256_mul(x: &mut [u32; 8], y_and_modulus: &[u32; 16]) {
+    let a: U256 = to_uint(x);
+    let b: U256 = to_uint(&y_and_modulus[..8]);
+    let modulus: U256 = to_uint(&y_and_modulus[8..]);
+
+    let (lo, hi) = a.mul_wide(&b);
+    let res = if modulus == U256::ZERO {
+        lo
+    } else {
+        U256::const_rem_wide((lo, hi), &modulus).0
+    };
+    from_uint(&res, x);
+}
+
+/// Computes the inverse of a secp256k1 scalar, given as little endian words.
+pub(crate) fn secp256k1_scalar_invert(p: &mut [u32; 8]) {
+    let inverse = PrimeField::invert(&SECP256K1_SCALAR.element(p));
+    PrimeField::store(&inverse, p);
+}
+
+/// Computes the message schedule of SHA-256, extending the first 16 words to all 64.
+pub(crate) fn sha256_extend(w: &mut [u32; 64]) {
+    for i in 16..64 {
+        let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+        let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+        w[i] = w[i - 16]
+            .wrapping_add(s0)
+            .wrapping_add(w[i - 7])
+            .wrapping_add(s1);
+    }
+}
+
+const KECCAK_ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808a,
+    0x8000000080008000,
+    0x000000000000808b,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008a,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000a,
+    0x000000008000808b,
+    0x800000000000008b,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800a,
+    0x800000008000000a,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+const KECCAK_ROTATIONS: [u32; 24] = [
+    1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44,
+];
+
+const KECCAK_PI: [usize; 24] = [
+    10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1,
+];
+
+/// Computes the Keccak-f[1600] permutation.
+pub(crate) fn keccak_permute(state: &mut [u64; 25]) {
+    for round_constant in KECCAK_ROUND_CONSTANTS {
+        // Theta.
+        let mut c = [0u64; 5];
+        for (x, column) in c.iter_mut().enumerate() {
+            *column = state[x] ^ state[x + 5] ^ state[x + 10] ^ state[x + 15] ^ state[x + 20];
+        }
+        for x in 0..5 {
+            let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+            for y in 0..5 {
+                state[5 * y + x] ^= d;
+            }
+        }
+
+        // Rho and pi.
+        let mut last = state[1];
+        for (&pi, &rotation) in KECCAK_PI.iter().zip(KECCAK_ROTATIONS.iter()) {
+            let next = state[pi];
+            state[pi] = last.rotate_left(rotation);
+            last = next;
+        }
+
+        // Chi.
+        for y in 0..5 {
+            let row = [
+                state[5 * y],
+                state[5 * y + 1],
+                state[5 * y + 2],
+                state[5 * y + 3],
+                state[5 * y + 4],
+            ];
+            for x in 0..5 {
+                state[5 * y + x] = row[x] ^ (!row[(x + 1) % 5] & row[(x + 2) % 5]);
+            }
+        }
+
+        // Iota.
+        state[0] ^= round_constant;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k256::elliptic_curve::{PrimeField as _, sec1::ToEncodedPoint};
+    use sha2::Digest;
+
+    /// Parses big endian bytes into little endian words.
+    fn le_words(be: &[u8]) -> Vec<u32> {
+        be.rchunks_exact(4)
+            .map(|chunk| u32::from_be_bytes(chunk.try_into().unwrap()))
+            .collect()
+    }
+
+    /// Lays out an uncompressed SEC1 point as the words of `X || Y`.
+    fn point_words(uncompressed: &[u8]) -> Vec<u32> {
+        let (x, y) = uncompressed[1..].split_at(32);
+        [le_words(x), le_words(y)].concat()
+    }
+
+    fn k256_point(k: u64) -> Vec<u32> {
+        let point = k256::ProjectivePoint::GENERATOR * k256::Scalar::from(k);
+        point_words(point.to_affine().to_encoded_point(false).as_bytes())
+    }
+
+    fn p256_point(k: u64) -> Vec<u32> {
+        let point = p256::ProjectivePoint::GENERATOR * p256::Scalar::from(k);
+        point_words(point.to_affine().to_encoded_point(false).as_bytes())
+    }
+
+    #[test]
+    fn secp256k1_matches_k256() {
+        for k in 1..8 {
+            let mut sum = k256_point(k);
+            SECP256K1.add(&mut sum, &k256_point(k + 3));
+            assert_eq!(sum, k256_point(2 * k + 3));
+
+            let mut double = k256_point(k);
+            SECP256K1.double(&mut double);
+            assert_eq!(double, k256_point(2 * k));
+        }
+    }
+
+    #[test]
+    fn secp256r1_matches_p256() {
+        for k in 1..8 {
+            let mut sum = p256_point(k);
+            SECP256R1.add(&mut sum, &p256_point(k + 3));
+            assert_eq!(sum, p256_point(2 * k + 3));
+
+            let mut double = p256_point(k);
+            SECP256R1.double(&mut double);
+            assert_eq!(double, p256_point(2 * k));
+        }
+    }
+
+    #[test]
+    fn decompress_matches_sec1() {
+        for k in 1u64..16 {
+            let k256 = (k256::ProjectivePoint::GENERATOR * k256::Scalar::from(k)).to_affine();
+            let p256 = (p256::ProjectivePoint::GENERATOR * p256::Scalar::from(k)).to_affine();
+            for (curve, uncompressed) in [
+                (&SECP256K1, k256.to_encoded_point(false).as_bytes().to_vec()),
+                (&SECP256R1, p256.to_encoded_point(false).as_bytes().to_vec()),
+            ] {
+                let is_odd = uncompressed[64] & 1 == 1;
+                let mut point = [0u8; 64];
+                point[..32].copy_from_slice(&uncompressed[1..33]);
+                curve.decompress(&mut point, is_odd);
+                assert_eq!(point[..], uncompressed[1..]);
+            }
+        }
+    }
+
+    #[test]
+    fn bn254_doubles_generator() {
+        let mut generator = [0u32; 16];
+        (generator[0], generator[8]) = (1, 2);
+        let mut point = generator.to_vec();
+        BN254.double(&mut point);
+
+        let x =
+            U256::from_be_hex("030644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd3");
+        let y =
+            U256::from_be_hex("15ed738c0e0a7c92e7845f96b2ae9c0a68a6a449e3538fc7ff3ebf7a5a18a2c4");
+        assert_eq!(to_uint::<{ U256::LIMBS }>(&point[..8]), x);
+        assert_eq!(to_uint::<{ U256::LIMBS }>(&point[8..]), y);
+
+        // G + 2G against 2G + G.
+        let mut three = generator.to_vec();
+        BN254.add(&mut three, &point);
+        let mut other = point.clone();
+        BN254.add(&mut other, &generator);
+        assert_eq!(three, other);
+    }
+
+    #[test]
+    fn bls12381_fp_matches_wide_reduction() {
+        let modulus = *BLS12381_FP.params.modulus();
+        let reduce = |(lo, hi)| U384::const_rem_wide((lo, hi), &modulus).0;
+        let a = U384::from_be_hex(
+            "0d1b2e4f5a6978879695a4b3c2d1e0f0123456789abcdef0fedcba98765432100123456789abcdef0123456789abcdef",
+        );
+        let b = U384::from_be_hex(
+            "19a2b3c4d5e6f708192a3b4c5d6e7f8091a2b3c4d5e6f708192a3b4c5d6e7f80fedcba9876543210fedcba9876543210",
+        );
+        let words = |value: &U384| {
+            let mut words = [0u32; 12];
+            from_uint(value, &mut words);
+            words
+        };
+
+        let mut sum = words(&a);
+        BLS12381_FP.add(&mut sum, &words(&b));
+        assert_eq!(sum, words(&a.add_mod(&b, &modulus)));
+
+        let mut product = words(&a);
+        BLS12381_FP.mul(&mut product, &words(&b));
+        assert_eq!(product, words(&reduce(a.mul_wide(&b))));
+
+        // (a + b u)(b + a u) = (ab - ba) + (a^2 + b^2) u = (a^2 + b^2) u.
+        let mut fp2 = [words(&a), words(&b)].concat();
+        BLS12381_FP.fp2_mul(&mut fp2, &[words(&b), words(&a)].concat());
+        let squares = reduce(a.mul_wide(&a)).add_mod(&reduce(b.mul_wide(&b)), &modulus);
+        assert_eq!(fp2, [[0; 12], words(&squares)].concat());
+
+        let mut fp2 = [words(&a), words(&b)].concat();
+        BLS12381_FP.fp2_add(&mut fp2, &[words(&b), words(&a)].concat());
+        let sum = words(&a.add_mod(&b, &modulus));
+        assert_eq!(fp2, [sum, sum].concat());
+    }
+
+    #[test]
+    fn uint256_mul_matches_crypto_bigint() {
+        let a =
+            U256::from_be_hex("fedcba9876543210fedcba9876543210fedcba9876543210fedcba9876543210");
+        let b =
+            U256::from_be_hex("0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef");
+        let modulus =
+            U256::from_be_hex("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff0001");
+        let words = |value: &U256| {
+            let mut words = [0u32; 8];
+            from_uint(value, &mut words);
+            words
+        };
+
+        let mut product = words(&a);
+        uint256_mul(
+            &mut product,
+            &[words(&b), words(&modulus)].concat().try_into().unwrap(),
+        );
+        assert_eq!(
+            product,
+            words(&U256::const_rem_wide(a.mul_wide(&b), &modulus).0)
+        );
+
+        let mut wrapped = words(&a);
+        uint256_mul(
+            &mut wrapped,
+            &[words(&b), [0; 8]].concat().try_into().unwrap(),
+        );
+        assert_eq!(wrapped, words(&a.wrapping_mul(&b)));
+    }
+
+    #[test]
+    fn scalar_invert_matches_k256() {
+        for seed in 0u8..8 {
+            let scalar = k256::Scalar::from_repr(sha2::Sha256::digest([seed])).unwrap();
+            let mut words: [u32; 8] = le_words(&scalar.to_repr()).try_into().unwrap();
+            secp256k1_scalar_invert(&mut words);
+            let inverse = scalar.invert().unwrap();
+            assert_eq!(words.to_vec(), le_words(&inverse.to_repr()));
+        }
+    }
+
+    #[test]
+    fn sha256_extend_compresses_abc() {
+        let mut block = [0u8; 64];
+        block[..3].copy_from_slice(b"abc");
+        block[3] = 0x80;
+        block[63] = 24;
+
+        let mut w = [0u32; 64];
+        for (word, chunk) in w.iter_mut().zip(block.chunks_exact(4)) {
+            *word = u32::from_be_bytes(chunk.try_into().unwrap());
+        }
+        sha256_extend(&mut w);
+
+        const K: [u32; 64] = [
+            0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4,
+            0xab1c5ed5, 0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe,
+            0x9bdc06a7, 0xc19bf174, 0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f,
+            0x4a7484aa, 0x5cb0a9dc, 0x76f988da, 0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7,
+            0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967, 0x27b70a85, 0x2e1b2138, 0x4d2c6dfc,
+            0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85, 0xa2bfe8a1, 0xa81a664b,
+            0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070, 0x19a4c116,
+            0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+            0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7,
+            0xc67178f2,
+        ];
+        let init = [
+            0x6a09e667u32,
+            0xbb67ae85,
+            0x3c6ef372,
+            0xa54ff53a,
+            0x510e527f,
+            0x9b05688c,
+            0x1f83d9ab,
+            0x5be0cd19,
+        ];
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = init;
+        for i in 0..64 {
+            let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+            let ch = (e & f) ^ (!e & g);
+            let t1 = h
+                .wrapping_add(s1)
+                .wrapping_add(ch)
+                .wrapping_add(K[i])
+                .wrapping_add(w[i]);
+            let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let t2 = s0.wrapping_add(maj);
+            (h, g, f, e, d, c, b, a) = (g, f, e, d.wrapping_add(t1), c, b, a, t1.wrapping_add(t2));
+        }
+
+        let digest: Vec<u8> = [a, b, c, d, e, f, g, h]
+            .iter()
+            .zip(init)
+            .flat_map(|(word, init)| word.wrapping_add(init).to_be_bytes())
+            .collect();
+        assert_eq!(digest[..], sha2::Sha256::digest(b"abc")[..]);
+    }
+
+    #[test]
+    fn keccak_permute_matches_keccak() {
+        let mut state: [u64; 25] =
+            core::array::from_fn(|i| (i as u64).wrapping_mul(0x9e3779b97f4a7c15));
+        let mut expected = state;
+        for _ in 0..3 {
+            keccak_permute(&mut state);
+            keccak::f1600(&mut expected);
+            assert_eq!(state, expected);
+        }
+    }
+}